@@ -1,16 +1,29 @@
-use std::collections::{HashMap, HashSet};
+use std::cell::Cell;
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use chrono::{DateTime, Utc};
 use crossbeam_channel::Sender;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::Rect;
 
+use crate::cancel::{self, CancelToken};
 use crate::config;
-use crate::doctl::CreateDropletArgs;
+use crate::doctl::{self, CreateDropletArgs};
 use crate::input::TextInput;
-use crate::model::{AppStateFile, Droplet, Image, Region, RsyncBind, Size, Snapshot, SshKey};
-use crate::mutagen::{SshConfig, SyncPath, SyncSession};
+use crate::logging;
+use crate::model::{
+    AccountInfo, AppStateFile, BindInterface, ConnectMethod, CreateDefaults, DomainRecord, Droplet,
+    Image, PortBinding, Region, RsyncBind, RsyncProfile, SavedScreen, SavedSelection, SavedView,
+    Size, Snapshot, SshKey, Workspace, default_bind_address, hostnames_for_ip, ssh_identity_args,
+};
+use crate::mutagen::{
+    self, CreatedSync, ReconcileReport, SshConfig, SyncBatchFailure, SyncPath, SyncPlan,
+    SyncPlanAction, SyncRollbackOutcome, SyncSession,
+};
 use crate::ports;
-use crate::tasks::{self, RsyncDirection, Task, TaskResult};
+use crate::tasks::{self, RemotePathStatus, RsyncDirection, Task, TaskResult};
+use crate::tools::{self, ToolAvailability};
+use crate::wake;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Screen {
@@ -28,6 +41,17 @@ pub enum ToastLevel {
     Error,
 }
 
+/// Most task failures kept for the errors view; older ones drop off.
+const ERROR_LOG_CAPACITY: usize = 50;
+
+/// A failed task as shown in the errors view, with the full error chain.
+#[derive(Debug, Clone)]
+pub struct ErrorLogEntry {
+    pub at: DateTime<Utc>,
+    pub task: &'static str,
+    pub message: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct Toast {
     pub message: String,
@@ -46,9 +70,11 @@ pub struct PickerItem {
     pub label: String,
     pub value: String,
     pub meta: Option<String>,
+    /// Group heading shown in the list; never selectable.
+    pub header: bool,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum PickerTarget {
     CreateRegion,
     CreateSize,
@@ -58,6 +84,11 @@ pub enum PickerTarget {
     RestoreRegion,
     RestoreSize,
     RestoreSshKeys,
+    DoctlContext,
+    CommandPalette,
+    RsyncBindDroplet,
+    ActivateWorkspace,
+    DeactivateWorkspace,
 }
 
 #[derive(Debug, Clone)]
@@ -81,6 +112,17 @@ pub struct CreateForm {
     pub ssh_keys: Vec<Selection>,
     pub tags: TextInput,
     pub focus: usize,
+    /// `Ctrl+B`: create without `--wait` and keep working while it provisions.
+    pub background: bool,
+}
+
+impl CreateForm {
+    /// The name DigitalOcean will get when it differs from what was typed.
+    pub fn normalized_name(&self) -> Option<String> {
+        let typed = self.name.value.trim();
+        let name = sanitize_name(typed);
+        (!typed.is_empty() && name != typed).then_some(name)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -98,22 +140,36 @@ pub struct RestoreForm {
 pub struct BindForm {
     pub droplet_id: u64,
     pub droplet_name: String,
-    pub public_ip: String,
+    /// `None` for private-only droplets, which can only be bound over their private IP.
+    pub public_ip: Option<String>,
+    pub private_ip: Option<String>,
+    pub interface: BindInterface,
     pub local_port: TextInput,
     pub remote_port: TextInput,
     pub ssh_user: TextInput,
     pub ssh_key_path: TextInput,
+    /// Problem with the key path, checked as focus leaves the field.
+    pub key_warning: Option<String>,
+    /// `Ctrl+A`: authenticate with `ssh-agent` instead of the key file.
+    pub use_agent: bool,
     pub ssh_port: TextInput,
+    pub bind_address: TextInput,
+    pub label: TextInput,
     pub focus: usize,
 }
 
 #[derive(Debug, Clone)]
 pub struct SyncForm {
     pub droplet_name: String,
-    pub public_ip: String,
+    /// Droplet address per the `preferred_ip` setting.
+    pub host: String,
     pub local_paths: TextInput,
     pub ssh_user: TextInput,
     pub ssh_key_path: TextInput,
+    /// Problem with the key path, checked as focus leaves the field.
+    pub key_warning: Option<String>,
+    /// `Ctrl+A`: authenticate with `ssh-agent` instead of the key file.
+    pub use_agent: bool,
     pub ssh_port: TextInput,
     pub focus: usize,
 }
@@ -123,6 +179,27 @@ pub struct SnapshotForm {
     pub droplet_id: u64,
     pub droplet_name: String,
     pub snapshot_name: TextInput,
+    pub disk_gb: Option<u64>,
+    /// Power off before snapshotting (`Ctrl+O`); off keeps the live snapshot.
+    pub power_off: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct BindingLabelForm {
+    /// `address:port` of the local end, as `ports::local_address` formats it.
+    pub local_address: String,
+    pub local_port: u16,
+    pub remote_port: u16,
+    pub droplet_name: String,
+    pub label: TextInput,
+}
+
+/// Connect to a droplet and run one command instead of opening a shell.
+#[derive(Debug, Clone)]
+pub struct ConnectCommandForm {
+    pub droplet_name: String,
+    pub method: ConnectMethod,
+    pub command: TextInput,
 }
 
 #[derive(Debug, Clone)]
@@ -141,9 +218,59 @@ pub struct RemoteBrowserForm {
     pub droplet_name: String,
     pub ssh: SshConfig,
     pub current_path: String,
+    /// Folder names from the last listing; `entries` is what survives the view options.
+    pub directories: Vec<String>,
     pub entries: Vec<RemoteBrowserEntry>,
     pub selected: usize,
     pub loading: bool,
+    /// Narrows `entries` by substring; `/` edits it and navigating clears it.
+    pub filter: TextInput,
+    pub filter_editing: bool,
+    /// Show dot-folders (`.`); hidden by default.
+    pub show_hidden: bool,
+    /// Sort ignoring case (`i`) instead of the listing's byte order.
+    pub case_insensitive: bool,
+    /// Set when opened from the sync form; `s` then fills in its remote path and returns.
+    pub sync_form: Option<SyncForm>,
+}
+
+impl RemoteBrowserForm {
+    fn new(droplet_name: String, ssh: SshConfig, sync_form: Option<SyncForm>) -> Self {
+        Self {
+            droplet_name,
+            ssh,
+            current_path: "~".to_string(),
+            directories: Vec::new(),
+            entries: Vec::new(),
+            selected: 0,
+            loading: false,
+            filter: TextInput::new(""),
+            filter_editing: false,
+            show_hidden: false,
+            case_insensitive: false,
+            sync_form,
+        }
+    }
+
+    fn rebuild_entries(&mut self) {
+        self.entries = build_remote_browser_entries(
+            &self.current_path,
+            &self.directories,
+            &RemoteBrowserView {
+                filter: self.filter.value.trim(),
+                show_hidden: self.show_hidden,
+                case_insensitive: self.case_insensitive,
+            },
+        );
+        self.selected = self.selected.min(self.entries.len().saturating_sub(1));
+    }
+}
+
+/// How the remote browser narrows and orders a listing.
+struct RemoteBrowserView<'a> {
+    filter: &'a str,
+    show_hidden: bool,
+    case_insensitive: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -153,6 +280,27 @@ pub struct RsyncBindForm {
     pub remote_path: String,
     pub local_path: TextInput,
     pub focus: usize,
+    /// Set when the bind is registered by hand from the RSYNC binds screen instead of the
+    /// remote browser; the droplet, remote path, and SSH details are then typed in.
+    pub manual: Option<ManualRsyncBindFields>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ManualRsyncBindFields {
+    pub remote_path: TextInput,
+    pub ssh_user: TextInput,
+    pub ssh_key_path: TextInput,
+    /// Problem with the key path, checked as focus leaves the field.
+    pub key_warning: Option<String>,
+    /// `Ctrl+A`: authenticate with `ssh-agent` instead of the key file.
+    pub use_agent: bool,
+    pub ssh_port: TextInput,
+}
+
+#[derive(Debug, Clone)]
+pub struct RsyncBindGroupForm {
+    pub bind: RsyncBind,
+    pub group: TextInput,
 }
 
 #[derive(Debug, Clone)]
@@ -167,10 +315,19 @@ pub struct RsyncBindActionsForm {
     pub selected_action: usize,
 }
 
+#[derive(Debug, Clone)]
+pub struct ReconcileForm {
+    pub droplet_name: String,
+    pub ssh: SshConfig,
+    pub report: ReconcileReport,
+}
+
 #[derive(Debug, Clone)]
 pub struct Notice {
     pub title: String,
     pub message: String,
+    /// First visible line when the message is taller than the modal.
+    pub scroll: u16,
 }
 
 #[derive(Debug, Clone)]
@@ -185,9 +342,18 @@ pub enum ConfirmAction {
     SnapshotDelete {
         droplet_id: u64,
         snapshot_name: String,
+        power_off: bool,
     },
     DeleteDroplet {
         droplet_id: u64,
+        droplet_name: String,
+    },
+    RestoreDeleted {
+        droplet_name: String,
+        snapshot_id: u64,
+    },
+    TerminateSyncing {
+        sync: SyncSession,
     },
     RestoreSyncs {
         ssh: SshConfig,
@@ -197,6 +363,33 @@ pub enum ConfirmAction {
         droplet_name: String,
     },
     DisableMutagen,
+    RemoveOrphanBindings {
+        local_ports: Vec<u16>,
+    },
+    PullRsync {
+        bind: RsyncBind,
+    },
+    /// Second confirmation before a bind's local folder is removed with it.
+    DeleteRsyncBindWithLocalCopy {
+        bind: RsyncBind,
+    },
+    PullRsyncGroup {
+        group: String,
+        binds: Vec<RsyncBind>,
+    },
+    CreateSyncs {
+        ssh: SshConfig,
+        plan: SyncPlan,
+        form: Option<SyncForm>,
+    },
+    RollbackSyncs {
+        ssh: SshConfig,
+        created: Vec<CreatedSync>,
+    },
+    /// Final review of a create form's resolved spec before any money is spent.
+    CreateDroplet {
+        form: CreateForm,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -210,12 +403,236 @@ pub enum Modal {
     RsyncBind(RsyncBindForm),
     RsyncBindActions(RsyncBindActionsForm),
     DeleteRsyncBind(DeleteRsyncBindForm),
+    Reconcile(ReconcileForm),
     Notice(Notice),
     Snapshot(SnapshotForm),
-    Picker { picker: Picker, parent: Box<Modal> },
+    BindingLabel(BindingLabelForm),
+    ConnectCommand(ConnectCommandForm),
+    RsyncBindGroup(RsyncBindGroupForm),
+    Picker {
+        picker: Picker,
+        parent: Option<Box<Modal>>,
+    },
     Confirm(Confirm),
 }
 
+/// Display-ready facts about a droplet for the details pane.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropletDetail {
+    /// Region slug plus its human name when the region list has loaded.
+    pub region: String,
+    /// Size slug plus memory, vCPU and price when the size list has loaded.
+    pub size: Option<String>,
+    /// Creation time formatted for display, or the raw value if it didn't parse.
+    pub created: Option<String>,
+    /// Relative age such as "5d ago", when `created_at` parsed.
+    pub age: Option<String>,
+    pub backups_enabled: Option<bool>,
+    /// Enabled features such as "monitoring, ipv6", when doctl reported any.
+    pub features: Option<String>,
+}
+
+impl DropletDetail {
+    pub fn new(droplet: &Droplet, regions: &[Region], sizes: &[Size], now: DateTime<Utc>) -> Self {
+        let region = match regions.iter().find(|region| region.slug == droplet.region) {
+            Some(region) => format!("{} ({})", region.name, region.slug),
+            None => droplet.region.clone(),
+        };
+        let size =
+            droplet
+                .size
+                .as_ref()
+                .map(|slug| match sizes.iter().find(|size| &size.slug == slug) {
+                    Some(size) => format!(
+                        "{} ({}, {} vCPU, {}GB disk, ${:.2}/mo)",
+                        size.slug,
+                        format_memory(size.memory_mb),
+                        size.vcpus,
+                        size.disk_gb,
+                        size.price_monthly
+                    ),
+                    None => match droplet_specs(droplet) {
+                        Some(specs) => format!("{slug} ({specs})"),
+                        None => slug.clone(),
+                    },
+                });
+        let parsed = droplet
+            .created_at
+            .as_deref()
+            .and_then(|raw| DateTime::parse_from_rfc3339(raw.trim()).ok())
+            .map(|created| created.with_timezone(&Utc));
+        let created = match parsed {
+            Some(created) => Some(created.format("%Y-%m-%d %H:%M UTC").to_string()),
+            None => droplet
+                .created_at
+                .as_deref()
+                .map(str::trim)
+                .filter(|raw| !raw.is_empty())
+                .map(str::to_string),
+        };
+        Self {
+            region,
+            size,
+            created,
+            age: parsed.map(|created| format_age(now - created)),
+            backups_enabled: droplet.backups_enabled(),
+            features: droplet
+                .features
+                .as_ref()
+                .filter(|features| !features.is_empty())
+                .map(|features| features.join(", ")),
+        }
+    }
+}
+
+/// Memory, vCPU and disk as the droplet itself reports them, for sizes missing from the list.
+fn droplet_specs(droplet: &Droplet) -> Option<String> {
+    let specs: Vec<String> = [
+        droplet.memory_mb.map(format_memory),
+        droplet.vcpus.map(|vcpus| format!("{vcpus} vCPU")),
+        droplet.disk_gb.map(|disk| format!("{disk}GB disk")),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    (!specs.is_empty()).then(|| specs.join(", "))
+}
+
+/// DigitalOcean bills hourly up to a monthly cap of 672 hours (28 days).
+const BILLABLE_HOURS_PER_MONTH: f64 = 672.0;
+
+/// The create spec as it will be sent, one field per line, for the final confirm.
+fn create_summary(
+    args: &CreateDropletArgs,
+    form: &CreateForm,
+    size: Option<&Size>,
+    ssh_keys: &[SshKey],
+) -> String {
+    let region = form
+        .region
+        .as_ref()
+        .map_or("(DigitalOcean picks)", |region| region.label.as_str());
+    let size = match size {
+        Some(size) => format!(
+            "{} ({}, {} vCPU, {}GB disk), {}",
+            size.slug,
+            format_memory(size.memory_mb),
+            size.vcpus,
+            size.disk_gb,
+            format_cost(size.price_monthly)
+        ),
+        None => format!("{} (price unknown)", args.size),
+    };
+    let image = form
+        .image
+        .as_ref()
+        .map_or(args.image.as_str(), |image| image.label.as_str());
+    let keys: Vec<String> = args
+        .ssh_keys
+        .iter()
+        .map(|id| {
+            ssh_keys
+                .iter()
+                .find(|key| key.id.to_string() == *id)
+                .map_or_else(|| format!("#{id}"), |key| key.name.clone())
+        })
+        .collect();
+    let or_none = |items: &[String]| {
+        if items.is_empty() {
+            "none".to_string()
+        } else {
+            items.join(", ")
+        }
+    };
+    format!(
+        "Name: {}\nRegion: {region}\nSize: {size}\nImage: {image}\nSSH keys: {}\nTags: {}\nProject: {}\nWait: {}",
+        args.name,
+        or_none(&keys),
+        or_none(&args.tags),
+        args.project_id.as_deref().unwrap_or("account default"),
+        if args.wait {
+            "until active (--wait)"
+        } else {
+            "no, provision in the background"
+        }
+    )
+}
+
+fn format_cost(price_monthly: f64) -> String {
+    format!(
+        "${price_monthly:.2}/mo (${:.4}/hr)",
+        price_monthly / BILLABLE_HOURS_PER_MONTH
+    )
+}
+
+fn format_memory(memory_mb: u64) -> String {
+    if memory_mb >= 1024 && memory_mb.is_multiple_of(1024) {
+        format!("{}GB", memory_mb / 1024)
+    } else {
+        format!("{memory_mb}MB")
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+pub(crate) fn format_age(elapsed: chrono::Duration) -> String {
+    let minutes = elapsed.num_minutes();
+    if minutes < 1 {
+        return "just now".to_string();
+    }
+    let hours = elapsed.num_hours();
+    let days = elapsed.num_days();
+    if hours < 1 {
+        format!("{minutes}m ago")
+    } else if days < 1 {
+        format!("{hours}h ago")
+    } else if days < 365 {
+        format!("{days}d ago")
+    } else {
+        format!("{}y ago", days / 365)
+    }
+}
+
+/// Names of the chosen SSH keys for a form row, cut to `max_chars` with an ellipsis.
+pub(crate) fn ssh_key_summary(keys: &[Selection], max_chars: usize) -> String {
+    let names = keys
+        .iter()
+        .map(|key| key.label.split(" (").next().unwrap_or(&key.label))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let summary = format!("{}: {names}", keys.len());
+    if summary.chars().count() <= max_chars {
+        return summary;
+    }
+    let mut cut: String = summary.chars().take(max_chars.saturating_sub(1)).collect();
+    cut.push('…');
+    cut
+}
+
+/// Rows of the current screen's list as last drawn, used to map mouse clicks.
+#[derive(Debug, Clone, Copy)]
+pub struct ListHitArea {
+    pub rows: Rect,
+    pub offset: usize,
+}
+
+const DEFAULT_TOAST_TTL_SECS: u64 = 6;
+const NOTICE_PAGE_LINES: u16 = 10;
+/// DigitalOcean's published snapshot storage rate, in USD per GB per month.
+const SNAPSHOT_PRICE_PER_GB_MONTH: f64 = 0.06;
+
 #[derive(Debug)]
 pub struct App {
     pub screen: Screen,
@@ -229,20 +646,69 @@ pub struct App {
     pub ssh_keys: Vec<SshKey>,
     pub syncs: Vec<SyncSession>,
     pub syncs_context: Option<SshConfig>,
+    /// Persisted registry and settings. Only the main thread touches it, and every change that
+    /// should survive a restart goes through `update_state`, so writes never interleave.
     pub state: AppStateFile,
+    /// Account doctl last authenticated as; `None` until checked or after a failed check.
+    pub account: Option<AccountInfo>,
+    /// Why the last `CheckDoctl` failed, shown in the header until a check succeeds.
+    pub account_error: Option<String>,
+    pub tools: ToolAvailability,
     pub toast: Option<Toast>,
+    /// Last command run through the connect-and-run form, prefilled next time.
+    pub last_connect_command: String,
+    /// Recent task failures, oldest first, capped at `ERROR_LOG_CAPACITY`.
+    pub error_log: VecDeque<ErrorLogEntry>,
     pub should_quit: bool,
     pub last_refresh: Option<DateTime<Utc>>,
+    /// DigitalOcean returned 429; API refreshes wait until this time.
+    pub rate_limited_until: Option<DateTime<Utc>>,
+    /// A refresh was skipped or failed while rate limited and runs once the window resets.
+    pub refresh_deferred: bool,
     pub filter_running: bool,
+    pub binding_filter: TextInput,
+    pub binding_filter_editing: bool,
+    pub binding_sort_by_port: bool,
     pub pending: usize,
+    /// When the current run of foreground work started; background tasks don't count.
+    pub pending_since: Option<DateTime<Utc>>,
     pub pending_labels: HashMap<String, usize>,
     pub terminal_reset: bool,
+    pub list_area: Cell<Option<ListHitArea>>,
+    /// Local folders whose pull-overwrite warning was accepted this session.
+    pub pull_acknowledged: HashSet<String>,
+    /// Largest useful `Notice::scroll` for the notice as last drawn.
+    pub notice_max_scroll: Cell<u16>,
+    /// The terminal was too small to draw the open modal last frame, so it showed
+    /// "Terminal too small" instead.
+    pub modal_hidden: Cell<bool>,
+    /// Last health probe per local port: whether the tunnel's port accepted a connection.
+    pub binding_health: HashMap<u16, bool>,
+    /// Last remote-folder probe per rsync bind (see `rsync_remote_key`), with when it ran.
+    pub rsync_remote_checks: HashMap<String, (DateTime<Utc>, RemotePathStatus)>,
+    /// DNS records across the account with when they were fetched (`show_dns_records`).
+    pub domain_records: Option<(DateTime<Utc>, Vec<DomainRecord>)>,
+    domain_records_loading: bool,
+    /// Root token every spawned task gets a child of; Esc on the overlay cancels them.
+    cancel: CancelToken,
+    /// In-flight tasks that stop early when cancelled (creates, snapshots, rsync runs).
+    pub cancellable_pending: usize,
+    /// The part of `pending` that doesn't raise the busy overlay (see `is_background_task`).
+    pub background_pending: usize,
+    /// Latest status of droplet actions being polled, keyed by droplet id, for the overlay.
+    pub action_progress: HashMap<u64, String>,
+    /// Cancels the remote browser's in-flight listing when it's superseded or closed.
+    remote_listing: Option<CancelToken>,
+    /// Last search typed into each picker this session, prefilled when it reopens.
+    pub picker_queries: HashMap<PickerTarget, String>,
     pub task_tx: Sender<TaskResult>,
 }
 
 impl App {
     pub fn new(task_tx: Sender<TaskResult>) -> Self {
         let state = config::load_state().unwrap_or_else(|_| config::default_state());
+        doctl::set_context(state.settings.doctl_context.clone());
+        ports::set_tunnel_start_timeout(state.settings.tunnel_start_timeout_ms);
         Self {
             screen: Screen::Home,
             modal: None,
@@ -256,23 +722,100 @@ impl App {
             syncs: Vec::new(),
             syncs_context: None,
             state,
+            account: None,
+            account_error: None,
+            tools: ToolAvailability::default(),
             toast: None,
+            last_connect_command: String::new(),
+            error_log: VecDeque::new(),
             should_quit: false,
             last_refresh: None,
+            rate_limited_until: None,
+            refresh_deferred: false,
             filter_running: false,
+            binding_filter: TextInput::new(""),
+            binding_filter_editing: false,
+            binding_sort_by_port: false,
             pending: 0,
+            pending_since: None,
             pending_labels: HashMap::new(),
             terminal_reset: false,
+            list_area: Cell::new(None),
+            pull_acknowledged: HashSet::new(),
+            notice_max_scroll: Cell::new(0),
+            modal_hidden: Cell::new(false),
+            binding_health: HashMap::new(),
+            rsync_remote_checks: HashMap::new(),
+            domain_records: None,
+            domain_records_loading: false,
+            cancel: CancelToken::default(),
+            cancellable_pending: 0,
+            background_pending: 0,
+            action_progress: HashMap::new(),
+            remote_listing: None,
+            picker_queries: HashMap::new(),
             task_tx,
         }
     }
 
     pub fn bootstrap(&mut self) {
+        self.spawn(Task::CheckTools);
         self.spawn(Task::CheckDoctl);
         self.refresh_all();
+        self.restore_view();
+        // Settings are only read at startup, so the watcher's lifetime is the app's.
+        if self.state.settings.reconnect_on_resume {
+            wake::watch(self.task_tx.clone());
+        }
+    }
+
+    fn restore_view(&mut self) {
+        if !self.state.settings.remember_view {
+            return;
+        }
+        let Some(view) = self.state.settings.last_view.clone() else {
+            return;
+        };
+        self.filter_running = view.filter_running;
+        self.binding_sort_by_port = view.binding_sort_by_port;
+        match view.screen {
+            SavedScreen::Home => {}
+            SavedScreen::Bindings => self.screen = Screen::Bindings,
+            SavedScreen::Syncs => self.open_syncs_screen_global(),
+            SavedScreen::RsyncBinds => self.open_rsync_binds_screen(),
+        }
+    }
+
+    /// The view to reopen next launch, when `remember_view` is on.
+    fn view_to_remember(&self) -> Option<SavedView> {
+        if !self.state.settings.remember_view {
+            return None;
+        }
+        let screen = match self.screen {
+            Screen::Home => SavedScreen::Home,
+            Screen::Bindings => SavedScreen::Bindings,
+            Screen::Syncs => SavedScreen::Syncs,
+            Screen::RsyncBinds => SavedScreen::RsyncBinds,
+        };
+        Some(SavedView {
+            screen,
+            filter_running: self.filter_running,
+            binding_sort_by_port: self.binding_sort_by_port,
+        })
     }
 
     pub fn refresh_all(&mut self) {
+        if let Some(until) = self.rate_limited_until.filter(|until| *until > Utc::now()) {
+            self.refresh_deferred = true;
+            self.push_toast(
+                format!(
+                    "Rate limited by DigitalOcean; refresh deferred until {}",
+                    until.with_timezone(&chrono::Local).format("%H:%M:%S")
+                ),
+                ToastLevel::Warning,
+            );
+            return;
+        }
         self.spawn(Task::RefreshDroplets);
         self.spawn(Task::LoadSnapshots);
         self.spawn(Task::LoadRegions);
@@ -282,29 +825,173 @@ impl App {
     }
 
     pub fn spawn(&mut self, task: Task) {
+        self.spawn_with_cancel(task, self.cancel.child());
+    }
+
+    /// Spawns with a token of the caller's own, for tasks cancelled on their own schedule.
+    fn spawn_with_cancel(&mut self, task: Task, cancel: CancelToken) {
+        logging::log(format!("task: {}", pending_label_for_task(&task)));
         self.track_task_start(&task);
-        tasks::spawn(task, self.task_tx.clone());
+        tasks::spawn(task, self.task_tx.clone(), cancel);
+    }
+
+    fn report_cancelled(&mut self, result: &TaskResult) {
+        let label = pending_label_for_result(result);
+        let droplet_task = matches!(
+            result,
+            TaskResult::CreateDroplet { .. }
+                | TaskResult::RestoreDroplet { .. }
+                | TaskResult::SnapshotDelete(_)
+        );
+        if droplet_task {
+            // DigitalOcean keeps going with whatever it already accepted; show the real state.
+            self.push_toast(
+                format!("{label} cancelled; DigitalOcean may still finish it"),
+                ToastLevel::Warning,
+            );
+            self.spawn(Task::RefreshDroplets);
+        } else {
+            self.push_toast(format!("{label} cancelled"), ToastLevel::Warning);
+        }
+    }
+
+    /// Runs a refresh deferred by a rate limit once its window has reset.
+    pub fn tick(&mut self) {
+        self.load_domain_records_if_needed();
+        let Some(until) = self.rate_limited_until else {
+            return;
+        };
+        if until > Utc::now() {
+            return;
+        }
+        self.rate_limited_until = None;
+        if std::mem::take(&mut self.refresh_deferred) {
+            self.push_toast("Rate limit window reset; refreshing", ToastLevel::Info);
+            self.refresh_all();
+        }
     }
 
     pub fn handle_task_result(&mut self, result: TaskResult) {
+        if let TaskResult::ActionProgress { droplet_id, step } = result {
+            // Progress arrives while the task is still running, so it isn't a task end.
+            self.action_progress.insert(droplet_id, step);
+            return;
+        }
+        if let TaskResult::SystemResumed = result {
+            self.reconnect_after_resume();
+            return;
+        }
+        if logging::enabled() {
+            let label = pending_label_for_result(&result);
+            match task_error(&result) {
+                Some(err) => logging::log(format!("result: {label} failed: {err:#}")),
+                None => logging::log(format!("result: {label} ok")),
+            }
+        }
         self.track_task_end(&result);
+        if let TaskResult::RemoteDirectories {
+            requested_path,
+            result: listing,
+        } = &result
+            && (listing.as_ref().is_err_and(cancel::is_cancelled_error)
+                || !self.is_current_remote_listing(requested_path))
+        {
+            // Superseded by later navigation or a closed browser; showing it would flash
+            // an old directory's contents.
+            logging::log(format!("dropping stale listing of {requested_path}"));
+            return;
+        }
+        if task_error(&result).is_some_and(cancel::is_cancelled_error) {
+            self.report_cancelled(&result);
+            return;
+        }
+        if let Some(err) = task_error(&result) {
+            push_error_log(
+                &mut self.error_log,
+                ErrorLogEntry {
+                    at: Utc::now(),
+                    task: pending_label_for_result(&result),
+                    message: logging::redact(&format!("{err:#}")),
+                },
+            );
+        }
+        if let Some(limited) = rate_limit_of(&result) {
+            self.rate_limited_until = self.rate_limited_until.max(Some(limited.reset_at));
+            self.refresh_deferred = true;
+        }
         match result {
+            TaskResult::ActionProgress { .. } | TaskResult::SystemResumed => {}
             TaskResult::DoctlCheck(res) => match res {
-                Ok(()) => self.push_toast("doctl authenticated", ToastLevel::Success),
-                Err(err) => self.push_toast(err.to_string(), ToastLevel::Error),
+                Ok(account) => {
+                    match account.warning() {
+                        Some(warning) => self.push_toast(
+                            format!("{}: {warning}", account.email),
+                            ToastLevel::Warning,
+                        ),
+                        None => self.push_toast(
+                            format!("doctl authenticated as {}", account.email),
+                            ToastLevel::Success,
+                        ),
+                    }
+                    self.account = Some(account);
+                    self.account_error = None;
+                }
+                Err(err) => {
+                    self.account = None;
+                    self.account_error = Some(err.to_string());
+                    self.push_toast(err.to_string(), ToastLevel::Error);
+                }
             },
+            TaskResult::Tools(tools) => {
+                self.tools = tools;
+                let missing = tools.missing();
+                if !missing.is_empty() {
+                    self.push_toast(
+                        format!(
+                            "Not found on PATH: {}. Related actions are disabled.",
+                            missing.join(", ")
+                        ),
+                        ToastLevel::Warning,
+                    );
+                }
+            }
             TaskResult::Droplets(res) => match res {
-                Ok(mut droplets) => {
-                    droplets.sort_by(|a, b| a.name.cmp(&b.name));
+                Ok(droplets) => {
+                    let selected_id = self.selected_droplet().map(|droplet| droplet.id);
+                    self.warn_if_truncated("droplets", droplets.len());
                     self.droplets = droplets;
-                    self.selected = 0;
+                    self.sort_droplets();
+                    self.reselect_droplet(selected_id);
                     self.last_refresh = Some(Utc::now());
                 }
                 Err(err) => self.push_toast(err.to_string(), ToastLevel::Error),
             },
-            TaskResult::Snapshots(res) => match res {
+            TaskResult::Droplet(res) => match res {
+                Ok(droplet) => self.upsert_droplet(droplet),
+                Err(err) => self.push_toast(err.to_string(), ToastLevel::Error),
+            },
+            TaskResult::DropletNetwork {
+                droplet_name,
+                result,
+                ..
+            } => match result {
+                Ok(droplet) => {
+                    let ip = droplet.public_ipv4.clone().unwrap_or_default();
+                    self.upsert_droplet(droplet);
+                    self.push_toast(
+                        format!("{droplet_name} is ready at {ip}"),
+                        ToastLevel::Success,
+                    );
+                }
+                Err(err) => {
+                    self.push_toast(err.to_string(), ToastLevel::Warning);
+                    self.spawn(Task::RefreshDroplets);
+                }
+            },
+            TaskResult::Snapshots { result, .. } => match result {
                 Ok(mut snapshots) => {
                     snapshots.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+                    self.warn_if_truncated("snapshots", snapshots.len());
                     self.snapshots = snapshots;
                     let snapshot_items = self.snapshot_picker_items();
                     if let Some(Modal::Picker { picker, .. }) = &mut self.modal {
@@ -333,6 +1020,7 @@ impl App {
             TaskResult::Images(res) => match res {
                 Ok(mut images) => {
                     images.sort_by(|a, b| a.name.cmp(&b.name));
+                    self.warn_if_truncated("images", images.len());
                     self.images = images;
                 }
                 Err(err) => self.push_toast(err.to_string(), ToastLevel::Error),
@@ -340,22 +1028,83 @@ impl App {
             TaskResult::SshKeys(res) => match res {
                 Ok(mut keys) => {
                     keys.sort_by(|a, b| a.name.cmp(&b.name));
+                    self.warn_if_truncated("SSH keys", keys.len());
                     self.ssh_keys = keys;
                 }
                 Err(err) => self.push_toast(err.to_string(), ToastLevel::Error),
             },
-            TaskResult::CreateDroplet(res) => match res {
+            TaskResult::DomainRecords(res) => {
+                self.domain_records_loading = false;
+                let records = res.unwrap_or_else(|err| {
+                    // Cache the failure too so it's retried on the TTL rather than every tick.
+                    self.push_toast(format!("DNS lookup failed: {err}"), ToastLevel::Warning);
+                    Vec::new()
+                });
+                self.domain_records = Some((Utc::now(), records));
+            }
+            TaskResult::Contexts(res) => match res {
+                Ok(contexts) => {
+                    if contexts.is_empty() {
+                        self.push_toast("No doctl contexts found", ToastLevel::Warning);
+                        return;
+                    }
+                    let active = self.state.settings.doctl_context.clone();
+                    let items = contexts
+                        .into_iter()
+                        .map(|context| {
+                            let is_active = match &active {
+                                Some(name) => *name == context.name,
+                                None => context.current,
+                            };
+                            PickerItem {
+                                label: if is_active {
+                                    format!("{} (active)", context.name)
+                                } else {
+                                    context.name.clone()
+                                },
+                                value: context.name,
+                                meta: None,
+                                header: false,
+                            }
+                        })
+                        .collect();
+                    let mut picker = Picker::new(
+                        "Select doctl Context".to_string(),
+                        items,
+                        PickerTarget::DoctlContext,
+                        false,
+                    );
+                    self.restore_picker_query(&mut picker);
+                    self.modal = Some(Modal::Picker {
+                        picker,
+                        parent: None,
+                    });
+                }
+                Err(err) => self.push_toast(err.to_string(), ToastLevel::Error),
+            },
+            TaskResult::CreateDroplet { args, result } => match result {
                 Ok(droplet) => {
-                    self.push_toast("Droplet created", ToastLevel::Success);
+                    if args.wait {
+                        self.push_toast("Droplet created", ToastLevel::Success);
+                    } else {
+                        self.push_toast(
+                            format!("{} is provisioning in the background", droplet.name),
+                            ToastLevel::Info,
+                        );
+                    }
+                    self.record_droplet_ssh_keys(droplet.id, &args);
+                    self.wait_for_network_if_needed(&droplet, !args.wait);
                     self.droplets.push(droplet);
                     self.modal = None;
                     self.spawn(Task::RefreshDroplets);
                 }
                 Err(err) => self.push_toast(err.to_string(), ToastLevel::Error),
             },
-            TaskResult::RestoreDroplet(res) => match res {
+            TaskResult::RestoreDroplet { args, result } => match result {
                 Ok(droplet) => {
                     self.push_toast("Droplet restored", ToastLevel::Success);
+                    self.record_droplet_ssh_keys(droplet.id, &args);
+                    self.wait_for_network_if_needed(&droplet, false);
                     self.droplets.push(droplet);
                     self.modal = None;
                     self.spawn(Task::RefreshDroplets);
@@ -363,8 +1112,14 @@ impl App {
                 Err(err) => self.push_toast(err.to_string(), ToastLevel::Error),
             },
             TaskResult::SnapshotDelete(res) => match res {
-                Ok(()) => {
-                    self.push_toast("Snapshot created and droplet deleted", ToastLevel::Success);
+                Ok(action) => {
+                    self.push_toast(
+                        format!(
+                            "Snapshot {} (action #{}) and droplet deleted",
+                            action.status, action.id
+                        ),
+                        ToastLevel::Success,
+                    );
                     self.modal = None;
                     self.spawn(Task::RefreshDroplets);
                     self.spawn(Task::LoadSnapshots);
@@ -372,34 +1127,159 @@ impl App {
                 }
                 Err(err) => self.push_toast(err.to_string(), ToastLevel::Error),
             },
-            TaskResult::DeleteDroplet(res) => match res {
+            TaskResult::DeleteDroplet {
+                droplet_id,
+                droplet_name,
+                result,
+            } => match result {
                 Ok(()) => {
-                    self.push_toast("Droplet deleted", ToastLevel::Success);
                     self.modal = None;
                     self.spawn(Task::RefreshDroplets);
+                    self.offer_restore_after_delete(droplet_id, droplet_name);
+                }
+                Err(err) => self.push_toast(err.to_string(), ToastLevel::Error),
+            },
+            TaskResult::ReconnectTunnels { outcome, alive } => {
+                let reconnected = outcome.reconnected.len();
+                self.update_state(|state| {
+                    for binding in outcome.reconnected {
+                        if let Some(existing) = state
+                            .bindings
+                            .iter_mut()
+                            .find(|existing| existing.local_port == binding.local_port)
+                        {
+                            *existing = binding;
+                        }
+                    }
+                });
+                let summary = format!(
+                    "Tunnels: {reconnected} reconnected, {} failed, {alive} already alive",
+                    outcome.failed.len()
+                );
+                if outcome.failed.is_empty() {
+                    self.push_toast(summary, ToastLevel::Success);
+                } else {
+                    let failures: Vec<String> = outcome
+                        .failed
+                        .iter()
+                        .map(|(port, reason)| format!("{port}: {reason}"))
+                        .collect();
+                    self.modal = Some(Modal::Notice(Notice {
+                        title: "Reconnect Tunnels".to_string(),
+                        message: format!("{summary}\n\n{}", failures.join("\n")),
+                        scroll: 0,
+                    }));
+                }
+            }
+            TaskResult::ActivateWorkspace {
+                name,
+                outcome,
+                alive,
+                syncs,
+            } => {
+                let started = outcome.reconnected.len();
+                self.update_state(|state| {
+                    for binding in outcome.reconnected {
+                        state
+                            .port_history
+                            .insert(binding.local_port, binding.droplet_name.clone());
+                        if let Some(existing) = state
+                            .bindings
+                            .iter_mut()
+                            .find(|existing| existing.local_port == binding.local_port)
+                        {
+                            *existing = binding;
+                        }
+                    }
+                });
+                let summary = format!(
+                    "Workspace {name}: {started} tunnel(s) started, {} failed, {alive} already running",
+                    outcome.failed.len()
+                );
+                self.report_workspace("Activate Workspace", summary, &outcome.failed, &syncs);
+            }
+            TaskResult::DeactivateWorkspace {
+                name,
+                stopped,
+                failed,
+                syncs,
+            } => {
+                self.update_state(|state| {
+                    state
+                        .bindings
+                        .retain(|binding| !stopped.contains(&binding.local_port))
+                });
+                self.clamp_binding_selection();
+                let summary = format!(
+                    "Workspace {name}: {} tunnel(s) stopped, {} failed",
+                    stopped.len(),
+                    failed.len()
+                );
+                self.report_workspace("Deactivate Workspace", summary, &failed, &syncs);
+            }
+            TaskResult::BindingHealth(results) => {
+                let broken = results.iter().filter(|(_, serving)| !serving).count();
+                self.binding_health.extend(results);
+                if broken > 0 {
+                    self.push_toast(
+                        format!("{broken} tunnel(s) alive but not accepting connections"),
+                        ToastLevel::Warning,
+                    );
                 }
+            }
+            TaskResult::SshConnection { binding, result } => match result {
+                Ok(()) => self.spawn(Task::StartTunnel(binding)),
                 Err(err) => self.push_toast(err.to_string(), ToastLevel::Error),
             },
             TaskResult::StartTunnel(res) => match res {
                 Ok(binding) => {
-                    self.state.bindings.push(binding);
-                    let _ = config::save_state(&self.state);
-                    self.push_toast("Port bound", ToastLevel::Success);
+                    let message = format!(
+                        "Bound local {} to {}:{}",
+                        binding.local_port, binding.droplet_name, binding.remote_port
+                    );
+                    let restarted = self.update_state(|state| {
+                        state
+                            .port_history
+                            .insert(binding.local_port, binding.droplet_name.clone());
+                        match state
+                            .bindings
+                            .iter_mut()
+                            .find(|existing| existing.local_port == binding.local_port)
+                        {
+                            Some(existing) => {
+                                *existing = binding;
+                                true
+                            }
+                            None => {
+                                state.bindings.push(binding);
+                                false
+                            }
+                        }
+                    });
+                    if restarted {
+                        self.push_toast("Tunnel restarted", ToastLevel::Success);
+                    } else {
+                        self.push_toast(message, ToastLevel::Success);
+                    }
                     self.modal = None;
                 }
                 Err(err) => self.push_toast(err.to_string(), ToastLevel::Error),
             },
             TaskResult::StopTunnel(res) => match res {
                 Ok(port) => {
-                    self.state
-                        .bindings
-                        .retain(|binding| binding.local_port != port);
-                    let _ = config::save_state(&self.state);
+                    self.update_state(|state| {
+                        state.bindings.retain(|binding| binding.local_port != port)
+                    });
+                    self.clamp_binding_selection();
                     self.push_toast("Port unbound", ToastLevel::Success);
                 }
                 Err(err) => self.push_toast(err.to_string(), ToastLevel::Error),
             },
-            TaskResult::CreateSyncs(res) => match res {
+            TaskResult::PlanSyncs { ssh, result } => match result {
+                Ok(plan) => self.confirm_sync_plan(ssh, plan),
+                Err(err) => self.push_toast(err.to_string(), ToastLevel::Error),
+            },
+            TaskResult::CreateSyncs { ssh, result } => match result {
                 Ok(count) => {
                     self.push_toast(
                         format!("Synced {count} folder{}", if count == 1 { "" } else { "s" }),
@@ -407,14 +1287,35 @@ impl App {
                     );
                     self.modal = None;
                 }
-                Err(err) => self.push_toast(err.to_string(), ToastLevel::Error),
+                Err(err) => match err.downcast_ref::<SyncBatchFailure>() {
+                    Some(failure) if !failure.created.is_empty() => {
+                        self.confirm_sync_rollback(ssh, failure.clone());
+                    }
+                    _ => self.push_toast(err.to_string(), ToastLevel::Error),
+                },
             },
+            TaskResult::RollbackSyncs(outcome) => self.show_rollback_outcome(outcome),
             TaskResult::RestoreSyncs(res) => match res {
-                Ok(count) => {
-                    self.push_toast(
-                        format!("Restored {count} sync{}", if count == 1 { "" } else { "s" }),
-                        ToastLevel::Success,
-                    );
+                Ok(outcome) => {
+                    let count = outcome.restored;
+                    let skipped = outcome.skipped_lines.len();
+                    if skipped == 0 {
+                        self.push_toast(
+                            format!("Restored {count} sync{}", if count == 1 { "" } else { "s" }),
+                            ToastLevel::Success,
+                        );
+                    } else {
+                        self.modal = Some(Modal::Notice(Notice {
+                            title: "Syncs Restored With Warnings".to_string(),
+                            message: format!(
+                                "Restored {count} sync{}, but skipped {skipped} malformed ~/.mountlist line{}:\n\n{}",
+                                if count == 1 { "" } else { "s" },
+                                if skipped == 1 { "" } else { "s" },
+                                outcome.skipped_lines.join("\n")
+                            ),
+                            scroll: 0,
+                        }));
+                    }
                 }
                 Err(err) => self.push_toast(err.to_string(), ToastLevel::Error),
             },
@@ -452,14 +1353,15 @@ impl App {
             },
             TaskResult::CreateRsyncBind(res) => match res {
                 Ok(bind) => {
-                    self.state
-                        .rsync_binds
-                        .retain(|item| !same_rsync_bind(item, &bind));
-                    self.state.rsync_binds.push(bind.clone());
-                    self.state
-                        .rsync_binds
-                        .sort_by(|a, b| a.local_path.cmp(&b.local_path));
-                    let _ = config::save_state(&self.state);
+                    self.update_state(|state| {
+                        state
+                            .rsync_binds
+                            .retain(|item| !same_rsync_bind(item, &bind));
+                        state.rsync_binds.push(bind.clone());
+                        state
+                            .rsync_binds
+                            .sort_by(|a, b| a.local_path.cmp(&b.local_path));
+                    });
 
                     if self.screen == Screen::RsyncBinds {
                         self.selected = self
@@ -495,41 +1397,132 @@ impl App {
                     self.modal = Some(Modal::Notice(Notice {
                         title: "Unable to Create RSYNC Bind".to_string(),
                         message: err.to_string(),
+                        scroll: 0,
                     }));
                 }
             },
-            TaskResult::RunRsync(res) => match res {
-                Ok(outcome) => {
-                    let action = match outcome.direction {
+            TaskResult::RunRsync {
+                bind,
+                direction,
+                result,
+            } => match result {
+                Ok(stats) => {
+                    let summary = stats.summary();
+                    self.record_rsync_result(&bind, direction, Ok(&summary));
+                    let action = match direction {
                         RsyncDirection::Up => "Pushed local changes to remote",
                         RsyncDirection::Down => "Pulled remote changes to local",
                     };
-                    self.push_toast(
-                        format!(
-                            "{action}: '{}' <-> '{}'",
-                            outcome.bind.remote_path, outcome.bind.local_path
-                        ),
-                        ToastLevel::Success,
+                    let mut message = format!(
+                        "{action}: '{}' <-> '{}'\n\n{summary}",
+                        bind.remote_path, bind.local_path
                     );
+                    if let Some(files) = &stats.files {
+                        message.push_str(&format!("\nFiles considered: {files}"));
+                    }
+                    message.push_str(&format!("\n\n{}", stats.output.trim()));
+                    self.modal = Some(Modal::Notice(Notice {
+                        title: "RSYNC Complete".to_string(),
+                        message,
+                        scroll: 0,
+                    }));
                 }
                 Err(err) => {
+                    self.record_rsync_result(&bind, direction, Err(&err.to_string()));
                     self.modal = Some(Modal::Notice(Notice {
                         title: "RSYNC Failed".to_string(),
                         message: err.to_string(),
+                        scroll: 0,
+                    }));
+                }
+            },
+            TaskResult::RsyncGroup {
+                group,
+                direction,
+                results,
+            } => {
+                let mut lines = Vec::new();
+                let mut failed = 0;
+                for (bind, result) in &results {
+                    match result {
+                        Ok(stats) => {
+                            let summary = stats.summary();
+                            self.record_rsync_result(bind, direction, Ok(&summary));
+                            lines.push(format!(
+                                "ok      {} <-> {}: {summary}",
+                                bind.remote_path, bind.local_path
+                            ));
+                        }
+                        Err(err) => {
+                            failed += 1;
+                            let error = err.to_string();
+                            self.record_rsync_result(bind, direction, Err(&error));
+                            lines.push(format!(
+                                "FAILED  {} <-> {}: {}",
+                                bind.remote_path,
+                                bind.local_path,
+                                error.lines().next().unwrap_or_default()
+                            ));
+                        }
+                    }
+                }
+                let verb = match direction {
+                    RsyncDirection::Up => "Push",
+                    RsyncDirection::Down => "Pull",
+                };
+                self.modal = Some(Modal::Notice(Notice {
+                    title: format!("RSYNC Group '{group}'"),
+                    message: format!(
+                        "{verb}: {} succeeded, {failed} failed\n\n{}",
+                        results.len() - failed,
+                        lines.join("\n")
+                    ),
+                    scroll: 0,
+                }));
+            }
+            TaskResult::RsyncRemote { bind, status } => {
+                self.rsync_remote_checks
+                    .insert(rsync_remote_key(&bind), (Utc::now(), status));
+            }
+            TaskResult::LocalCopySize { bind, size } => match size {
+                // Another dialog opened while sizing; don't replace it with the confirm.
+                Some(_) if self.modal.is_some() => self.push_toast(
+                    format!(
+                        "Bind to {}:{} was not deleted; close the open dialog and delete it again",
+                        bind.droplet_name, bind.remote_path
+                    ),
+                    ToastLevel::Warning,
+                ),
+                Some((path, size)) => {
+                    self.modal = Some(Modal::Confirm(Confirm {
+                        title: "Delete Local Copy?".to_string(),
+                        message: format!(
+                            "This permanently deletes '{path}' ({}) from this machine, along with the bind to {}:{}.\n\nFiles there that were never pushed are lost.",
+                            format_bytes(size),
+                            bind.droplet_name,
+                            bind.remote_path
+                        ),
+                        action: ConfirmAction::DeleteRsyncBindWithLocalCopy { bind },
                     }));
                 }
+                // Nothing left to delete locally; just drop the bind.
+                None => self.spawn(Task::DeleteRsyncBind {
+                    bind,
+                    delete_local_copy: false,
+                }),
             },
             TaskResult::DeleteRsyncBind(res) => match res {
                 Ok(outcome) => {
-                    self.state
-                        .rsync_binds
-                        .retain(|bind| !same_rsync_bind(bind, &outcome.bind));
+                    self.update_state(|state| {
+                        state
+                            .rsync_binds
+                            .retain(|bind| !same_rsync_bind(bind, &outcome.bind))
+                    });
                     if self.state.rsync_binds.is_empty() {
                         self.selected = 0;
                     } else if self.screen == Screen::RsyncBinds {
                         self.selected = self.selected.min(self.state.rsync_binds.len() - 1);
                     }
-                    let _ = config::save_state(&self.state);
                     self.modal = None;
                     if outcome.local_deleted {
                         self.push_toast(
@@ -544,6 +1537,7 @@ impl App {
                     self.modal = Some(Modal::Notice(Notice {
                         title: "Failed to Delete RSYNC Bind".to_string(),
                         message: err.to_string(),
+                        scroll: 0,
                     }));
                 }
             },
@@ -552,24 +1546,24 @@ impl App {
                 result,
             } => match result {
                 Ok(listing) => {
+                    self.remote_listing = None;
                     if let Some(Modal::RemoteBrowser(form)) = &mut self.modal {
-                        if form.current_path != requested_path {
-                            return;
-                        }
-                        form.current_path = listing.path.clone();
-                        form.entries =
-                            build_remote_browser_entries(&listing.path, listing.directories);
+                        form.current_path = listing.path;
+                        form.directories = listing.directories;
                         form.selected = 0;
                         form.loading = false;
+                        form.rebuild_entries();
                     }
                 }
                 Err(err) => {
+                    self.remote_listing = None;
                     if let Some(Modal::RemoteBrowser(form)) = &mut self.modal {
-                        if form.current_path == requested_path {
-                            form.loading = false;
-                        }
+                        form.loading = false;
                     }
-                    self.push_toast(err.to_string(), ToastLevel::Error);
+                    self.push_toast(
+                        format!("Listing {requested_path} failed: {err}"),
+                        ToastLevel::Error,
+                    );
                 }
             },
             TaskResult::DeleteDropletSyncs(res) => match res {
@@ -595,6 +1589,40 @@ impl App {
                 }
                 Err(err) => self.push_toast(err.to_string(), ToastLevel::Error),
             },
+            TaskResult::ReconcileSyncs { ssh, result } => match result {
+                Ok(report) => {
+                    if report.stale_entries.is_empty() && report.untracked_sessions.is_empty() {
+                        self.push_toast(
+                            format!(
+                                "Mountlist and Mutagen sessions agree ({} matched)",
+                                report.matched
+                            ),
+                            ToastLevel::Success,
+                        );
+                    } else {
+                        let droplet_name = self
+                            .selected_droplet()
+                            .map(|droplet| droplet.name.clone())
+                            .unwrap_or_else(|| ssh.host.clone());
+                        self.modal = Some(Modal::Reconcile(ReconcileForm {
+                            droplet_name,
+                            ssh,
+                            report,
+                        }));
+                    }
+                }
+                Err(err) => self.push_toast(err.to_string(), ToastLevel::Error),
+            },
+            TaskResult::PruneMountEntries(res) => match res {
+                Ok(count) => self.push_toast(
+                    format!(
+                        "Pruned {count} stale mountlist line{}",
+                        if count == 1 { "" } else { "s" }
+                    ),
+                    ToastLevel::Success,
+                ),
+                Err(err) => self.push_toast(err.to_string(), ToastLevel::Error),
+            },
             TaskResult::TerminateAllSyncs(res) => match res {
                 Ok(count) => {
                     self.push_toast(
@@ -604,6 +1632,10 @@ impl App {
                         ),
                         ToastLevel::Success,
                     );
+                    self.syncs.clear();
+                    if self.screen == Screen::Syncs {
+                        self.selected = 0;
+                    }
                     self.spawn(Task::LoadSyncs);
                 }
                 Err(err) => self.push_toast(err.to_string(), ToastLevel::Error),
@@ -612,11 +1644,36 @@ impl App {
     }
 
     pub fn handle_key(&mut self, key: KeyEvent) {
+        // The overlay sits above any modal, so Esc goes to it first.
+        if key.code == KeyCode::Esc && self.cancellable_pending > 0 && self.show_loading_overlay() {
+            self.cancel.cancel_all();
+            self.push_toast("Cancelling running tasks...", ToastLevel::Info);
+            return;
+        }
         if let Some(modal) = self.modal.clone() {
+            // Keys must not act on a modal the user can't see, e.g. `y` on a delete confirm.
+            if self.modal_hidden.get() {
+                match key.code {
+                    KeyCode::Esc => self.handle_modal_key(modal, key),
+                    KeyCode::Char('q') => self.should_quit = true,
+                    _ => {}
+                }
+                return;
+            }
             self.handle_modal_key(modal, key);
             return;
         }
 
+        let palette_key = match key.code {
+            KeyCode::Char(':') => true,
+            KeyCode::Char('p') => key.modifiers.contains(KeyModifiers::CONTROL),
+            _ => false,
+        };
+        if palette_key && !self.binding_filter_editing {
+            self.open_picker(PickerTarget::CommandPalette, None, vec![]);
+            return;
+        }
+
         match self.screen {
             Screen::Home => self.handle_home_key(key),
             Screen::Bindings => self.handle_bindings_key(key),
@@ -625,21 +1682,81 @@ impl App {
         }
     }
 
+    pub fn handle_mouse(&mut self, event: MouseEvent) {
+        // Modals have no list hit areas recorded; keep the click from reaching the screen behind.
+        if self.modal.is_some() {
+            return;
+        }
+        match event.kind {
+            MouseEventKind::ScrollDown => self.move_screen_selection(1),
+            MouseEventKind::ScrollUp => self.move_screen_selection(-1),
+            MouseEventKind::Down(MouseButton::Left) => {
+                let Some(hit) = self.list_area.get() else {
+                    return;
+                };
+                let rows = hit.rows;
+                if event.column < rows.x
+                    || event.column >= rows.x + rows.width
+                    || event.row < rows.y
+                    || event.row >= rows.y + rows.height
+                {
+                    return;
+                }
+                let index = hit.offset + (event.row - rows.y) as usize;
+                if index < self.screen_list_len() {
+                    self.selected = index;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn move_screen_selection(&mut self, delta: i32) {
+        match self.screen {
+            Screen::Home => self.move_selection(delta),
+            Screen::Bindings => self.move_binding_selection(delta),
+            Screen::Syncs => self.move_sync_selection(delta),
+            Screen::RsyncBinds => self.move_rsync_bind_selection(delta),
+        }
+    }
+
+    fn screen_list_len(&self) -> usize {
+        match self.screen {
+            Screen::Home => self.visible_indices().len(),
+            Screen::Bindings => self.visible_binding_indices().len(),
+            Screen::Syncs => self.syncs.len(),
+            Screen::RsyncBinds => self.state.rsync_binds.len(),
+        }
+    }
+
     fn handle_home_key(&mut self, key: KeyEvent) {
         match key.code {
             KeyCode::Char('q') => self.should_quit = true,
             KeyCode::Char('g') => self.refresh_all(),
+            KeyCode::Char('G') => match self.selected_droplet() {
+                Some(droplet) => self.spawn(Task::RefreshDroplet { id: droplet.id }),
+                None => self.push_toast("No droplet selected", ToastLevel::Warning),
+            },
             KeyCode::Char('c') => self.open_create_modal(),
+            KeyCode::Char('D') => self.duplicate_selected_droplet(),
+            KeyCode::Char('6') => self.copy_selected_ipv6(),
+            KeyCode::Char('*') => self.toggle_favorite(),
             KeyCode::Char('r') => self.open_restore_modal(),
             KeyCode::Char('s') => self.open_snapshot_modal(),
             KeyCode::Char('d') => self.open_delete_modal(),
             KeyCode::Char('b') => self.open_bind_modal(),
+            KeyCode::Char('R') => self.reconnect_all_tunnels(),
             KeyCode::Char('m') => self.open_mutagen_modal(),
+            KeyCode::Char('M') => self.remove_droplet_syncs(),
+            KeyCode::Char('C') => self.spawn(Task::LoadContexts),
+            KeyCode::Char('S') => self.show_config_location(),
+            KeyCode::Char('E') => self.show_error_log(),
             KeyCode::Char('o') => self.open_remote_browser(),
             KeyCode::Char('u') => self.open_rsync_binds_screen(),
             KeyCode::Char('p') => {
                 self.screen = Screen::Bindings;
                 self.selected = 0;
+                self.check_binding_health();
             }
             KeyCode::Char('f') => {
                 self.filter_running = !self.filter_running;
@@ -647,13 +1764,25 @@ impl App {
             }
             KeyCode::Down => self.move_selection(1),
             KeyCode::Up => self.move_selection(-1),
-            KeyCode::Enter => self.connect_selected(),
+            KeyCode::Enter => self.connect_selected(self.state.settings.connect_method, None),
+            KeyCode::Char('x') => {
+                self.connect_selected(self.state.settings.connect_method.other(), None)
+            }
+            KeyCode::Char('e') => self.open_connect_command_modal(),
             _ => {}
         }
     }
 
     fn handle_bindings_key(&mut self, key: KeyEvent) {
+        if self.binding_filter_editing {
+            self.handle_binding_filter_key(key);
+            return;
+        }
         match key.code {
+            KeyCode::Esc if !self.binding_filter.value.is_empty() => {
+                self.binding_filter = TextInput::new("");
+                self.clamp_binding_selection();
+            }
             KeyCode::Char('q') | KeyCode::Esc => {
                 self.screen = Screen::Home;
                 self.selected = 0;
@@ -662,10 +1791,70 @@ impl App {
             KeyCode::Up => self.move_binding_selection(-1),
             KeyCode::Char('d') => self.unbind_selected(),
             KeyCode::Char('x') => self.cleanup_stale(),
+            KeyCode::Char('r') => self.restart_selected_binding(),
+            KeyCode::Char('R') => self.reconnect_all_tunnels(),
+            KeyCode::Char('h') => self.check_binding_health(),
+            KeyCode::Char('l') => self.open_binding_label_modal(),
+            KeyCode::Char('w') => self.open_picker(PickerTarget::ActivateWorkspace, None, vec![]),
+            KeyCode::Char('W') => self.open_picker(PickerTarget::DeactivateWorkspace, None, vec![]),
+            KeyCode::Char('/') => self.binding_filter_editing = true,
+            KeyCode::Char('s') => {
+                self.binding_sort_by_port = !self.binding_sort_by_port;
+                self.selected = 0;
+            }
             _ => {}
         }
     }
 
+    fn open_binding_label_modal(&mut self) {
+        let Some(binding) = self.selected_binding() else {
+            return;
+        };
+        let form = BindingLabelForm {
+            local_address: ports::local_address(binding),
+            local_port: binding.local_port,
+            remote_port: binding.remote_port,
+            droplet_name: binding.droplet_name.clone(),
+            label: TextInput::new(binding.label.clone().unwrap_or_default()),
+        };
+        self.modal = Some(Modal::BindingLabel(form));
+    }
+
+    /// Probes the local port of every binding whose tunnel process is alive.
+    fn check_binding_health(&mut self) {
+        let targets: Vec<(String, u16)> = self
+            .state
+            .bindings
+            .iter()
+            .filter(|binding| binding.tunnel_pid.is_some_and(ports::is_pid_running))
+            .map(|binding| (binding.bind_address.clone(), binding.local_port))
+            .collect();
+        self.binding_health.clear();
+        if !targets.is_empty() {
+            self.spawn(Task::CheckBindingHealth(targets));
+        }
+    }
+
+    fn handle_binding_filter_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => {
+                self.binding_filter = TextInput::new("");
+                self.binding_filter_editing = false;
+            }
+            KeyCode::Enter => self.binding_filter_editing = false,
+            KeyCode::Down => self.move_binding_selection(1),
+            KeyCode::Up => self.move_binding_selection(-1),
+            KeyCode::Backspace => self.binding_filter.backspace(),
+            KeyCode::Char(ch) => {
+                if !key.modifiers.contains(KeyModifiers::CONTROL) {
+                    self.binding_filter.insert(ch);
+                }
+            }
+            _ => return,
+        }
+        self.clamp_binding_selection();
+    }
+
     fn handle_syncs_key(&mut self, key: KeyEvent) {
         match key.code {
             KeyCode::Char('q') | KeyCode::Esc => {
@@ -676,6 +1865,7 @@ impl App {
             KeyCode::Up => self.move_sync_selection(-1),
             KeyCode::Char('d') => self.terminate_selected_sync(),
             KeyCode::Char('g') => self.spawn(Task::LoadSyncs),
+            KeyCode::Char('X') => self.confirm_terminate_all_syncs(),
             _ => {}
         }
     }
@@ -689,15 +1879,29 @@ impl App {
             KeyCode::Down => self.move_rsync_bind_selection(1),
             KeyCode::Up => self.move_rsync_bind_selection(-1),
             KeyCode::Enter => self.open_selected_rsync_bind_actions(),
+            KeyCode::Char('n') => self.open_manual_rsync_bind_modal(),
             KeyCode::Char('?') | KeyCode::Char('h') => self.show_rsync_binds_shortcuts(),
             _ => {}
         }
     }
 
+    fn show_error_log(&mut self) {
+        if self.error_log.is_empty() {
+            self.push_toast("No errors this session", ToastLevel::Info);
+            return;
+        }
+        self.modal = Some(Modal::Notice(Notice {
+            title: format!("Recent Errors ({})", self.error_log.len()),
+            message: error_log_message(&self.error_log),
+            scroll: 0,
+        }));
+    }
+
     fn show_rsync_binds_shortcuts(&mut self) {
         self.modal = Some(Modal::Notice(Notice {
             title: "RSYNC Binds Shortcuts".to_string(),
-            message: "Up/Down: Move selection\nEnter: Open bind actions modal\nIn modal: Push/Pull/Finder/iTerm/Delete\nq/Esc: Back to Home\nh or ?: Show this help".to_string(),
+            message: "Up/Down: Move selection\nEnter: Open bind actions modal\nn: New bind from typed-in paths\nIn modal: Push/Pull/Finder/iTerm/Delete, g set group, U/D push/pull the whole group\nq/Esc: Back to Home\nh or ?: Show this help".to_string(),
+            scroll: 0,
         }));
     }
 
@@ -706,7 +1910,9 @@ impl App {
             self.push_toast("No rsync binds available", ToastLevel::Info);
             return;
         }
+        self.refresh_rsync_hosts();
         if let Some(bind) = self.state.rsync_binds.get(self.selected).cloned() {
+            self.check_rsync_remote(&bind);
             self.modal = Some(Modal::RsyncBindActions(RsyncBindActionsForm {
                 bind,
                 selected_action: 0,
@@ -714,6 +1920,63 @@ impl App {
         }
     }
 
+    /// Fetches DNS records the first time the details pane needs them and again once the
+    /// cache goes stale; nothing is fetched unless `show_dns_records` is on.
+    fn load_domain_records_if_needed(&mut self) {
+        if !self.state.settings.show_dns_records
+            || self.domain_records_loading
+            || self.screen != Screen::Home
+            || self.rate_limited_until.is_some()
+            || self
+                .selected_droplet()
+                .and_then(Droplet::public_ip)
+                .is_none()
+        {
+            return;
+        }
+        let fresh = self.domain_records.as_ref().is_some_and(|(fetched_at, _)| {
+            (Utc::now() - *fetched_at).num_seconds() < DOMAIN_RECORDS_TTL_SECS
+        });
+        if !fresh {
+            self.domain_records_loading = true;
+            self.spawn(Task::LoadDomainRecords);
+        }
+    }
+
+    /// Hostnames pointing at the droplet's public addresses, `None` until records load.
+    pub fn dns_hostnames(&self, droplet: &Droplet) -> Option<Vec<String>> {
+        let (_, records) = self.domain_records.as_ref()?;
+        let mut names: Vec<String> = [&droplet.public_ipv4, &droplet.public_ipv6]
+            .into_iter()
+            .flatten()
+            .flat_map(|ip| hostnames_for_ip(records, ip))
+            .collect();
+        // A and AAAA lists are sorted separately; a host with both must still show once.
+        names.sort();
+        names.dedup();
+        Some(names)
+    }
+
+    /// Probes the bind's remote folder unless a recent result is cached.
+    fn check_rsync_remote(&mut self, bind: &RsyncBind) {
+        let fresh = self
+            .rsync_remote_checks
+            .get(&rsync_remote_key(bind))
+            .is_some_and(|(checked_at, _)| {
+                (Utc::now() - *checked_at).num_seconds() < RSYNC_REMOTE_CHECK_TTL_SECS
+            });
+        if !fresh && self.tools.ssh {
+            self.spawn(Task::CheckRsyncRemote { bind: bind.clone() });
+        }
+    }
+
+    /// Cached remote-folder status for a bind, `None` while unchecked or probing.
+    pub fn rsync_remote_status(&self, bind: &RsyncBind) -> Option<&RemotePathStatus> {
+        self.rsync_remote_checks
+            .get(&rsync_remote_key(bind))
+            .map(|(_, status)| status)
+    }
+
     fn handle_modal_key(&mut self, modal: Modal, key: KeyEvent) {
         match modal {
             Modal::Create(mut form) => {
@@ -761,6 +2024,9 @@ impl App {
                     self.modal = Some(Modal::DeleteRsyncBind(form));
                 }
             }
+            Modal::Reconcile(form) => {
+                self.handle_reconcile_key(form, key);
+            }
             Modal::Notice(notice) => {
                 self.handle_notice_key(notice, key);
             }
@@ -769,8 +2035,23 @@ impl App {
                     self.modal = Some(Modal::Snapshot(form));
                 }
             }
+            Modal::RsyncBindGroup(mut form) => {
+                if self.handle_rsync_bind_group_key(&mut form, key) {
+                    self.modal = Some(Modal::RsyncBindGroup(form));
+                }
+            }
+            Modal::BindingLabel(mut form) => {
+                if self.handle_binding_label_key(&mut form, key) {
+                    self.modal = Some(Modal::BindingLabel(form));
+                }
+            }
+            Modal::ConnectCommand(mut form) => {
+                if self.handle_connect_command_key(&mut form, key) {
+                    self.modal = Some(Modal::ConnectCommand(form));
+                }
+            }
             Modal::Picker { mut picker, parent } => {
-                let parent_clone = (*parent).clone();
+                let parent_clone = parent.as_deref().cloned();
                 if self.handle_picker_key(&mut picker, key, parent_clone) {
                     self.modal = Some(Modal::Picker { picker, parent });
                 }
@@ -787,6 +2068,13 @@ impl App {
                 self.modal = None;
                 return false;
             }
+            KeyCode::Char('y') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                return self.copy_create_command(form);
+            }
+            KeyCode::Char('b') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                form.background = !form.background;
+                return true;
+            }
             KeyCode::Tab | KeyCode::Down => {
                 form.focus = (form.focus + 1) % 8;
                 return true;
@@ -801,7 +2089,7 @@ impl App {
                     1 => {
                         self.open_picker(
                             PickerTarget::CreateRegion,
-                            Modal::Create(form.clone()),
+                            Some(Modal::Create(form.clone())),
                             vec![],
                         );
                         return false;
@@ -809,7 +2097,7 @@ impl App {
                     2 => {
                         self.open_picker(
                             PickerTarget::CreateSize,
-                            Modal::Create(form.clone()),
+                            Some(Modal::Create(form.clone())),
                             vec![],
                         );
                         return false;
@@ -817,7 +2105,7 @@ impl App {
                     3 => {
                         self.open_picker(
                             PickerTarget::CreateImage,
-                            Modal::Create(form.clone()),
+                            Some(Modal::Create(form.clone())),
                             vec![],
                         );
                         return false;
@@ -825,7 +2113,7 @@ impl App {
                     4 => {
                         self.open_picker(
                             PickerTarget::CreateSshKeys,
-                            Modal::Create(form.clone()),
+                            Some(Modal::Create(form.clone())),
                             form.ssh_keys.clone(),
                         );
                         return false;
@@ -871,13 +2159,17 @@ impl App {
                 form.focus = (form.focus + 7) % 8;
                 return true;
             }
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.use_source_specs(form);
+                return true;
+            }
             KeyCode::Enter => {
                 match form.focus {
                     0 => form.focus = 1,
                     1 => {
                         self.open_picker(
                             PickerTarget::RestoreSnapshot,
-                            Modal::Restore(form.clone()),
+                            Some(Modal::Restore(form.clone())),
                             vec![],
                         );
                         return false;
@@ -885,7 +2177,7 @@ impl App {
                     2 => {
                         self.open_picker(
                             PickerTarget::RestoreRegion,
-                            Modal::Restore(form.clone()),
+                            Some(Modal::Restore(form.clone())),
                             vec![],
                         );
                         return false;
@@ -893,7 +2185,7 @@ impl App {
                     3 => {
                         self.open_picker(
                             PickerTarget::RestoreSize,
-                            Modal::Restore(form.clone()),
+                            Some(Modal::Restore(form.clone())),
                             vec![],
                         );
                         return false;
@@ -901,7 +2193,7 @@ impl App {
                     4 => {
                         self.open_picker(
                             PickerTarget::RestoreSshKeys,
-                            Modal::Restore(form.clone()),
+                            Some(Modal::Restore(form.clone())),
                             form.ssh_keys.clone(),
                         );
                         return false;
@@ -939,20 +2231,42 @@ impl App {
                 self.modal = None;
                 return false;
             }
+            KeyCode::Char('a') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                toggle_ssh_agent(
+                    &mut form.use_agent,
+                    &form.ssh_key_path,
+                    &mut form.key_warning,
+                );
+                return true;
+            }
+            _ if form.focus == 3 && leaves_field(&key) => {
+                check_ssh_key_field(
+                    &mut form.ssh_key_path,
+                    form.use_agent,
+                    &mut form.key_warning,
+                );
+            }
+            _ => {}
+        }
+        match key.code {
             KeyCode::Tab | KeyCode::Down => {
-                form.focus = (form.focus + 1) % 6;
+                form.focus = (form.focus + 1) % 8;
                 return true;
             }
             KeyCode::BackTab | KeyCode::Up => {
-                form.focus = (form.focus + 5) % 6;
+                form.focus = (form.focus + 7) % 8;
                 return true;
             }
             KeyCode::Enter => {
-                if form.focus == 5 {
+                if form.focus == 7 {
                     self.submit_bind_form(form.clone());
                     return false;
                 }
-                form.focus = (form.focus + 1) % 6;
+                form.focus = (form.focus + 1) % 8;
+                return true;
+            }
+            KeyCode::Char(' ') | KeyCode::Left | KeyCode::Right if form.focus == 7 => {
+                self.toggle_bind_interface(form);
                 return true;
             }
             _ => {}
@@ -964,6 +2278,8 @@ impl App {
             2 => &mut form.ssh_user,
             3 => &mut form.ssh_key_path,
             4 => &mut form.ssh_port,
+            5 => &mut form.bind_address,
+            6 => &mut form.label,
             _ => return true,
         };
         handle_text_input(input, key);
@@ -976,6 +2292,24 @@ impl App {
                 self.modal = None;
                 return false;
             }
+            KeyCode::Char('a') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                toggle_ssh_agent(
+                    &mut form.use_agent,
+                    &form.ssh_key_path,
+                    &mut form.key_warning,
+                );
+                return true;
+            }
+            _ if form.focus == 2 && leaves_field(&key) => {
+                check_ssh_key_field(
+                    &mut form.ssh_key_path,
+                    form.use_agent,
+                    &mut form.key_warning,
+                );
+            }
+            _ => {}
+        }
+        match key.code {
             KeyCode::Tab | KeyCode::Down => {
                 form.focus = (form.focus + 1) % 6;
                 return true;
@@ -984,6 +2318,10 @@ impl App {
                 form.focus = (form.focus + 5) % 6;
                 return true;
             }
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.browse_remote_sync_path(form.clone());
+                return false;
+            }
             KeyCode::Enter => {
                 if form.focus == 4 {
                     self.submit_sync_form(form.clone());
@@ -1044,11 +2382,73 @@ impl App {
 
     fn handle_remote_browser_key(&mut self, form: &mut RemoteBrowserForm, key: KeyEvent) -> bool {
         match key.code {
+            _ if form.filter_editing => {
+                match key.code {
+                    KeyCode::Esc => {
+                        form.filter_editing = false;
+                        form.filter = TextInput::new("");
+                        form.selected = 0;
+                    }
+                    KeyCode::Enter => form.filter_editing = false,
+                    KeyCode::Backspace => form.filter.backspace(),
+                    KeyCode::Up => form.selected = form.selected.saturating_sub(1),
+                    KeyCode::Down => {
+                        form.selected =
+                            (form.selected + 1).min(form.entries.len().saturating_sub(1))
+                    }
+                    KeyCode::Char(ch) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        form.filter.insert(ch);
+                        form.selected = 0;
+                    }
+                    _ => return true,
+                }
+                form.rebuild_entries();
+                return true;
+            }
             KeyCode::Esc => {
-                self.modal = None;
+                self.cancel_remote_listing();
+                self.modal = form.sync_form.take().map(Modal::Sync);
+                return false;
+            }
+            KeyCode::Char('/') => {
+                form.filter_editing = true;
+                return true;
+            }
+            KeyCode::Char('.') => {
+                form.show_hidden = !form.show_hidden;
+                form.rebuild_entries();
+                return true;
+            }
+            KeyCode::Char('i') => {
+                form.case_insensitive = !form.case_insensitive;
+                form.rebuild_entries();
+                return true;
+            }
+            KeyCode::PageUp => {
+                form.selected = form.selected.saturating_sub(REMOTE_BROWSER_PAGE);
+                return true;
+            }
+            KeyCode::PageDown => {
+                form.selected =
+                    (form.selected + REMOTE_BROWSER_PAGE).min(form.entries.len().saturating_sub(1));
+                return true;
+            }
+            // Navigation stays live while a level loads; it supersedes the pending listing.
+            // Actions on the (stale) entries wait for the listing.
+            KeyCode::Char('s' | 'o' | 'm') if form.loading => return true,
+            KeyCode::Char('s') if form.sync_form.is_some() => {
+                let remote = form
+                    .entries
+                    .get(form.selected)
+                    .map(|entry| entry.path.clone())
+                    .unwrap_or_else(|| form.current_path.clone());
+                if let Some(mut sync_form) = form.sync_form.take() {
+                    sync_form.local_paths = with_remote_sync_path(&sync_form.local_paths, &remote);
+                    sync_form.focus = 0;
+                    self.modal = Some(Modal::Sync(sync_form));
+                }
                 return false;
             }
-            _ if form.loading => return true,
             KeyCode::Up => {
                 if form.selected > 0 {
                     form.selected -= 1;
@@ -1076,7 +2476,7 @@ impl App {
                 }
                 return true;
             }
-            KeyCode::Char('o') => {
+            KeyCode::Char('o') if form.sync_form.is_none() => {
                 if let Some(entry) = form.entries.get(form.selected) {
                     self.open_cursor_remote_folder(form, entry.path.clone());
                     return false;
@@ -1084,7 +2484,7 @@ impl App {
                 self.push_toast("No folder selected", ToastLevel::Warning);
                 return true;
             }
-            KeyCode::Char('m') => {
+            KeyCode::Char('m') if form.sync_form.is_none() => {
                 if let Some(entry) = form.entries.get(form.selected) {
                     self.open_rsync_bind_modal(form, entry.path.clone());
                     return false;
@@ -1098,6 +2498,9 @@ impl App {
     }
 
     fn handle_rsync_bind_form_key(&mut self, form: &mut RsyncBindForm, key: KeyEvent) -> bool {
+        if form.manual.is_some() {
+            return self.handle_manual_rsync_bind_key(form, key);
+        }
         match key.code {
             KeyCode::Esc => {
                 self.modal = None;
@@ -1134,6 +2537,81 @@ impl App {
         true
     }
 
+    /// Focus order: droplet, remote path, local path, SSH user, key, port, submit, cancel.
+    fn handle_manual_rsync_bind_key(&mut self, form: &mut RsyncBindForm, key: KeyEvent) -> bool {
+        const FIELDS: usize = 8;
+        match key.code {
+            KeyCode::Esc => {
+                self.modal = None;
+                return false;
+            }
+            KeyCode::Char('a') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if let Some(fields) = form.manual.as_mut() {
+                    toggle_ssh_agent(
+                        &mut fields.use_agent,
+                        &fields.ssh_key_path,
+                        &mut fields.key_warning,
+                    );
+                }
+                return true;
+            }
+            _ if form.focus == 4 && leaves_field(&key) => {
+                if let Some(fields) = form.manual.as_mut() {
+                    check_ssh_key_field(
+                        &mut fields.ssh_key_path,
+                        fields.use_agent,
+                        &mut fields.key_warning,
+                    );
+                }
+            }
+            _ => {}
+        }
+        match key.code {
+            KeyCode::Tab | KeyCode::Down => {
+                form.focus = (form.focus + 1) % FIELDS;
+                return true;
+            }
+            KeyCode::BackTab | KeyCode::Up => {
+                form.focus = (form.focus + FIELDS - 1) % FIELDS;
+                return true;
+            }
+            KeyCode::Enter => match form.focus {
+                0 => {
+                    let parent = Modal::RsyncBind(form.clone());
+                    self.open_picker(PickerTarget::RsyncBindDroplet, Some(parent), Vec::new());
+                    return false;
+                }
+                6 => {
+                    self.submit_rsync_bind_form(form.clone());
+                    return false;
+                }
+                7 => {
+                    self.modal = None;
+                    return false;
+                }
+                _ => {
+                    form.focus += 1;
+                    return true;
+                }
+            },
+            _ => {}
+        }
+
+        let Some(fields) = form.manual.as_mut() else {
+            return true;
+        };
+        let input = match form.focus {
+            1 => &mut fields.remote_path,
+            2 => &mut form.local_path,
+            3 => &mut fields.ssh_user,
+            4 => &mut fields.ssh_key_path,
+            5 => &mut fields.ssh_port,
+            _ => return true,
+        };
+        handle_text_input(input, key);
+        true
+    }
+
     fn handle_rsync_bind_actions_key(
         &mut self,
         form: &mut RsyncBindActionsForm,
@@ -1163,6 +2641,37 @@ impl App {
                 }
                 return true;
             }
+            KeyCode::Char('p') => {
+                self.update_rsync_bind_options(form, |bind| {
+                    bind.rsync_profile = bind.rsync_profile.next()
+                });
+                return true;
+            }
+            KeyCode::Char('z') => {
+                self.update_rsync_bind_options(form, |bind| bind.compress = !bind.compress);
+                return true;
+            }
+            KeyCode::Char('c') => {
+                self.update_rsync_bind_options(form, |bind| bind.checksum = !bind.checksum);
+                return true;
+            }
+            KeyCode::Char('g') => {
+                self.modal = Some(Modal::RsyncBindGroup(RsyncBindGroupForm {
+                    group: TextInput::new(form.bind.group.clone().unwrap_or_default()),
+                    bind: form.bind.clone(),
+                }));
+                return false;
+            }
+            KeyCode::Char('U') => {
+                self.modal = None;
+                self.run_rsync_group(&form.bind, RsyncDirection::Up);
+                return false;
+            }
+            KeyCode::Char('D') => {
+                self.modal = None;
+                self.run_rsync_group(&form.bind, RsyncDirection::Down);
+                return false;
+            }
             KeyCode::Down => {
                 if form.selected_action < 2 {
                     form.selected_action = 2;
@@ -1176,8 +2685,9 @@ impl App {
                     return false;
                 }
                 1 => {
-                    self.run_selected_rsync(RsyncDirection::Down);
+                    // Cleared first so a pull-overwrite confirm can take its place.
                     self.modal = None;
+                    self.run_selected_rsync(RsyncDirection::Down);
                     return false;
                 }
                 2 => {
@@ -1204,6 +2714,36 @@ impl App {
         true
     }
 
+    fn handle_rsync_bind_group_key(
+        &mut self,
+        form: &mut RsyncBindGroupForm,
+        key: KeyEvent,
+    ) -> bool {
+        match key.code {
+            KeyCode::Esc => {
+                self.modal = None;
+                return false;
+            }
+            KeyCode::Enter => {
+                let group = normalize_label(&form.group.value);
+                let bind = form.bind.clone();
+                self.update_state(|state| {
+                    if let Some(stored) = state
+                        .rsync_binds
+                        .iter_mut()
+                        .find(|item| same_rsync_bind(item, &bind))
+                    {
+                        stored.group = group;
+                    }
+                });
+                self.modal = None;
+                return false;
+            }
+            _ => handle_text_input(&mut form.group, key),
+        }
+        true
+    }
+
     fn handle_delete_rsync_bind_key(
         &mut self,
         form: &mut DeleteRsyncBindForm,
@@ -1219,9 +2759,26 @@ impl App {
                 return true;
             }
             KeyCode::Enter | KeyCode::Char('y') | KeyCode::Char('Y') => {
-                self.spawn(Task::DeleteRsyncBind {
+                if !form.delete_local_copy {
+                    self.spawn(Task::DeleteRsyncBind {
+                        bind: form.bind.clone(),
+                        delete_local_copy: false,
+                    });
+                    self.modal = None;
+                    return false;
+                }
+                // Checked before sizing so a bind at `~` or `/` is refused without walking it.
+                if let Some(problem) = tasks::local_delete_problem(&form.bind.local_path) {
+                    form.delete_local_copy = false;
+                    self.push_toast(
+                        format!("Won't delete the local copy: {problem}"),
+                        ToastLevel::Error,
+                    );
+                    return true;
+                }
+                // The confirmation opens once the folder has been sized off the UI thread.
+                self.spawn(Task::MeasureLocalCopy {
                     bind: form.bind.clone(),
-                    delete_local_copy: form.delete_local_copy,
                 });
                 self.modal = None;
                 return false;
@@ -1235,15 +2792,105 @@ impl App {
         true
     }
 
-    fn handle_notice_key(&mut self, _notice: Notice, key: KeyEvent) {
+    fn handle_reconcile_key(&mut self, form: ReconcileForm, key: KeyEvent) {
         match key.code {
-            KeyCode::Esc | KeyCode::Enter | KeyCode::Char(' ') | KeyCode::Char('q') => {
+            KeyCode::Char('p') => {
+                if form.report.stale_entries.is_empty() {
+                    self.push_toast("No stale mountlist entries to prune", ToastLevel::Info);
+                    return;
+                }
+                let names = form
+                    .report
+                    .stale_entries
+                    .iter()
+                    .map(|entry| entry.name.clone())
+                    .collect();
+                self.spawn(Task::PruneMountEntries {
+                    ssh: form.ssh,
+                    names,
+                });
+                self.modal = None;
+            }
+            KeyCode::Char('r') => {
+                if form.report.stale_entries.is_empty() {
+                    self.push_toast("No missing sessions to recreate", ToastLevel::Info);
+                    return;
+                }
+                self.spawn(Task::RestoreSyncs { ssh: form.ssh });
+                self.modal = None;
+            }
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
                 self.modal = None;
             }
             _ => {}
         }
     }
 
+    fn handle_notice_key(&mut self, mut notice: Notice, key: KeyEvent) {
+        let max_scroll = self.notice_max_scroll.get();
+        notice.scroll = match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char(' ') | KeyCode::Char('q') => {
+                self.modal = None;
+                return;
+            }
+            KeyCode::Down | KeyCode::Char('j') => notice.scroll.saturating_add(1),
+            KeyCode::Up | KeyCode::Char('k') => notice.scroll.saturating_sub(1),
+            KeyCode::PageDown => notice.scroll.saturating_add(NOTICE_PAGE_LINES),
+            KeyCode::PageUp => notice.scroll.saturating_sub(NOTICE_PAGE_LINES),
+            KeyCode::Home => 0,
+            KeyCode::End => max_scroll,
+            _ => return,
+        }
+        .min(max_scroll);
+        self.modal = Some(Modal::Notice(notice));
+    }
+
+    fn handle_binding_label_key(&mut self, form: &mut BindingLabelForm, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Esc => {
+                self.modal = None;
+                return false;
+            }
+            KeyCode::Enter => {
+                let label = normalize_label(&form.label.value);
+                let local_port = form.local_port;
+                self.update_state(|state| {
+                    if let Some(binding) = state
+                        .bindings
+                        .iter_mut()
+                        .find(|binding| binding.local_port == local_port)
+                    {
+                        binding.label = label;
+                    }
+                });
+                self.modal = None;
+                return false;
+            }
+            _ => handle_text_input(&mut form.label, key),
+        }
+        true
+    }
+
+    fn handle_connect_command_key(&mut self, form: &mut ConnectCommandForm, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Esc => {
+                self.modal = None;
+                return false;
+            }
+            KeyCode::Tab | KeyCode::BackTab => form.method = form.method.other(),
+            KeyCode::Enter => {
+                self.modal = None;
+                let command = form.command.value.trim().to_string();
+                self.last_connect_command = command.clone();
+                let command = (!command.is_empty()).then_some(command.as_str());
+                self.connect_selected(form.method, command);
+                return false;
+            }
+            _ => handle_text_input(&mut form.command, key),
+        }
+        true
+    }
+
     fn handle_snapshot_key(&mut self, form: &mut SnapshotForm, key: KeyEvent) -> bool {
         match key.code {
             KeyCode::Esc => {
@@ -1256,43 +2903,55 @@ impl App {
                     self.push_toast("Snapshot name required", ToastLevel::Warning);
                     return true;
                 }
+                let power_off = if form.power_off {
+                    "Power off, then create"
+                } else {
+                    "Create"
+                };
                 let confirm = Confirm {
                     title: "Snapshot + Delete".to_string(),
                     message: format!(
-                        "Create snapshot '{}' and delete droplet '{}' ?",
-                        name, form.droplet_name
+                        "{power_off} snapshot '{}' and delete droplet '{}' ?\n\n{}",
+                        name,
+                        form.droplet_name,
+                        snapshot_cost_note(form.disk_gb)
                     ),
                     action: ConfirmAction::SnapshotDelete {
                         droplet_id: form.droplet_id,
                         snapshot_name: name,
+                        power_off: form.power_off,
                     },
                 };
                 self.modal = Some(Modal::Confirm(confirm));
                 return false;
             }
+            KeyCode::Char('o') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                form.power_off = !form.power_off;
+            }
             _ => handle_text_input(&mut form.snapshot_name, key),
         }
         true
     }
 
-    fn handle_picker_key(&mut self, picker: &mut Picker, key: KeyEvent, parent: Modal) -> bool {
+    fn handle_picker_key(
+        &mut self,
+        picker: &mut Picker,
+        key: KeyEvent,
+        parent: Option<Modal>,
+    ) -> bool {
+        if matches!(key.code, KeyCode::Esc | KeyCode::Enter) {
+            self.picker_queries
+                .insert(picker.target, picker.query.value.clone());
+        }
         match key.code {
             KeyCode::Esc => {
-                self.modal = Some(parent);
+                self.modal = parent;
                 return false;
             }
-            KeyCode::Up => {
-                if picker.selected > 0 {
-                    picker.selected -= 1;
-                }
-            }
-            KeyCode::Down => {
-                if picker.selected + 1 < picker.filtered.len() {
-                    picker.selected += 1;
-                }
-            }
+            KeyCode::Up => picker.move_selection(-1),
+            KeyCode::Down => picker.move_selection(1),
             KeyCode::Char(' ') if picker.multi => {
-                if let Some(&idx) = picker.filtered.get(picker.selected) {
+                if let Some(idx) = picker.selected_index() {
                     if picker.chosen.contains(&idx) {
                         picker.chosen.remove(&idx);
                     } else {
@@ -1301,6 +2960,9 @@ impl App {
                 }
             }
             KeyCode::Enter => {
+                if !picker.multi && picker.selected_index().is_none() {
+                    return true;
+                }
                 self.apply_picker_selection(picker.clone(), parent);
                 return false;
             }
@@ -1325,16 +2987,34 @@ impl App {
                 ConfirmAction::SnapshotDelete {
                     droplet_id,
                     snapshot_name,
+                    power_off,
                 } => {
                     self.spawn(Task::SnapshotDelete {
                         droplet_id,
                         snapshot_name,
+                        power_off,
+                    });
+                    self.modal = None;
+                }
+                ConfirmAction::DeleteDroplet {
+                    droplet_id,
+                    droplet_name,
+                } => {
+                    self.spawn(Task::DeleteDroplet {
+                        droplet_id,
+                        droplet_name,
                     });
                     self.modal = None;
                 }
-                ConfirmAction::DeleteDroplet { droplet_id } => {
-                    self.spawn(Task::DeleteDroplet { droplet_id });
+                ConfirmAction::RestoreDeleted {
+                    droplet_name,
+                    snapshot_id,
+                } => {
+                    self.open_restore_from_snapshot(droplet_name, snapshot_id);
+                }
+                ConfirmAction::TerminateSyncing { sync } => {
                     self.modal = None;
+                    self.delete_sync(sync, true);
                 }
                 ConfirmAction::RestoreSyncs { ssh, .. } => {
                     self.spawn(Task::RestoreSyncs { ssh });
@@ -1348,23 +3028,153 @@ impl App {
                     self.spawn(Task::TerminateAllSyncs);
                     self.modal = None;
                 }
+                ConfirmAction::RemoveOrphanBindings { local_ports } => {
+                    self.modal = None;
+                    self.remove_bindings(&local_ports);
+                }
+                ConfirmAction::DeleteRsyncBindWithLocalCopy { bind } => {
+                    self.modal = None;
+                    self.spawn(Task::DeleteRsyncBind {
+                        bind,
+                        delete_local_copy: true,
+                    });
+                }
+                ConfirmAction::PullRsync { bind } => {
+                    self.modal = None;
+                    self.pull_acknowledged.insert(bind.local_path.clone());
+                    self.spawn(Task::RunRsync {
+                        bind,
+                        direction: RsyncDirection::Down,
+                    });
+                }
+                ConfirmAction::PullRsyncGroup { group, binds } => {
+                    self.modal = None;
+                    self.pull_acknowledged
+                        .extend(binds.iter().map(|bind| bind.local_path.clone()));
+                    self.spawn(Task::RunRsyncGroup {
+                        group,
+                        binds,
+                        direction: RsyncDirection::Down,
+                    });
+                }
+                ConfirmAction::CreateSyncs { ssh, plan, .. } => {
+                    self.modal = None;
+                    self.spawn(Task::CreateSyncs { ssh, plan });
+                }
+                ConfirmAction::RollbackSyncs { ssh, created } => {
+                    self.modal = None;
+                    self.spawn(Task::RollbackSyncs { ssh, created });
+                }
+                ConfirmAction::CreateDroplet { form } => {
+                    self.modal = Some(Modal::Create(form.clone()));
+                    self.create_droplet_from_form(&form);
+                }
             },
+            KeyCode::Char('t') | KeyCode::Char('T') => {
+                if let ConfirmAction::TerminateSyncing { sync } = confirm.action {
+                    self.modal = None;
+                    self.delete_sync(sync, false);
+                } else {
+                    self.modal = Some(Modal::Confirm(confirm));
+                }
+            }
             KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
-                self.modal = None;
+                self.modal = match confirm.action {
+                    ConfirmAction::CreateSyncs {
+                        form: Some(form), ..
+                    } => Some(Modal::Sync(form)),
+                    ConfirmAction::CreateDroplet { form } => Some(Modal::Create(form)),
+                    _ => None,
+                };
             }
             _ => {}
         }
     }
 
     fn open_create_modal(&mut self) {
+        let last = self.state.settings.last_create.clone().unwrap_or_default();
+        let suggested_name = self
+            .state
+            .settings
+            .name_template
+            .as_deref()
+            .and_then(|template| {
+                let existing: Vec<&str> = self.droplets.iter().map(|d| d.name.as_str()).collect();
+                next_template_name(template, &existing)
+            })
+            .unwrap_or_default();
         let form = CreateForm {
-            name: TextInput::new(""),
-            region: None,
-            size: None,
-            image: None,
-            ssh_keys: Vec::new(),
+            name: TextInput::new(suggested_name),
+            region: last.region.as_ref().map(selection_from_saved),
+            size: last.size.as_ref().map(selection_from_saved),
+            image: last.image.as_ref().map(selection_from_saved),
+            ssh_keys: last.ssh_keys.iter().map(selection_from_saved).collect(),
             tags: TextInput::new(""),
             focus: 0,
+            background: false,
+        };
+        self.modal = Some(Modal::Create(form));
+    }
+
+    /// Opens the create form prefilled from the selected droplet's region, size, image, and
+    /// tags, with the next free name after it.
+    fn duplicate_selected_droplet(&mut self) {
+        let Some(droplet) = self.selected_droplet().cloned() else {
+            self.push_toast("No droplet selected", ToastLevel::Warning);
+            return;
+        };
+        let existing: Vec<&str> = self.droplets.iter().map(|d| d.name.as_str()).collect();
+        let name = duplicate_name(&droplet.name, &existing).unwrap_or_default();
+        let region = self
+            .regions
+            .iter()
+            .find(|region| region.slug == droplet.region)
+            .map(|region| format!("{} ({})", region.slug, region.name))
+            .unwrap_or_else(|| droplet.region.clone());
+        let size = droplet.size.as_ref().map(|slug| Selection {
+            label: self
+                .sizes
+                .iter()
+                .find(|size| &size.slug == slug)
+                .map(|size| {
+                    format!(
+                        "{} ({}MB, {} vCPU, {}GB)",
+                        size.slug, size.memory_mb, size.vcpus, size.disk_gb
+                    )
+                })
+                .unwrap_or_else(|| slug.clone()),
+            value: slug.clone(),
+        });
+        // Only public images can be recreated by slug; anything else needs picking again.
+        let image = droplet.image.as_ref().and_then(|slug| {
+            let image = self
+                .images
+                .iter()
+                .find(|image| image.slug.as_ref() == Some(slug))?;
+            Some(Selection {
+                label: format!("{} ({slug})", image.name),
+                value: slug.clone(),
+            })
+        });
+        if image.is_none() {
+            self.push_toast(
+                "The original image can't be recovered (custom, snapshot, or retired); pick one",
+                ToastLevel::Info,
+            );
+        }
+        let last = self.state.settings.last_create.clone().unwrap_or_default();
+        let form = CreateForm {
+            name: TextInput::new(name),
+            region: Some(Selection {
+                label: region,
+                value: droplet.region.clone(),
+            }),
+            size,
+            image,
+            ssh_keys: last.ssh_keys.iter().map(selection_from_saved).collect(),
+            tags: TextInput::new(droplet.tags.join(", ")),
+            focus: 0,
+            background: false,
         };
         self.modal = Some(Modal::Create(form));
     }
@@ -1383,7 +3193,59 @@ impl App {
         self.modal = Some(Modal::Restore(form));
     }
 
+    /// Not a real undo: offers to restore the deleted droplet from its latest snapshot, if any.
+    fn offer_restore_after_delete(&mut self, droplet_id: u64, droplet_name: String) {
+        let Some(snapshot) = latest_snapshot_of(&self.snapshots, droplet_id) else {
+            self.push_toast(
+                format!("Droplet '{droplet_name}' deleted; it has no snapshot to restore from"),
+                ToastLevel::Success,
+            );
+            return;
+        };
+        let confirm = Confirm {
+            title: "Droplet Deleted".to_string(),
+            message: format!(
+                "Droplet '{droplet_name}' deleted.\n\nIts latest snapshot is '{}' ({}). Restore from it? This opens the restore form prefilled with that snapshot.",
+                snapshot.name, snapshot.created_at
+            ),
+            action: ConfirmAction::RestoreDeleted {
+                droplet_name,
+                snapshot_id: snapshot.id,
+            },
+        };
+        self.modal = Some(Modal::Confirm(confirm));
+    }
+
+    fn open_restore_from_snapshot(&mut self, droplet_name: String, snapshot_id: u64) {
+        let Some(snapshot) = self
+            .snapshots
+            .iter()
+            .find(|snapshot| snapshot.id == snapshot_id)
+        else {
+            self.modal = None;
+            self.push_toast("Snapshot no longer available", ToastLevel::Warning);
+            return;
+        };
+        let mut form = RestoreForm {
+            name: TextInput::new(droplet_name),
+            snapshot: Some(Selection {
+                label: format!("{} ({})", snapshot.name, snapshot.created_at),
+                value: snapshot.id.to_string(),
+            }),
+            region: None,
+            size: None,
+            ssh_keys: Vec::new(),
+            tags: TextInput::new(""),
+            focus: 0,
+        };
+        self.use_source_specs(&mut form);
+        self.modal = Some(Modal::Restore(form));
+    }
+
     fn open_bind_modal(&mut self) {
+        if !self.require_tool(self.tools.ssh, "ssh") {
+            return;
+        }
         let droplet = match self.selected_droplet() {
             Some(droplet) => droplet.clone(),
             None => {
@@ -1395,23 +3257,37 @@ impl App {
             self.push_toast("Droplet must be running", ToastLevel::Warning);
             return;
         }
-        let public_ip = match droplet.public_ipv4.clone() {
-            Some(ip) => ip,
-            None => {
-                self.push_toast("Droplet has no public IP", ToastLevel::Warning);
-                return;
-            }
+        let Some(interface) = default_bind_interface(&droplet, self.state.settings.preferred_ip)
+        else {
+            self.push_toast(
+                "Droplet has neither a public nor a private IP",
+                ToastLevel::Warning,
+            );
+            return;
         };
+        if droplet.public_ip().is_none() {
+            self.push_toast(
+                "Droplet has no public IP; the tunnel will use its private IP (VPC or VPN only)",
+                ToastLevel::Info,
+            );
+        }
+        let public_ip = droplet.public_ip().map(str::to_string);
         let settings = &self.state.settings;
         let form = BindForm {
             droplet_id: droplet.id,
             droplet_name: droplet.name,
             public_ip,
+            private_ip: droplet.private_ipv4,
+            interface,
             local_port: TextInput::new(""),
             remote_port: TextInput::new(""),
             ssh_user: TextInput::new(settings.default_ssh_user.clone()),
             ssh_key_path: TextInput::new(settings.default_ssh_key_path.clone()),
+            key_warning: None,
+            use_agent: false,
             ssh_port: TextInput::new(settings.default_ssh_port.to_string()),
+            bind_address: TextInput::new(default_bind_address()),
+            label: TextInput::new(""),
             focus: 0,
         };
         self.modal = Some(Modal::Bind(form));
@@ -1423,20 +3299,16 @@ impl App {
     }
 
     fn open_remote_browser(&mut self) {
+        if !self.require_tool(self.tools.ssh, "ssh") {
+            return;
+        }
         let droplet_name = self
             .selected_droplet()
             .map(|droplet| droplet.name.clone())
             .unwrap_or_else(|| "droplet".to_string());
         match self.selected_ssh_config() {
             Ok(ssh) => {
-                let mut form = RemoteBrowserForm {
-                    droplet_name,
-                    ssh,
-                    current_path: "~".to_string(),
-                    entries: Vec::new(),
-                    selected: 0,
-                    loading: false,
-                };
+                let mut form = RemoteBrowserForm::new(droplet_name, ssh, None);
                 let path = form.current_path.clone();
                 self.browse_remote_path(&mut form, path);
                 self.modal = Some(Modal::RemoteBrowser(form));
@@ -1445,6 +3317,28 @@ impl App {
         }
     }
 
+    /// Opens the remote browser on the sync form's host to pick a remote path.
+    fn browse_remote_sync_path(&mut self, sync_form: SyncForm) {
+        if !self.require_tool(self.tools.ssh, "ssh") {
+            return;
+        }
+        let Ok(port) = sync_form.ssh_port.value.trim().parse::<u16>() else {
+            self.push_toast("Invalid SSH port", ToastLevel::Warning);
+            return;
+        };
+        let ssh = SshConfig {
+            user: sync_form.ssh_user.value.trim().to_string(),
+            host: sync_form.host.clone(),
+            port,
+            key_path: sync_form.ssh_key_path.value.trim().to_string(),
+            use_agent: sync_form.use_agent,
+        };
+        let mut form = RemoteBrowserForm::new(sync_form.droplet_name.clone(), ssh, Some(sync_form));
+        let path = form.current_path.clone();
+        self.browse_remote_path(&mut form, path);
+        self.modal = Some(Modal::RemoteBrowser(form));
+    }
+
     fn open_sync_modal(&mut self) {
         let droplet = match self.selected_droplet() {
             Some(droplet) => droplet.clone(),
@@ -1457,20 +3351,35 @@ impl App {
             self.push_toast("Droplet must be running", ToastLevel::Warning);
             return;
         }
-        let public_ip = match droplet.public_ipv4.clone() {
-            Some(ip) => ip,
-            None => {
-                self.push_toast("Droplet has no public IP", ToastLevel::Warning);
-                return;
-            }
+        let preferred = self.state.settings.preferred_ip;
+        let host = match droplet.preferred_ip(preferred) {
+            Some(ip) => ip.to_string(),
+            None => match &droplet.private_ipv4 {
+                Some(ip) => {
+                    self.push_toast(
+                        "Droplet has no public IP; syncing over its private IP (VPC or VPN only)",
+                        ToastLevel::Info,
+                    );
+                    ip.clone()
+                }
+                None => {
+                    self.push_toast(
+                        "Droplet has neither a public nor a private IP",
+                        ToastLevel::Warning,
+                    );
+                    return;
+                }
+            },
         };
         let settings = &self.state.settings;
         let form = SyncForm {
             droplet_name: droplet.name,
-            public_ip,
+            host,
             local_paths: TextInput::new(""),
             ssh_user: TextInput::new(settings.default_ssh_user.clone()),
             ssh_key_path: TextInput::new(settings.default_ssh_key_path.clone()),
+            key_warning: None,
+            use_agent: false,
             ssh_port: TextInput::new(settings.default_ssh_port.to_string()),
             focus: 0,
         };
@@ -1494,10 +3403,18 @@ impl App {
             sanitize_name(&droplet.name),
             Utc::now().format("%Y%m%d-%H%M%S")
         );
+        let disk_gb = droplet.size.as_deref().and_then(|slug| {
+            self.sizes
+                .iter()
+                .find(|size| size.slug == slug)
+                .map(|size| size.disk_gb)
+        });
         let form = SnapshotForm {
             droplet_id: droplet.id,
             droplet_name: droplet.name,
             snapshot_name: TextInput::new(snapshot_name),
+            disk_gb,
+            power_off: false,
         };
         self.modal = Some(Modal::Snapshot(form));
     }
@@ -1518,12 +3435,18 @@ impl App {
             ),
             action: ConfirmAction::DeleteDroplet {
                 droplet_id: droplet.id,
+                droplet_name: droplet.name.clone(),
             },
         };
         self.modal = Some(Modal::Confirm(confirm));
     }
 
-    fn open_picker(&mut self, target: PickerTarget, parent: Modal, preselected: Vec<Selection>) {
+    fn open_picker(
+        &mut self,
+        target: PickerTarget,
+        parent: Option<Modal>,
+        preselected: Vec<Selection>,
+    ) {
         let (title, items, multi) = match target {
             PickerTarget::CreateRegion | PickerTarget::RestoreRegion => {
                 if self.regions.is_empty() {
@@ -1533,58 +3456,27 @@ impl App {
                     );
                     return;
                 }
-                let mut available: Vec<&Region> =
-                    self.regions.iter().filter(|r| r.available).collect();
-                if available.is_empty() {
-                    available = self.regions.iter().collect();
-                }
-                let items = available
-                    .into_iter()
-                    .map(|region| PickerItem {
-                        label: if region.available {
-                            format!("{} ({})", region.slug, region.name)
-                        } else {
-                            format!("{} ({}, unavailable)", region.slug, region.name)
-                        },
-                        value: region.slug.clone(),
-                        meta: Some(region.name.clone()),
-                    })
-                    .collect();
+                let items =
+                    region_picker_items(&self.regions, &self.state.settings.allowed_regions);
+                if items.is_empty() {
+                    self.push_toast(
+                        "No loaded region is in allowed_regions",
+                        ToastLevel::Warning,
+                    );
+                    return;
+                }
                 ("Select Region".to_string(), items, false)
             }
             PickerTarget::CreateSize | PickerTarget::RestoreSize => {
-                let items = self
-                    .sizes
-                    .iter()
-                    .map(|size| PickerItem {
-                        label: format!(
-                            "{} ({}MB, {} vCPU, {}GB)",
-                            size.slug, size.memory_mb, size.vcpus, size.disk_gb
-                        ),
-                        value: size.slug.clone(),
-                        meta: Some(format!("${:.2}/mo", size.price_monthly)),
-                    })
-                    .collect();
+                let items = size_picker_items(&self.sizes, &self.state.settings.allowed_sizes);
+                if items.is_empty() && !self.sizes.is_empty() {
+                    self.push_toast("No loaded size is in allowed_sizes", ToastLevel::Warning);
+                    return;
+                }
                 ("Select Size".to_string(), items, false)
             }
             PickerTarget::CreateImage => {
-                let items = self
-                    .images
-                    .iter()
-                    .map(|image| PickerItem {
-                        label: format!(
-                            "{}{}",
-                            image.name,
-                            image
-                                .slug
-                                .as_ref()
-                                .map(|slug| format!(" ({slug})"))
-                                .unwrap_or_default()
-                        ),
-                        value: image.slug.clone().unwrap_or_else(|| image.id.to_string()),
-                        meta: image.distribution.clone(),
-                    })
-                    .collect();
+                let items = image_picker_items(&self.images);
                 ("Select Image".to_string(), items, false)
             }
             PickerTarget::CreateSshKeys | PickerTarget::RestoreSshKeys => {
@@ -1595,10 +3487,15 @@ impl App {
                         label: format!("{} ({})", key.name, key.fingerprint),
                         value: key.id.to_string(),
                         meta: None,
+                        header: false,
                     })
                     .collect();
                 ("Select SSH Keys".to_string(), items, true)
             }
+            PickerTarget::DoctlContext => {
+                self.spawn(Task::LoadContexts);
+                return;
+            }
             PickerTarget::RestoreSnapshot => {
                 if self.snapshots.is_empty() {
                     self.push_toast("No snapshots loaded yet (refreshing)", ToastLevel::Warning);
@@ -1608,24 +3505,94 @@ impl App {
                 let items = self.snapshot_picker_items();
                 ("Select Snapshot".to_string(), items, false)
             }
-        };
-
-        let mut picker = Picker::new(title, items, target, multi);
-        if picker.multi {
-            for (idx, item) in picker.items.iter().enumerate() {
-                if preselected.iter().any(|sel| sel.value == item.value) {
-                    picker.chosen.insert(idx);
+            PickerTarget::RsyncBindDroplet => {
+                let items: Vec<PickerItem> = self
+                    .droplets
+                    .iter()
+                    .filter_map(|droplet| {
+                        let ip = droplet.preferred_ip(self.state.settings.preferred_ip)?;
+                        Some(PickerItem {
+                            label: format!("{} ({ip})", droplet.name),
+                            value: droplet.id.to_string(),
+                            meta: Some(droplet.status.clone()),
+                            header: false,
+                        })
+                    })
+                    .collect();
+                if items.is_empty() {
+                    self.push_toast("No droplets with a public IP", ToastLevel::Warning);
+                    return;
                 }
+                ("Select Droplet".to_string(), items, false)
             }
-        }
+            PickerTarget::ActivateWorkspace | PickerTarget::DeactivateWorkspace => {
+                if self.state.workspaces.is_empty() {
+                    self.push_toast(
+                        "No saved workspaces (save one with `workspace save <name>`)",
+                        ToastLevel::Warning,
+                    );
+                    return;
+                }
+                let items = self
+                    .state
+                    .workspaces
+                    .iter()
+                    .map(|workspace| PickerItem {
+                        label: workspace.name.clone(),
+                        value: workspace.name.clone(),
+                        meta: Some(format!(
+                            "{} tunnel(s), {} sync(s)",
+                            workspace.bindings.len(),
+                            workspace.syncs.len()
+                        )),
+                        header: false,
+                    })
+                    .collect();
+                let title = if target == PickerTarget::ActivateWorkspace {
+                    "Activate Workspace"
+                } else {
+                    "Deactivate Workspace"
+                };
+                (title.to_string(), items, false)
+            }
+            PickerTarget::CommandPalette => {
+                let items = palette_actions(self.screen)
+                    .iter()
+                    .map(|(key, label)| PickerItem {
+                        label: label.to_string(),
+                        value: key.to_string(),
+                        meta: Some(key.to_string()),
+                        header: false,
+                    })
+                    .collect();
+                ("Commands".to_string(), items, false)
+            }
+        };
+
+        let mut picker = Picker::new(title, items, target, multi);
+        self.restore_picker_query(&mut picker);
+        if picker.multi {
+            for (idx, item) in picker.items.iter().enumerate() {
+                if preselected.iter().any(|sel| sel.value == item.value) {
+                    picker.chosen.insert(idx);
+                }
+            }
+        }
 
         self.modal = Some(Modal::Picker {
             picker,
-            parent: Box::new(parent),
+            parent: parent.map(Box::new),
         });
     }
 
-    fn apply_picker_selection(&mut self, picker: Picker, mut parent: Modal) {
+    fn restore_picker_query(&self, picker: &mut Picker) {
+        if let Some(query) = self.picker_queries.get(&picker.target) {
+            picker.query = TextInput::new(query.clone());
+            picker.refresh_filter();
+        }
+    }
+
+    fn apply_picker_selection(&mut self, picker: Picker, mut parent: Option<Modal>) {
         let selected_items: Vec<PickerItem> = if picker.multi {
             picker
                 .chosen
@@ -1634,9 +3601,8 @@ impl App {
                 .collect()
         } else {
             picker
-                .filtered
-                .get(picker.selected)
-                .and_then(|idx| picker.items.get(*idx))
+                .selected_index()
+                .and_then(|idx| picker.items.get(idx))
                 .cloned()
                 .map(|item| vec![item])
                 .unwrap_or_default()
@@ -1649,83 +3615,344 @@ impl App {
 
         match picker.target {
             PickerTarget::CreateRegion => {
-                if let Modal::Create(form) = &mut parent {
+                if let Some(Modal::Create(form)) = &mut parent {
                     form.region = selected_items.first().cloned().map(to_selection);
                 }
             }
             PickerTarget::CreateSize => {
-                if let Modal::Create(form) = &mut parent {
+                if let Some(Modal::Create(form)) = &mut parent {
                     form.size = selected_items.first().cloned().map(to_selection);
                 }
             }
             PickerTarget::CreateImage => {
-                if let Modal::Create(form) = &mut parent {
+                if let Some(Modal::Create(form)) = &mut parent {
                     form.image = selected_items.first().cloned().map(to_selection);
                 }
             }
             PickerTarget::CreateSshKeys => {
-                if let Modal::Create(form) = &mut parent {
+                if let Some(Modal::Create(form)) = &mut parent {
                     form.ssh_keys = selected_items.into_iter().map(to_selection).collect();
                 }
             }
             PickerTarget::RestoreSnapshot => {
-                if let Modal::Restore(form) = &mut parent {
+                if let Some(Modal::Restore(form)) = &mut parent {
                     form.snapshot = selected_items.first().cloned().map(to_selection);
                 }
             }
             PickerTarget::RestoreRegion => {
-                if let Modal::Restore(form) = &mut parent {
+                if let Some(Modal::Restore(form)) = &mut parent {
                     form.region = selected_items.first().cloned().map(to_selection);
                 }
             }
             PickerTarget::RestoreSize => {
-                if let Modal::Restore(form) = &mut parent {
+                if let Some(Modal::Restore(form)) = &mut parent {
                     form.size = selected_items.first().cloned().map(to_selection);
                 }
             }
             PickerTarget::RestoreSshKeys => {
-                if let Modal::Restore(form) = &mut parent {
+                if let Some(Modal::Restore(form)) = &mut parent {
                     form.ssh_keys = selected_items.into_iter().map(to_selection).collect();
                 }
             }
+            PickerTarget::DoctlContext => {
+                if let Some(item) = selected_items.first() {
+                    self.switch_doctl_context(item.value.clone());
+                }
+            }
+            PickerTarget::RsyncBindDroplet => {
+                let droplet = selected_items
+                    .first()
+                    .and_then(|item| item.value.parse::<u64>().ok())
+                    .and_then(|id| self.droplets.iter().find(|droplet| droplet.id == id));
+                if let (Some(Modal::RsyncBind(form)), Some(droplet)) = (&mut parent, droplet) {
+                    form.droplet_name = droplet.name.clone();
+                    form.ssh.host = droplet
+                        .preferred_ip(self.state.settings.preferred_ip)
+                        .unwrap_or_default()
+                        .to_string();
+                    form.focus = 1;
+                }
+            }
+            PickerTarget::ActivateWorkspace => {
+                if let Some(item) = selected_items.first() {
+                    self.activate_workspace(&item.value);
+                }
+            }
+            PickerTarget::DeactivateWorkspace => {
+                if let Some(item) = selected_items.first() {
+                    self.deactivate_workspace(&item.value);
+                }
+            }
+            PickerTarget::CommandPalette => {
+                // Replay the action's hotkey so it runs through the screen's own handler,
+                // which may open a modal of its own.
+                self.modal = parent;
+                if let Some(key) = selected_items
+                    .first()
+                    .and_then(|item| palette_key(&item.value))
+                {
+                    self.handle_key(key);
+                }
+                return;
+            }
         }
 
-        self.modal = Some(parent);
+        self.modal = parent;
     }
 
-    fn submit_create_form(&mut self, form: &CreateForm) {
-        let name = form.name.value.trim();
-        if name.is_empty() {
-            self.push_toast("Name is required", ToastLevel::Warning);
+    /// Replaces the droplet's row (or adds it) without losing the current selection.
+    fn upsert_droplet(&mut self, droplet: Droplet) {
+        let selected_id = self.selected_droplet().map(|droplet| droplet.id);
+        match self.droplets.iter_mut().find(|d| d.id == droplet.id) {
+            Some(existing) => *existing = droplet,
+            None => self.droplets.push(droplet),
+        }
+        self.sort_droplets();
+        self.reselect_droplet(selected_id);
+    }
+
+    /// `create --wait` can return while the droplet is still `new` with no IP, and a
+    /// background create always does; keep polling.
+    fn wait_for_network_if_needed(&mut self, droplet: &Droplet, background: bool) {
+        if droplet.has_network() {
+            return;
+        }
+        self.spawn(Task::WaitForNetwork {
+            droplet_id: droplet.id,
+            droplet_name: droplet.name.clone(),
+            background,
+        });
+    }
+
+    fn record_droplet_ssh_keys(&mut self, droplet_id: u64, args: &CreateDropletArgs) {
+        if args.ssh_keys.is_empty() {
             return;
         }
+        self.update_state(|state| {
+            state
+                .droplet_ssh_keys
+                .insert(droplet_id, args.ssh_keys.clone())
+        });
+    }
+
+    /// Applies a change to the persisted state and saves it. This is the only place state is
+    /// written; a failed save is reported instead of silently dropped.
+    fn update_state<R>(&mut self, update: impl FnOnce(&mut AppStateFile) -> R) -> R {
+        let result = update(&mut self.state);
+        if let Err(err) = config::save_state(&self.state) {
+            self.push_toast(format!("{err:#}"), ToastLevel::Error);
+        }
+        result
+    }
+
+    /// The other droplet a bind form's local port last pointed at, if it differs.
+    pub fn previous_port_droplet(&self, form: &BindForm) -> Option<&str> {
+        let port = form.local_port.value.trim().parse::<u16>().ok()?;
+        self.state
+            .port_history
+            .get(&port)
+            .map(String::as_str)
+            .filter(|name| *name != form.droplet_name)
+    }
+
+    /// Names of the SSH keys recorded for a droplet at create time, if any.
+    pub fn droplet_ssh_key_names(&self, droplet_id: u64) -> Option<Vec<String>> {
+        let ids = self.state.droplet_ssh_keys.get(&droplet_id)?;
+        Some(
+            ids.iter()
+                .map(|id| {
+                    self.ssh_keys
+                        .iter()
+                        .find(|key| key.id.to_string() == *id)
+                        .map(|key| key.name.clone())
+                        .unwrap_or_else(|| format!("key #{id}"))
+                })
+                .collect(),
+        )
+    }
+
+    fn switch_doctl_context(&mut self, name: String) {
+        doctl::set_context(Some(name.clone()));
+        self.update_state(|state| state.settings.doctl_context = Some(name.clone()));
+        self.droplets.clear();
+        self.selected = 0;
+        self.account = None;
+        self.account_error = None;
+        self.push_toast(
+            format!("Switched to doctl context '{name}'"),
+            ToastLevel::Success,
+        );
+        self.spawn(Task::CheckDoctl);
+        self.refresh_all();
+    }
+
+    fn create_args_from_form(&mut self, form: &CreateForm) -> Option<CreateDropletArgs> {
+        if form.name.value.trim().is_empty() {
+            self.push_toast("Name is required", ToastLevel::Warning);
+            return None;
+        }
+        let name = sanitize_name(&form.name.value);
         let size = match &form.size {
             Some(size) => size.value.clone(),
             None => {
                 self.push_toast("Size is required", ToastLevel::Warning);
-                return;
+                return None;
             }
         };
         let image = match &form.image {
             Some(image) => image.value.clone(),
             None => {
                 self.push_toast("Image is required", ToastLevel::Warning);
-                return;
+                return None;
             }
         };
 
-        let args = CreateDropletArgs {
-            name: name.to_string(),
+        Some(CreateDropletArgs {
+            name,
             region: form.region.as_ref().map(|region| region.value.clone()),
             size,
             image,
             ssh_keys: form.ssh_keys.iter().map(|k| k.value.clone()).collect(),
             tags: split_csv(&form.tags.value),
+            project_id: self.active_project().map(str::to_string),
+            wait: !form.background,
+        })
+    }
+
+    fn copy_selected_ipv6(&mut self) {
+        let Some(droplet) = self.selected_droplet() else {
+            self.push_toast("No droplet selected", ToastLevel::Warning);
+            return;
+        };
+        let Some(ip) = droplet.public_ipv6.clone() else {
+            self.push_toast("Droplet has no public IPv6 address", ToastLevel::Warning);
+            return;
+        };
+        match crate::ui::copy_to_clipboard(&ip) {
+            Ok(()) => self.push_toast(format!("Copied {ip} to clipboard"), ToastLevel::Success),
+            Err(err) => self.push_toast(err.to_string(), ToastLevel::Error),
+        }
+    }
+
+    /// Copies the equivalent doctl command; returns false when the form was replaced by a notice.
+    fn copy_create_command(&mut self, form: &CreateForm) -> bool {
+        let Some(args) = self.create_args_from_form(form) else {
+            return true;
+        };
+        let command = doctl::create_command_line(&args);
+        match crate::ui::copy_to_clipboard(&command) {
+            Ok(()) => {
+                self.push_toast("Copied doctl command to clipboard", ToastLevel::Success);
+                true
+            }
+            Err(err) => {
+                self.modal = Some(Modal::Notice(Notice {
+                    title: "doctl Command".to_string(),
+                    message: format!("{command}\n\n(Clipboard unavailable: {err})"),
+                    scroll: 0,
+                }));
+                false
+            }
+        }
+    }
+
+    fn submit_create_form(&mut self, form: &CreateForm) {
+        let Some(args) = self.create_args_from_form(form) else {
+            return;
         };
+        let size = self.sizes.iter().find(|size| size.slug == args.size);
+        let mut message = create_summary(&args, form, size, &self.ssh_keys);
+        if form.ssh_keys.is_empty() {
+            message.push_str("\n\nNo SSH keys are selected. DigitalOcean will email a root password instead, and the droplet will only accept password logins until you add a key.");
+        }
+        message.push_str("\n\nCreate it? Press n to go back and edit.");
+        self.modal = Some(Modal::Confirm(Confirm {
+            title: "Create Droplet".to_string(),
+            message,
+            action: ConfirmAction::CreateDroplet { form: form.clone() },
+        }));
+    }
+
+    fn create_droplet_from_form(&mut self, form: &CreateForm) {
+        let Some(args) = self.create_args_from_form(form) else {
+            return;
+        };
+
+        let last_create = CreateDefaults {
+            region: form.region.as_ref().map(saved_selection),
+            size: form.size.as_ref().map(saved_selection),
+            image: form.image.as_ref().map(saved_selection),
+            ssh_keys: form.ssh_keys.iter().map(saved_selection).collect(),
+        };
+        self.update_state(|state| state.settings.last_create = Some(last_create));
 
         self.spawn(Task::CreateDroplet(args));
     }
 
+    fn use_source_specs(&mut self, form: &mut RestoreForm) {
+        let Some(snapshot_id) = form.snapshot.as_ref().map(|s| s.value.clone()) else {
+            self.push_toast("Select a snapshot first", ToastLevel::Warning);
+            return;
+        };
+        let Some(snapshot) = self
+            .snapshots
+            .iter()
+            .find(|snapshot| snapshot.id.to_string() == snapshot_id)
+            .cloned()
+        else {
+            self.push_toast("Snapshot details not loaded", ToastLevel::Warning);
+            return;
+        };
+
+        match source_region(&snapshot, &self.regions) {
+            Some(region) => {
+                form.region = Some(Selection {
+                    label: format!("{} ({})", region.slug, region.name),
+                    value: region.slug.clone(),
+                });
+            }
+            None => {
+                self.push_toast(
+                    format!(
+                        "No available region holds this snapshot ({}); pick one manually",
+                        snapshot.regions.join(", ")
+                    ),
+                    ToastLevel::Warning,
+                );
+                return;
+            }
+        }
+
+        if self.sizes.is_empty() {
+            self.push_toast(
+                "Region set; no sizes loaded (press g to refresh), pick a size manually",
+                ToastLevel::Warning,
+            );
+            return;
+        }
+        match smallest_compatible_size(snapshot.min_disk_size, &self.sizes) {
+            Some(size) => {
+                form.size = Some(Selection {
+                    label: format!(
+                        "{} ({}MB, {} vCPU, {}GB)",
+                        size.slug, size.memory_mb, size.vcpus, size.disk_gb
+                    ),
+                    value: size.slug.clone(),
+                });
+                self.push_toast("Using snapshot source specs", ToastLevel::Info);
+            }
+            None => {
+                self.push_toast(
+                    format!(
+                        "Region set; no size has at least {}GB disk, pick a size manually",
+                        snapshot.min_disk_size
+                    ),
+                    ToastLevel::Warning,
+                );
+            }
+        }
+    }
+
     fn submit_restore_form(&mut self, form: &RestoreForm) {
         let name = form.name.value.trim();
         if name.is_empty() {
@@ -1753,11 +3980,28 @@ impl App {
             image: snapshot,
             ssh_keys: form.ssh_keys.iter().map(|k| k.value.clone()).collect(),
             tags: split_csv(&form.tags.value),
+            project_id: self.active_project().map(str::to_string),
+            wait: true,
         };
 
         self.spawn(Task::RestoreDroplet(args));
     }
 
+    fn toggle_bind_interface(&mut self, form: &mut BindForm) {
+        form.interface = match form.interface {
+            BindInterface::Public if form.private_ip.is_none() => {
+                self.push_toast("Droplet has no private IP", ToastLevel::Warning);
+                BindInterface::Public
+            }
+            BindInterface::Public => BindInterface::Private,
+            BindInterface::Private if form.public_ip.is_none() => {
+                self.push_toast("Droplet has no public IP", ToastLevel::Warning);
+                BindInterface::Private
+            }
+            BindInterface::Private => BindInterface::Public,
+        };
+    }
+
     fn submit_bind_form(&mut self, form: BindForm) {
         let local_port = match form.local_port.value.trim().parse::<u16>() {
             Ok(port) => port,
@@ -1781,28 +4025,68 @@ impl App {
             }
         };
 
+        let bind_address = form.bind_address.value.trim().to_string();
+        if bind_address.parse::<std::net::IpAddr>().is_err()
+            && !bind_address.eq_ignore_ascii_case("localhost")
+        {
+            self.push_toast("Invalid bind address", ToastLevel::Warning);
+            return;
+        }
+        if let Some(problem) = tasks::ssh_identity_problem(&form.ssh_key_path.value, form.use_agent)
+        {
+            self.push_toast(problem, ToastLevel::Warning);
+        }
+
         if ports::port_in_registry(&self.state, local_port).is_some() {
             self.push_toast("Local port already bound", ToastLevel::Warning);
             return;
         }
 
-        if !ports::is_port_available(local_port) {
-            self.push_toast("Local port is in use", ToastLevel::Warning);
+        if !ports::is_port_available(&bind_address, local_port) {
+            self.push_toast(
+                format!("Local port is in use on {bind_address}"),
+                ToastLevel::Warning,
+            );
             return;
         }
 
-        let binding = ports::new_binding(
+        let host = match form.interface {
+            BindInterface::Public => match form.public_ip {
+                Some(ip) => ip,
+                None => {
+                    self.push_toast("Droplet has no public IP", ToastLevel::Warning);
+                    return;
+                }
+            },
+            BindInterface::Private => match form.private_ip {
+                Some(ip) => ip,
+                None => {
+                    self.push_toast("Droplet has no private IP", ToastLevel::Warning);
+                    return;
+                }
+            },
+        };
+
+        let mut binding = ports::new_binding(
             form.droplet_id,
             form.droplet_name,
-            form.public_ip,
+            host,
             local_port,
             remote_port,
             form.ssh_user.value.trim().to_string(),
             form.ssh_key_path.value.trim().to_string(),
             ssh_port,
+            form.interface,
+            bind_address,
         );
+        binding.label = normalize_label(&form.label.value);
+        binding.use_agent = form.use_agent;
 
-        self.spawn(Task::StartTunnel(binding));
+        if self.state.settings.skip_bind_ssh_check {
+            self.spawn(Task::StartTunnel(binding));
+        } else {
+            self.spawn(Task::TestSshConnection(binding));
+        }
     }
 
     fn submit_sync_form(&mut self, form: SyncForm) {
@@ -1821,18 +4105,96 @@ impl App {
             }
         };
 
+        if let Some(problem) = tasks::ssh_identity_problem(&form.ssh_key_path.value, form.use_agent)
+        {
+            self.push_toast(problem, ToastLevel::Warning);
+        }
+
         let ssh = SshConfig {
             user: form.ssh_user.value.trim().to_string(),
-            host: form.public_ip.clone(),
+            host: form.host.clone(),
             port: ssh_port,
             key_path: form.ssh_key_path.value.trim().to_string(),
+            use_agent: form.use_agent,
         };
 
-        self.spawn(Task::CreateSyncs {
+        self.spawn(Task::PlanSyncs {
             ssh,
             droplet_name: form.droplet_name.clone(),
             paths,
         });
+        self.modal = Some(Modal::Sync(form));
+    }
+
+    fn confirm_sync_rollback(&mut self, ssh: SshConfig, failure: SyncBatchFailure) {
+        let created = failure
+            .created
+            .iter()
+            .map(|sync| format!("  {} -> {}", sync.local, sync.remote))
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.modal = Some(Modal::Confirm(Confirm {
+            title: "Sync Batch Failed".to_string(),
+            message: format!(
+                "{failure}\n\nCreated before the failure:\n{created}\n\nRoll back? This terminates these sessions and removes the lines this batch added to ~/.mountlist. Press n to keep them."
+            ),
+            action: ConfirmAction::RollbackSyncs {
+                ssh,
+                created: failure.created,
+            },
+        }));
+    }
+
+    fn show_rollback_outcome(&mut self, outcome: SyncRollbackOutcome) {
+        if outcome.errors.is_empty() {
+            self.push_toast(
+                format!(
+                    "Rolled back {} sync(s) and {} mountlist line(s)",
+                    outcome.terminated.len(),
+                    outcome.mount_removed
+                ),
+                ToastLevel::Success,
+            );
+            return;
+        }
+        let mut lines = vec![format!(
+            "Terminated {} session(s), removed {} mountlist line(s).",
+            outcome.terminated.len(),
+            outcome.mount_removed
+        )];
+        lines.push(String::new());
+        lines.push("Could not roll back:".to_string());
+        lines.extend(outcome.errors.iter().map(|err| format!("  {err}")));
+        self.modal = Some(Modal::Notice(Notice {
+            title: "Rollback Incomplete".to_string(),
+            message: lines.join("\n"),
+            scroll: 0,
+        }));
+    }
+
+    fn confirm_sync_plan(&mut self, ssh: SshConfig, plan: SyncPlan) {
+        let form = match self.modal.take() {
+            Some(Modal::Sync(form)) => Some(form),
+            other => {
+                self.modal = other;
+                None
+            }
+        };
+        let runnable = plan.items.len() - plan.count(SyncPlanAction::Skip);
+        let message = format!(
+            "{}\n\n{}",
+            sync_plan_lines(&plan),
+            if runnable == 0 {
+                "Nothing to do. Press n to edit the paths.".to_string()
+            } else {
+                format!("Apply these {runnable} change(s)? Press n to edit the paths.")
+            }
+        );
+        self.modal = Some(Modal::Confirm(Confirm {
+            title: "Review Mutagen Syncs".to_string(),
+            message,
+            action: ConfirmAction::CreateSyncs { ssh, plan, form },
+        }));
     }
 
     fn restore_syncs(&mut self) {
@@ -1854,13 +4216,36 @@ impl App {
     }
 
     fn browse_remote_path(&mut self, form: &mut RemoteBrowserForm, path: String) {
+        self.cancel_remote_listing();
         form.current_path = path.clone();
         form.loading = true;
         form.selected = 0;
-        self.spawn(Task::ListRemoteDirectories {
-            ssh: form.ssh.clone(),
-            path,
-        });
+        form.filter = TextInput::new("");
+        form.filter_editing = false;
+        let listing = CancelToken::default();
+        self.spawn_with_cancel(
+            Task::ListRemoteDirectories {
+                ssh: form.ssh.clone(),
+                path,
+            },
+            listing.child(),
+        );
+        self.remote_listing = Some(listing);
+    }
+
+    /// Kills the ssh behind an in-flight remote listing; its result is then dropped.
+    fn cancel_remote_listing(&mut self) {
+        if let Some(listing) = self.remote_listing.take() {
+            listing.cancel_all();
+        }
+    }
+
+    /// Whether a listing of `path` is what the open remote browser is waiting for.
+    fn is_current_remote_listing(&self, path: &str) -> bool {
+        matches!(
+            &self.modal,
+            Some(Modal::RemoteBrowser(form)) if form.loading && form.current_path == path
+        )
     }
 
     fn open_cursor_remote_folder(&mut self, form: &RemoteBrowserForm, selected_path: String) {
@@ -1879,6 +4264,9 @@ impl App {
     }
 
     fn open_rsync_bind_modal(&mut self, form: &RemoteBrowserForm, remote_path: String) {
+        if !self.require_tool(self.tools.rsync, "rsync") {
+            return;
+        }
         let local_path = build_rsync_local_path(&form.droplet_name, &remote_path);
         let bind_form = RsyncBindForm {
             droplet_name: form.droplet_name.clone(),
@@ -1886,26 +4274,101 @@ impl App {
             remote_path,
             local_path: TextInput::new(local_path),
             focus: 0,
+            manual: None,
         };
         self.modal = Some(Modal::RsyncBind(bind_form));
     }
 
-    fn submit_rsync_bind_form(&mut self, form: RsyncBindForm) {
+    /// Opens the bind form with the droplet, remote path, and SSH details left to type in.
+    fn open_manual_rsync_bind_modal(&mut self) {
+        if !self.require_tool(self.tools.rsync, "rsync") {
+            return;
+        }
+        let settings = &self.state.settings;
+        let form = RsyncBindForm {
+            droplet_name: String::new(),
+            ssh: SshConfig {
+                user: settings.default_ssh_user.clone(),
+                host: String::new(),
+                port: settings.default_ssh_port,
+                key_path: settings.default_ssh_key_path.clone(),
+                use_agent: false,
+            },
+            remote_path: String::new(),
+            local_path: TextInput::new(""),
+            focus: 0,
+            manual: Some(ManualRsyncBindFields {
+                remote_path: TextInput::new(""),
+                ssh_user: TextInput::new(settings.default_ssh_user.clone()),
+                ssh_key_path: TextInput::new(settings.default_ssh_key_path.clone()),
+                key_warning: None,
+                use_agent: false,
+                ssh_port: TextInput::new(settings.default_ssh_port.to_string()),
+            }),
+        };
+        self.modal = Some(Modal::RsyncBind(form));
+    }
+
+    fn submit_rsync_bind_form(&mut self, mut form: RsyncBindForm) {
+        let manual = form.manual.is_some();
+        if let Some(fields) = form.manual.take() {
+            if form.ssh.host.is_empty() {
+                self.push_toast("Pick a droplet first", ToastLevel::Warning);
+                return;
+            }
+            let remote_path = match validate_remote_path(&fields.remote_path.value) {
+                Ok(path) => path,
+                Err(message) => {
+                    self.push_toast(message, ToastLevel::Warning);
+                    return;
+                }
+            };
+            let Ok(port) = fields.ssh_port.value.trim().parse::<u16>() else {
+                self.push_toast("Invalid SSH port", ToastLevel::Warning);
+                return;
+            };
+            if let Some(problem) =
+                tasks::ssh_identity_problem(&fields.ssh_key_path.value, fields.use_agent)
+            {
+                self.push_toast(problem, ToastLevel::Warning);
+            }
+            form.ssh.user = fields.ssh_user.value.trim().to_string();
+            form.ssh.key_path = fields.ssh_key_path.value.trim().to_string();
+            form.ssh.use_agent = fields.use_agent;
+            form.ssh.port = port;
+            form.remote_path = remote_path;
+        }
         let local_path = form.local_path.value.trim();
         if local_path.is_empty() {
             self.push_toast("Local folder is required", ToastLevel::Warning);
             return;
         }
+        if manual && !local_path.starts_with('/') && !local_path.starts_with('~') {
+            self.push_toast(
+                "Local folder must be an absolute or ~/ path",
+                ToastLevel::Warning,
+            );
+            return;
+        }
 
+        let droplet_id = droplet_for_host(&self.droplets, &form.ssh.host).map(|droplet| droplet.id);
         let bind = RsyncBind {
             droplet_name: form.droplet_name,
+            droplet_id,
             ssh_user: form.ssh.user,
             host: form.ssh.host,
             ssh_port: form.ssh.port,
             ssh_key_path: form.ssh.key_path,
+            use_agent: form.ssh.use_agent,
             remote_path: form.remote_path,
             local_path: local_path.to_string(),
             created_at: Utc::now(),
+            last_synced_at: None,
+            last_result: None,
+            rsync_profile: RsyncProfile::default(),
+            compress: true,
+            checksum: false,
+            group: None,
         };
 
         self.spawn(Task::CreateRsyncBind { bind });
@@ -1918,25 +4381,158 @@ impl App {
         Ok(())
     }
 
+    /// Applies a flag change to the modal's bind and persists it to the registry.
+    fn update_rsync_bind_options(
+        &mut self,
+        form: &mut RsyncBindActionsForm,
+        update: impl Fn(&mut RsyncBind),
+    ) {
+        update(&mut form.bind);
+        if self
+            .state
+            .rsync_binds
+            .iter()
+            .any(|item| same_rsync_bind(item, &form.bind))
+        {
+            self.update_state(|state| {
+                state
+                    .rsync_binds
+                    .iter_mut()
+                    .filter(|item| same_rsync_bind(item, &form.bind))
+                    .for_each(update)
+            });
+        }
+    }
+
+    fn record_rsync_result(
+        &mut self,
+        bind: &RsyncBind,
+        direction: RsyncDirection,
+        outcome: Result<&str, &str>,
+    ) {
+        if !self
+            .state
+            .rsync_binds
+            .iter()
+            .any(|item| same_rsync_bind(item, bind))
+        {
+            return;
+        }
+        self.update_state(|state| {
+            if let Some(stored) = state
+                .rsync_binds
+                .iter_mut()
+                .find(|item| same_rsync_bind(item, bind))
+            {
+                stored.last_synced_at = Some(Utc::now());
+                stored.last_result = Some(rsync_result_label(direction, outcome));
+            }
+        });
+    }
+
+    /// Points registry binds at their droplets' current addresses so a rebuild or reserved
+    /// IP move doesn't strand them, saving any host that changed.
+    fn refresh_rsync_hosts(&mut self) {
+        let preferred = self.state.settings.preferred_ip;
+        let hosts: Vec<String> = self
+            .state
+            .rsync_binds
+            .iter()
+            .map(|bind| resolve_rsync_host(bind, &self.droplets, preferred))
+            .collect();
+        if hosts
+            .iter()
+            .zip(&self.state.rsync_binds)
+            .all(|(host, bind)| *host == bind.host)
+        {
+            return;
+        }
+        self.update_state(|state| {
+            for (bind, host) in state.rsync_binds.iter_mut().zip(hosts) {
+                bind.host = host;
+            }
+        });
+    }
+
     fn open_rsync_binds_screen(&mut self) {
         self.screen = Screen::RsyncBinds;
         self.selected = 0;
     }
 
     fn run_selected_rsync(&mut self, direction: RsyncDirection) {
+        if !self.require_tool(self.tools.rsync, "rsync") {
+            return;
+        }
         if self.state.rsync_binds.is_empty() {
             self.push_toast("No rsync binds available", ToastLevel::Info);
             return;
         }
+        self.refresh_rsync_hosts();
         if let Some(bind) = self.state.rsync_binds.get(self.selected).cloned() {
+            if direction == RsyncDirection::Down
+                && !self.pull_acknowledged.contains(&bind.local_path)
+                && tasks::local_folder_has_files(&bind.local_path)
+            {
+                self.modal = Some(Modal::Confirm(Confirm {
+                    title: "Overwrite Local Files?".to_string(),
+                    message: format!(
+                        "Pulling from {}:{} will overwrite files in '{}' that differ from the remote, including local edits that were never pushed.\n\nContinue? You won't be asked again for this bind until restart.",
+                        bind.droplet_name, bind.remote_path, bind.local_path
+                    ),
+                    action: ConfirmAction::PullRsync { bind },
+                }));
+                return;
+            }
             self.spawn(Task::RunRsync { bind, direction });
         }
     }
 
-    fn confirm_delete_selected_rsync_bind(&mut self) {
-        if self.state.rsync_binds.is_empty() {
-            self.push_toast("No rsync binds to delete", ToastLevel::Info);
-            return;
+    /// Pushes or pulls every bind in `bind`'s group, one after another.
+    fn run_rsync_group(&mut self, bind: &RsyncBind, direction: RsyncDirection) {
+        let Some(group) = bind.group.clone() else {
+            self.push_toast(
+                "This bind has no group (press g in its actions to set one)",
+                ToastLevel::Info,
+            );
+            return;
+        };
+        if !self.require_tool(self.tools.rsync, "rsync") {
+            return;
+        }
+        self.refresh_rsync_hosts();
+        let binds = rsync_group_members(&self.state.rsync_binds, &group);
+        if direction == RsyncDirection::Down {
+            let overwritten: Vec<&str> = binds
+                .iter()
+                .filter(|bind| {
+                    !self.pull_acknowledged.contains(&bind.local_path)
+                        && tasks::local_folder_has_files(&bind.local_path)
+                })
+                .map(|bind| bind.local_path.as_str())
+                .collect();
+            if !overwritten.is_empty() {
+                self.modal = Some(Modal::Confirm(Confirm {
+                    title: "Overwrite Local Files?".to_string(),
+                    message: format!(
+                        "Pulling group '{group}' will overwrite files that differ from the remote, including local edits that were never pushed, in:\n\n{}\n\nContinue? You won't be asked again for these binds until restart.",
+                        overwritten.join("\n")
+                    ),
+                    action: ConfirmAction::PullRsyncGroup { group, binds },
+                }));
+                return;
+            }
+        }
+        self.spawn(Task::RunRsyncGroup {
+            group,
+            binds,
+            direction,
+        });
+    }
+
+    fn confirm_delete_selected_rsync_bind(&mut self) {
+        if self.state.rsync_binds.is_empty() {
+            self.push_toast("No rsync binds to delete", ToastLevel::Info);
+            return;
         }
         if let Some(bind) = self.state.rsync_binds.get(self.selected).cloned() {
             self.modal = Some(Modal::DeleteRsyncBind(DeleteRsyncBindForm {
@@ -1984,6 +4580,32 @@ impl App {
         }
     }
 
+    /// Shows where state lives and, on a desktop session, opens the folder too.
+    fn show_config_location(&mut self) {
+        let path = match config::state_file_path() {
+            Ok(path) => path,
+            Err(err) => {
+                self.push_toast(err.to_string(), ToastLevel::Error);
+                return;
+            }
+        };
+        let dir = path
+            .parent()
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .into_owned();
+        let opened = tools::file_manager().map(|program| {
+            let result = crate::ui::run_external(program, std::slice::from_ref(&dir));
+            self.terminal_reset = true;
+            result.map(|()| program).map_err(|err| err.to_string())
+        });
+        self.modal = Some(Modal::Notice(Notice {
+            title: "Config Location".to_string(),
+            message: config_location_message(&path, opened),
+            scroll: 0,
+        }));
+    }
+
     fn open_local_folder_in_iterm(&mut self, local_path: &str) -> anyhow::Result<()> {
         let args = vec![
             "-a".to_string(),
@@ -1995,6 +4617,16 @@ impl App {
         Ok(())
     }
 
+    fn require_tool(&mut self, available: bool, name: &str) -> bool {
+        if !available {
+            self.push_toast(
+                format!("{name} not found on PATH; install it to use this action"),
+                ToastLevel::Warning,
+            );
+        }
+        available
+    }
+
     fn ensure_local_bind_path_exists(&mut self, local_path: &str) -> bool {
         let path = std::path::Path::new(local_path);
         if path.is_dir() {
@@ -2025,11 +4657,12 @@ impl App {
     }
 
     fn move_binding_selection(&mut self, delta: i32) {
-        if self.state.bindings.is_empty() {
+        let visible = self.visible_binding_indices().len();
+        if visible == 0 {
             self.selected = 0;
             return;
         }
-        let max = self.state.bindings.len() as i32 - 1;
+        let max = visible as i32 - 1;
         let mut next = self.selected as i32 + delta;
         if next < 0 {
             next = 0;
@@ -2039,6 +4672,11 @@ impl App {
         self.selected = next as usize;
     }
 
+    fn clamp_binding_selection(&mut self) {
+        let visible = self.visible_binding_indices().len();
+        self.selected = self.selected.min(visible.saturating_sub(1));
+    }
+
     fn move_sync_selection(&mut self, delta: i32) {
         if self.syncs.is_empty() {
             self.selected = 0;
@@ -2069,7 +4707,21 @@ impl App {
         self.selected = next as usize;
     }
 
-    fn connect_selected(&mut self) {
+    fn open_connect_command_modal(&mut self) {
+        let Some(droplet) = self.selected_droplet() else {
+            self.push_toast("No droplet selected", ToastLevel::Warning);
+            return;
+        };
+        self.modal = Some(Modal::ConnectCommand(ConnectCommandForm {
+            droplet_name: droplet.name.clone(),
+            method: self.state.settings.connect_method,
+            command: TextInput::new(self.last_connect_command.clone()),
+        }));
+    }
+
+    /// Opens a shell on the selected droplet, or runs `command` there and waits for Enter
+    /// before returning so its output stays readable.
+    fn connect_selected(&mut self, method: ConnectMethod, command: Option<&str>) {
         let droplet = match self.selected_droplet() {
             Some(droplet) => droplet,
             None => {
@@ -2081,48 +4733,357 @@ impl App {
             self.push_toast("Droplet must be running", ToastLevel::Warning);
             return;
         }
-        let droplet_id = droplet.id.to_string();
-        if let Err(err) = crate::ui::run_interactive(&["compute", "ssh", &droplet_id]) {
+        let (program, args) = match method {
+            ConnectMethod::Doctl => {
+                let mut args = doctl::context_args();
+                args.extend(doctl_connect_args(droplet.id, command));
+                ("doctl", args)
+            }
+            ConnectMethod::Ssh => match self.selected_ssh_config() {
+                Ok(ssh) => ("ssh", ssh_connect_args(&ssh, command)),
+                Err(err) => {
+                    self.push_toast(err.to_string(), ToastLevel::Warning);
+                    return;
+                }
+            },
+        };
+        let available = match method {
+            ConnectMethod::Doctl => self.tools.doctl,
+            ConnectMethod::Ssh => self.tools.ssh,
+        };
+        if !self.require_tool(available, program) {
+            return;
+        }
+        let use_tmux = self
+            .state
+            .settings
+            .tmux_new_window
+            .unwrap_or_else(crate::ui::inside_tmux);
+        if use_tmux {
+            match crate::ui::run_in_tmux_window(program, &args) {
+                Ok(()) => self.push_toast(
+                    "Opened connection in a new tmux window",
+                    ToastLevel::Success,
+                ),
+                Err(err) => self.push_toast(err.to_string(), ToastLevel::Error),
+            }
+            return;
+        }
+        let result = match (method, command) {
+            (_, Some(_)) => crate::ui::run_external_and_wait(program, &args),
+            (ConnectMethod::Doctl, None) => {
+                let args: Vec<&str> = args.iter().map(String::as_str).collect();
+                crate::ui::run_interactive(&args)
+            }
+            (ConnectMethod::Ssh, None) => crate::ui::run_external(program, &args),
+        };
+        if let Err(err) = result {
             self.push_toast(err.to_string(), ToastLevel::Error);
         }
         self.terminal_reset = true;
     }
 
     fn cleanup_stale(&mut self) {
-        let before = self.state.bindings.len();
-        self.state.bindings.retain(|binding| {
-            binding
+        let is_stale = |binding: &PortBinding| {
+            !binding
                 .tunnel_pid
                 .map(ports::is_pid_running)
                 .unwrap_or(false)
+        };
+        let stale: Vec<PortBinding> = self
+            .state
+            .bindings
+            .iter()
+            .filter(|binding| is_stale(binding))
+            .cloned()
+            .collect();
+        if !stale.is_empty() {
+            self.update_state(|state| state.bindings.retain(|binding| !is_stale(binding)));
+        }
+        self.clamp_binding_selection();
+
+        let mut message = if stale.is_empty() {
+            "No stale bindings found.".to_string()
+        } else {
+            format!(
+                "Removed {} stale binding{}:\n{}",
+                stale.len(),
+                if stale.len() == 1 { "" } else { "s" },
+                binding_summary_lines(&stale)
+            )
+        };
+
+        // Only trust the droplet list once it has loaded at least once.
+        let orphans: Vec<&PortBinding> = if self.last_refresh.is_some() {
+            self.state
+                .bindings
+                .iter()
+                .filter(|binding| {
+                    !self
+                        .droplets
+                        .iter()
+                        .any(|droplet| droplet.id == binding.droplet_id)
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        if orphans.is_empty() {
+            if stale.is_empty() {
+                self.push_toast("No stale bindings found", ToastLevel::Info);
+            } else {
+                self.modal = Some(Modal::Notice(Notice {
+                    title: "Stale Bindings Cleaned".to_string(),
+                    message,
+                    scroll: 0,
+                }));
+            }
+            return;
+        }
+
+        let local_ports = orphans.iter().map(|binding| binding.local_port).collect();
+        let orphans: Vec<PortBinding> = orphans.into_iter().cloned().collect();
+        message.push_str(&format!(
+            "\n\n{} binding{} point at droplets that no longer exist:\n{}\n\nStop and remove them?",
+            orphans.len(),
+            if orphans.len() == 1 { "" } else { "s" },
+            binding_summary_lines(&orphans)
+        ));
+        self.modal = Some(Modal::Confirm(Confirm {
+            title: "Orphaned Bindings".to_string(),
+            message,
+            action: ConfirmAction::RemoveOrphanBindings { local_ports },
+        }));
+    }
+
+    /// Stops running tunnels for the given local ports and drops their bindings.
+    fn remove_bindings(&mut self, local_ports: &[u16]) {
+        let targets: Vec<PortBinding> = self
+            .state
+            .bindings
+            .iter()
+            .filter(|binding| local_ports.contains(&binding.local_port))
+            .cloned()
+            .collect();
+        for binding in &targets {
+            if let Some(pid) = binding.tunnel_pid {
+                self.spawn(Task::StopTunnel {
+                    port: binding.local_port,
+                    pid,
+                });
+            }
+        }
+        self.update_state(|state| {
+            state.bindings.retain(|binding| {
+                !local_ports.contains(&binding.local_port) || binding.tunnel_pid.is_some()
+            })
         });
-        let removed = before.saturating_sub(self.state.bindings.len());
-        if removed > 0 {
-            let _ = config::save_state(&self.state);
+        self.clamp_binding_selection();
+        self.push_toast(
+            format!(
+                "Removing {} orphaned binding{}",
+                targets.len(),
+                if targets.len() == 1 { "" } else { "s" }
+            ),
+            ToastLevel::Info,
+        );
+    }
+
+    fn restart_selected_binding(&mut self) {
+        let Some(mut binding) = self.selected_binding().cloned() else {
+            return;
+        };
+        if binding
+            .tunnel_pid
+            .map(ports::is_pid_running)
+            .unwrap_or(false)
+        {
+            self.push_toast("Tunnel is already running", ToastLevel::Info);
+            return;
+        }
+        if !self.require_tool(self.tools.ssh, "ssh") {
+            return;
+        }
+        if !ports::is_port_available(&binding.bind_address, binding.local_port) {
+            self.push_toast(
+                format!(
+                    "Local port {} is in use by another process",
+                    binding.local_port
+                ),
+                ToastLevel::Warning,
+            );
+            return;
+        }
+        binding.tunnel_pid = None;
+        self.spawn(Task::StartTunnel(binding));
+    }
+
+    /// Runs a reconnect sweep after a system resume, if any tunnel died while asleep.
+    fn reconnect_after_resume(&mut self) {
+        let any_dead = self
+            .state
+            .bindings
+            .iter()
+            .any(|binding| !binding.tunnel_pid.is_some_and(ports::is_pid_running));
+        if any_dead {
+            self.push_toast("Resumed from sleep; reconnecting tunnels", ToastLevel::Info);
+            self.reconnect_all_tunnels();
+        }
+    }
+
+    /// Restarts every binding whose tunnel process has died, e.g. after the laptop slept.
+    fn reconnect_all_tunnels(&mut self) {
+        let (alive, dead): (Vec<PortBinding>, Vec<PortBinding>) = self
+            .state
+            .bindings
+            .iter()
+            .cloned()
+            .partition(|binding| binding.tunnel_pid.is_some_and(ports::is_pid_running));
+        if dead.is_empty() {
             self.push_toast(
-                format!("Removed {removed} stale bindings"),
+                format!("All {} tunnel(s) already alive", alive.len()),
                 ToastLevel::Info,
             );
-        } else {
-            self.push_toast("No stale bindings found", ToastLevel::Info);
+            return;
         }
+        if !self.require_tool(self.tools.ssh, "ssh") {
+            return;
+        }
+        self.spawn(Task::ReconnectTunnels {
+            bindings: dead,
+            alive: alive.len(),
+        });
     }
 
-    fn unbind_selected(&mut self) {
-        if self.state.bindings.is_empty() {
+    /// Starts a saved workspace's tunnels and syncs, like `workspace activate` on the CLI,
+    /// but through the app's own state so nothing it records is overwritten later.
+    fn activate_workspace(&mut self, name: &str) {
+        let Some(workspace) = self.find_workspace(name) else {
+            return;
+        };
+        if !workspace.bindings.is_empty() && !self.require_tool(self.tools.ssh, "ssh") {
+            return;
+        }
+        let (bindings, conflicts, alive) = self.update_state(|state| {
+            let (mut bindings, mut conflicts, mut alive) = (Vec::new(), Vec::new(), 0);
+            for recorded in &workspace.bindings {
+                match ports::place_recorded_binding(&mut state.bindings, recorded) {
+                    Ok(index)
+                        if state.bindings[index]
+                            .tunnel_pid
+                            .is_some_and(ports::is_pid_running) =>
+                    {
+                        alive += 1
+                    }
+                    Ok(index) => bindings.push(state.bindings[index].clone()),
+                    Err(index) => conflicts.push((
+                        recorded.local_port,
+                        format!(
+                            "in use by the tunnel to {}:{}",
+                            state.bindings[index].droplet_name, state.bindings[index].remote_port
+                        ),
+                    )),
+                }
+            }
+            (bindings, conflicts, alive)
+        });
+        self.spawn(Task::ActivateWorkspace {
+            name: workspace.name,
+            bindings,
+            conflicts,
+            alive,
+            syncs: workspace.syncs,
+        });
+    }
+
+    /// Stops a saved workspace's tunnels and pauses its syncs; stopped bindings are unbound.
+    fn deactivate_workspace(&mut self, name: &str) {
+        let Some(workspace) = self.find_workspace(name) else {
+            return;
+        };
+        let tunnels = self.update_state(|state| {
+            let mut tunnels = Vec::new();
+            for recorded in &workspace.bindings {
+                let Some(index) = state
+                    .bindings
+                    .iter()
+                    .position(|binding| ports::same_forward(binding, recorded))
+                else {
+                    continue;
+                };
+                match state.bindings[index]
+                    .tunnel_pid
+                    .filter(|pid| ports::is_pid_running(*pid))
+                {
+                    Some(pid) => tunnels.push((recorded.local_port, pid)),
+                    None => {
+                        state.bindings.remove(index);
+                    }
+                }
+            }
+            tunnels
+        });
+        self.clamp_binding_selection();
+        self.spawn(Task::DeactivateWorkspace {
+            name: workspace.name,
+            tunnels,
+            syncs: workspace.syncs,
+        });
+    }
+
+    fn find_workspace(&mut self, name: &str) -> Option<Workspace> {
+        let workspace = self
+            .state
+            .workspaces
+            .iter()
+            .find(|workspace| workspace.name == name)
+            .cloned();
+        if workspace.is_none() {
+            self.push_toast(format!("No workspace named '{name}'"), ToastLevel::Error);
+        }
+        workspace
+    }
+
+    /// Toasts `summary` when everything worked, else shows it with each failure.
+    fn report_workspace(
+        &mut self,
+        title: &str,
+        summary: String,
+        failed: &[(u16, String)],
+        syncs: &[(String, bool)],
+    ) {
+        if failed.is_empty() && syncs.iter().all(|(_, ok)| *ok) {
+            self.push_toast(summary, ToastLevel::Success);
             return;
         }
-        if let Some(binding) = self.state.bindings.get(self.selected).cloned() {
+        let mut lines = vec![summary, String::new()];
+        lines.extend(
+            failed
+                .iter()
+                .map(|(port, reason)| format!("tunnel {port}: FAILED, {reason}")),
+        );
+        lines.extend(syncs.iter().map(|(line, _)| line.clone()));
+        self.modal = Some(Modal::Notice(Notice {
+            title: title.to_string(),
+            message: lines.join("\n"),
+            scroll: 0,
+        }));
+    }
+
+    fn unbind_selected(&mut self) {
+        if let Some(binding) = self.selected_binding().cloned() {
             if let Some(pid) = binding.tunnel_pid {
                 self.spawn(Task::StopTunnel {
                     port: binding.local_port,
                     pid,
                 });
             } else {
-                self.state
-                    .bindings
-                    .retain(|item| item.local_port != binding.local_port);
-                let _ = config::save_state(&self.state);
+                self.update_state(|state| {
+                    state
+                        .bindings
+                        .retain(|item| item.local_port != binding.local_port)
+                });
+                self.clamp_binding_selection();
             }
         }
     }
@@ -2138,13 +5099,35 @@ impl App {
         if self.syncs.is_empty() {
             return;
         }
-        if let Some(sync) = self.syncs.get(self.selected).cloned() {
-            let ssh = self.syncs_context.clone();
-            self.spawn(Task::DeleteSync {
-                name: sync.name,
-                ssh,
-            });
+        let Some(sync) = self.syncs.get(self.selected).cloned() else {
+            return;
+        };
+        if sync
+            .status
+            .as_deref()
+            .is_some_and(mutagen::is_syncing_status)
+        {
+            self.modal = Some(Modal::Confirm(Confirm {
+                title: "Terminate Syncing Session".to_string(),
+                message: format!(
+                    "'{}' is mid-sync; terminating now can leave partially transferred files.\n\ny: flush (finish the current sync), then terminate\nt: terminate immediately\nn: cancel",
+                    sync.name
+                ),
+                action: ConfirmAction::TerminateSyncing { sync },
+            }));
+            return;
         }
+        self.delete_sync(sync, false);
+    }
+
+    fn delete_sync(&mut self, sync: SyncSession, flush: bool) {
+        let ssh = self.syncs_context.clone();
+        self.spawn(Task::DeleteSync {
+            name: sync.name,
+            identifier: sync.identifier,
+            ssh,
+            flush,
+        });
     }
 
     fn selected_ssh_config(&self) -> anyhow::Result<SshConfig> {
@@ -2154,32 +5137,46 @@ impl App {
         if !droplet.is_running() {
             return Err(anyhow::anyhow!("Droplet must be running"));
         }
-        let public_ip = droplet
-            .public_ipv4
-            .clone()
-            .ok_or_else(|| anyhow::anyhow!("Droplet has no public IP"))?;
         let settings = &self.state.settings;
+        let host = droplet
+            .preferred_ip(settings.preferred_ip)
+            .map(str::to_string)
+            .ok_or_else(|| anyhow::anyhow!("Droplet has no IP address"))?;
         Ok(SshConfig {
             user: settings.default_ssh_user.clone(),
-            host: public_ip,
+            host,
             port: settings.default_ssh_port,
             key_path: settings.default_ssh_key_path.clone(),
+            use_agent: false,
         })
     }
 
     pub(crate) fn mutagen_actions(&self) -> Vec<MutagenAction> {
-        let droplet_ready = self.selected_ssh_config().is_ok();
+        if !self.tools.mutagen {
+            return self
+                .mutagen_action_list(false, false)
+                .into_iter()
+                .map(|mut action| {
+                    action.disabled_hint = "mutagen not found on PATH".to_string();
+                    action
+                })
+                .collect();
+        }
+        self.mutagen_action_list(true, self.selected_ssh_config().is_ok())
+    }
+
+    fn mutagen_action_list(&self, global_ready: bool, droplet_ready: bool) -> Vec<MutagenAction> {
         vec![
             MutagenAction {
                 label: "Global: Sync List".to_string(),
                 kind: MutagenActionKind::ListSyncs,
-                enabled: true,
+                enabled: global_ready,
                 disabled_hint: "Sync list unavailable".to_string(),
             },
             MutagenAction {
                 label: "Global: Turn Mutagen Off".to_string(),
                 kind: MutagenActionKind::DisableMutagen,
-                enabled: true,
+                enabled: global_ready,
                 disabled_hint: "Mutagen not available".to_string(),
             },
             MutagenAction {
@@ -2194,6 +5191,12 @@ impl App {
                 enabled: droplet_ready,
                 disabled_hint: "Select a running droplet with a public IP".to_string(),
             },
+            MutagenAction {
+                label: "Droplet: Reconcile Mountlist".to_string(),
+                kind: MutagenActionKind::Reconcile,
+                enabled: droplet_ready,
+                disabled_hint: "Select a running droplet with a public IP".to_string(),
+            },
             MutagenAction {
                 label: "Droplet: Remove All Bindings".to_string(),
                 kind: MutagenActionKind::RemoveDropletSyncs,
@@ -2208,19 +5211,31 @@ impl App {
             MutagenActionKind::AddSync => self.open_sync_modal(),
             MutagenActionKind::RestoreSyncs => self.restore_syncs(),
             MutagenActionKind::RemoveDropletSyncs => self.remove_droplet_syncs(),
+            MutagenActionKind::Reconcile => match self.selected_ssh_config() {
+                Ok(ssh) => self.spawn(Task::ReconcileSyncs { ssh }),
+                Err(err) => self.push_toast(err.to_string(), ToastLevel::Warning),
+            },
             MutagenActionKind::ListSyncs => self.open_syncs_screen_global(),
-            MutagenActionKind::DisableMutagen => {
-                let confirm = Confirm {
-                    title: "Disable Mutagen".to_string(),
-                    message: "Terminate all Mutagen sync sessions?".to_string(),
-                    action: ConfirmAction::DisableMutagen,
-                };
-                self.modal = Some(Modal::Confirm(confirm));
-            }
+            MutagenActionKind::DisableMutagen => self.confirm_terminate_all_syncs(),
         }
     }
 
+    fn confirm_terminate_all_syncs(&mut self) {
+        if !self.require_tool(self.tools.mutagen, "mutagen") {
+            return;
+        }
+        let confirm = Confirm {
+            title: "Disable Mutagen".to_string(),
+            message: "Terminate all Mutagen sync sessions?".to_string(),
+            action: ConfirmAction::DisableMutagen,
+        };
+        self.modal = Some(Modal::Confirm(confirm));
+    }
+
     fn remove_droplet_syncs(&mut self) {
+        if !self.require_tool(self.tools.mutagen, "mutagen") {
+            return;
+        }
         let droplet_name = self
             .selected_droplet()
             .map(|droplet| droplet.name.clone())
@@ -2240,6 +5255,11 @@ impl App {
         }
     }
 
+    /// Name of the loaded droplet a Mutagen session's beta host points at.
+    pub fn droplet_name_for_host(&self, host: &str) -> Option<&str> {
+        droplet_for_host(&self.droplets, host).map(|droplet| droplet.name.as_str())
+    }
+
     pub(crate) fn selected_droplet(&self) -> Option<&Droplet> {
         let indices = self.visible_indices();
         indices
@@ -2247,6 +5267,70 @@ impl App {
             .and_then(|idx| self.droplets.get(*idx))
     }
 
+    /// The `project_id` setting, if set to something non-blank.
+    pub fn active_project(&self) -> Option<&str> {
+        self.state
+            .settings
+            .project_id
+            .as_deref()
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+    }
+
+    /// Monthly and hourly price of the chosen size, when its details are loaded.
+    pub fn size_cost_preview(&self, size: Option<&Selection>) -> Option<String> {
+        let slug = &size?.value;
+        let size = self.sizes.iter().find(|size| &size.slug == slug)?;
+        Some(format_cost(size.price_monthly))
+    }
+
+    /// Favorites first, then by name.
+    fn sort_droplets(&mut self) {
+        sort_droplets(&mut self.droplets, &self.state.settings.favorites);
+    }
+
+    pub fn is_favorite(&self, droplet_id: u64) -> bool {
+        self.state.settings.favorites.contains(&droplet_id)
+    }
+
+    fn toggle_favorite(&mut self) {
+        let Some(droplet) = self.selected_droplet() else {
+            self.push_toast("No droplet selected", ToastLevel::Warning);
+            return;
+        };
+        let (id, name) = (droplet.id, droplet.name.clone());
+        let pinned = self.update_state(|state| {
+            let favorites = &mut state.settings.favorites;
+            if favorites.remove(&id) {
+                false
+            } else {
+                favorites.insert(id);
+                true
+            }
+        });
+        self.sort_droplets();
+        self.reselect_droplet(Some(id));
+        if pinned {
+            self.push_toast(format!("Pinned '{name}' to the top"), ToastLevel::Info);
+        } else {
+            self.push_toast(format!("Unpinned '{name}'"), ToastLevel::Info);
+        }
+    }
+
+    /// Keeps the Home selection on the same droplet after the list is replaced or re-sorted.
+    fn reselect_droplet(&mut self, droplet_id: Option<u64>) {
+        if self.screen != Screen::Home {
+            return;
+        }
+        self.selected = droplet_id
+            .and_then(|id| {
+                self.visible_indices()
+                    .iter()
+                    .position(|&idx| self.droplets[idx].id == id)
+            })
+            .unwrap_or(0);
+    }
+
     pub fn visible_indices(&self) -> Vec<usize> {
         self.droplets
             .iter()
@@ -2261,21 +5345,59 @@ impl App {
             .collect()
     }
 
+    fn selected_binding(&self) -> Option<&PortBinding> {
+        self.visible_binding_indices()
+            .get(self.selected)
+            .and_then(|idx| self.state.bindings.get(*idx))
+    }
+
+    /// Indices into `state.bindings` that pass the Bindings screen filter, in display order.
+    pub fn visible_binding_indices(&self) -> Vec<usize> {
+        let query = self.binding_filter.value.trim().to_lowercase();
+        let mut indices: Vec<usize> = self
+            .state
+            .bindings
+            .iter()
+            .enumerate()
+            .filter(|(_, binding)| binding_matches(binding, &query))
+            .map(|(idx, _)| idx)
+            .collect();
+        if self.binding_sort_by_port {
+            indices.sort_by_key(|idx| self.state.bindings[*idx].local_port);
+        }
+        indices
+    }
+
     pub fn push_toast(&mut self, message: impl Into<String>, level: ToastLevel) {
+        let message = message.into();
         self.toast = Some(Toast {
-            message: message.into(),
+            message: match level {
+                ToastLevel::Warning | ToastLevel::Error => logging::redact(&message),
+                _ => message,
+            },
             level,
             created_at: Utc::now(),
         });
     }
 
+    fn warn_if_truncated(&mut self, what: &str, count: usize) {
+        if let Some(warning) = doctl::truncation_warning(what, count) {
+            self.push_toast(warning, ToastLevel::Warning);
+        }
+    }
+
     pub fn shutdown(&mut self) {
         for binding in &self.state.bindings {
             if let Some(pid) = binding.tunnel_pid {
                 let _ = ports::stop_tunnel(pid);
             }
         }
-        let _ = config::save_state(&self.state);
+        let view = self.view_to_remember();
+        self.update_state(|state| {
+            if view.is_some() {
+                state.settings.last_view = view;
+            }
+        });
     }
 
     pub fn take_terminal_reset(&mut self) -> bool {
@@ -2288,11 +5410,22 @@ impl App {
     }
 
     pub fn pending_overlay_lines(&self) -> Vec<String> {
+        let elapsed = self
+            .pending_since
+            .map(|since| format!(" ({}s)", (Utc::now() - since).num_seconds()))
+            .unwrap_or_default();
         let mut lines = vec![format!(
-            "{} task{} in progress",
+            "{} task{} in progress{elapsed}",
             self.pending,
             if self.pending == 1 { "" } else { "s" }
         )];
+        if self.cancellable_pending > 0 {
+            lines.push("Esc cancels creates, snapshots, and rsync runs".to_string());
+        }
+
+        let mut steps: Vec<(&u64, &String)> = self.action_progress.iter().collect();
+        steps.sort();
+        lines.extend(steps.into_iter().map(|(_, step)| step.clone()));
 
         if self.pending_labels.is_empty() {
             lines.push("Waiting for background work...".to_string());
@@ -2322,7 +5455,17 @@ impl App {
     }
 
     fn track_task_start(&mut self, task: &Task) {
+        let background = is_background_task(task);
+        if !background && self.pending == self.background_pending {
+            self.pending_since = Some(Utc::now());
+        }
         self.pending += 1;
+        if background {
+            self.background_pending += 1;
+        }
+        if is_cancellable_task(task) {
+            self.cancellable_pending += 1;
+        }
         let label = pending_label_for_task(task);
         *self.pending_labels.entry(label.to_string()).or_insert(0) += 1;
     }
@@ -2331,6 +5474,12 @@ impl App {
         if self.pending > 0 {
             self.pending -= 1;
         }
+        if is_cancellable_result(result) {
+            self.cancellable_pending = self.cancellable_pending.saturating_sub(1);
+        }
+        if is_background_result(result) {
+            self.background_pending = self.background_pending.saturating_sub(1);
+        }
         let label = pending_label_for_result(result);
         if let Some(count) = self.pending_labels.get_mut(label) {
             if *count > 1 {
@@ -2339,9 +5488,44 @@ impl App {
                 self.pending_labels.remove(label);
             }
         }
+        // Polled progress belongs to snapshot tasks; drop it once none are left.
+        if matches!(result, TaskResult::SnapshotDelete(_))
+            && !self.pending_labels.contains_key(label)
+        {
+            self.action_progress.clear();
+        }
         if self.pending == 0 {
             self.pending_labels.clear();
         }
+        if self.pending <= self.background_pending {
+            self.pending_since = None;
+        }
+    }
+
+    /// Seconds a toast of this level stays visible, honoring settings overrides.
+    pub fn toast_ttl_secs(&self, level: ToastLevel) -> i64 {
+        let settings = &self.state.settings;
+        let overrides = &settings.toast_ttl_overrides;
+        let level_ttl = match level {
+            ToastLevel::Info => overrides.info,
+            ToastLevel::Success => overrides.success,
+            ToastLevel::Warning => overrides.warning,
+            ToastLevel::Error => overrides.error,
+        };
+        level_ttl
+            .or(settings.toast_ttl_secs)
+            .unwrap_or(DEFAULT_TOAST_TTL_SECS) as i64
+    }
+
+    pub fn show_loading_overlay(&self) -> bool {
+        if self.pending <= self.background_pending {
+            return false;
+        }
+        let delay = self.state.settings.overlay_delay_ms as i64;
+        match self.pending_since {
+            Some(since) => (Utc::now() - since).num_milliseconds() >= delay,
+            None => true,
+        }
     }
 
     fn snapshot_picker_items(&self) -> Vec<PickerItem> {
@@ -2351,6 +5535,7 @@ impl App {
                 label: format!("{} ({})", snap.name, snap.created_at),
                 value: snap.id.to_string(),
                 meta: None,
+                header: false,
             })
             .collect()
     }
@@ -2374,28 +5559,56 @@ impl Picker {
 
     pub fn refresh_filter(&mut self) {
         let query = self.query.value.to_lowercase();
-        self.filtered = self
-            .items
-            .iter()
-            .enumerate()
-            .filter_map(|(idx, item)| {
-                if query.is_empty()
-                    || item.label.to_lowercase().contains(&query)
-                    || item
-                        .meta
-                        .as_ref()
-                        .map(|meta| meta.to_lowercase().contains(&query))
-                        .unwrap_or(false)
-                {
-                    Some(idx)
-                } else {
-                    None
-                }
-            })
-            .collect();
+        let mut filtered = Vec::new();
+        let mut pending_header = None;
+        for (idx, item) in self.items.iter().enumerate() {
+            if item.header {
+                pending_header = Some(idx);
+                continue;
+            }
+            if query.is_empty()
+                || item.label.to_lowercase().contains(&query)
+                || item
+                    .meta
+                    .as_ref()
+                    .map(|meta| meta.to_lowercase().contains(&query))
+                    .unwrap_or(false)
+            {
+                // A group heading only shows when something under it matches.
+                filtered.extend(pending_header.take());
+                filtered.push(idx);
+            }
+        }
+        self.filtered = filtered;
         if self.selected >= self.filtered.len() {
             self.selected = 0;
         }
+        if self.selected_index().is_none() {
+            self.move_selection(1);
+        }
+    }
+
+    /// Index into `items` of the highlighted row, unless it is a group heading.
+    pub fn selected_index(&self) -> Option<usize> {
+        self.filtered
+            .get(self.selected)
+            .copied()
+            .filter(|&idx| !self.items[idx].header)
+    }
+
+    /// Moves the highlight by one row in `delta`'s direction, skipping group headings.
+    fn move_selection(&mut self, delta: isize) {
+        let mut pos = self.selected;
+        loop {
+            pos = match pos.checked_add_signed(delta) {
+                Some(pos) if pos < self.filtered.len() => pos,
+                _ => return,
+            };
+            if !self.items[self.filtered[pos]].header {
+                self.selected = pos;
+                return;
+            }
+        }
     }
 }
 
@@ -2417,6 +5630,45 @@ fn handle_text_input(input: &mut TextInput, key: KeyEvent) {
     }
 }
 
+/// `compute ssh` arguments; a command goes through `--ssh-command` instead of a shell.
+fn doctl_connect_args(droplet_id: u64, command: Option<&str>) -> Vec<String> {
+    let mut args = vec![
+        "compute".to_string(),
+        "ssh".to_string(),
+        droplet_id.to_string(),
+    ];
+    if let Some(command) = command {
+        args.extend(["--ssh-command".to_string(), command.to_string()]);
+    }
+    args
+}
+
+/// Plain ssh arguments; a command is run with a forced tty so tools like `htop` work.
+fn ssh_connect_args(ssh: &SshConfig, command: Option<&str>) -> Vec<String> {
+    let mut args = ssh_identity_args(&ssh.key_path, ssh.use_agent);
+    args.extend(["-p".to_string(), ssh.port.to_string()]);
+    if command.is_some() {
+        args.push("-t".to_string());
+    }
+    args.push(format!("{}@{}", ssh.user, ssh.host));
+    args.extend(command.map(str::to_string));
+    args
+}
+
+fn saved_selection(selection: &Selection) -> SavedSelection {
+    SavedSelection {
+        label: selection.label.clone(),
+        value: selection.value.clone(),
+    }
+}
+
+fn selection_from_saved(saved: &SavedSelection) -> Selection {
+    Selection {
+        label: saved.label.clone(),
+        value: saved.value.clone(),
+    }
+}
+
 fn split_csv(value: &str) -> Vec<String> {
     value
         .split(',')
@@ -2426,7 +5678,14 @@ fn split_csv(value: &str) -> Vec<String> {
         .collect()
 }
 
-fn build_remote_browser_entries(path: &str, directories: Vec<String>) -> Vec<RemoteBrowserEntry> {
+/// Rows the remote browser's PageUp/PageDown jump.
+const REMOTE_BROWSER_PAGE: usize = 10;
+
+fn build_remote_browser_entries(
+    path: &str,
+    directories: &[String],
+    view: &RemoteBrowserView,
+) -> Vec<RemoteBrowserEntry> {
     let mut entries = Vec::new();
     if path != "/" {
         entries.push(RemoteBrowserEntry {
@@ -2434,10 +5693,19 @@ fn build_remote_browser_entries(path: &str, directories: Vec<String>) -> Vec<Rem
             path: remote_parent_path(path),
         });
     }
-    for dir in directories {
+    let filter = view.filter.to_lowercase();
+    let mut dirs: Vec<&String> = directories
+        .iter()
+        .filter(|dir| view.show_hidden || !dir.starts_with('.'))
+        .filter(|dir| filter.is_empty() || dir.to_lowercase().contains(&filter))
+        .collect();
+    if view.case_insensitive {
+        dirs.sort_by_key(|dir| dir.to_lowercase());
+    }
+    for dir in dirs {
         entries.push(RemoteBrowserEntry {
             label: format!("{dir}/"),
-            path: join_remote_path(path, &dir),
+            path: join_remote_path(path, dir),
         });
     }
     entries
@@ -2466,6 +5734,61 @@ fn join_remote_path(base: &str, child: &str) -> String {
     }
 }
 
+/// `outcome` is the stats summary on success or the error text on failure.
+fn rsync_result_label(direction: RsyncDirection, outcome: Result<&str, &str>) -> String {
+    let verb = match direction {
+        RsyncDirection::Up => "push",
+        RsyncDirection::Down => "pull",
+    };
+    match outcome {
+        Ok(summary) => format!("{verb}ed: {summary}"),
+        Err(error) => {
+            let first_line = error.lines().next().unwrap_or_default().trim();
+            format!("{verb} failed: {first_line}")
+        }
+    }
+}
+
+/// Binds sharing `group`, in registry order.
+fn rsync_group_members(binds: &[RsyncBind], group: &str) -> Vec<RsyncBind> {
+    binds
+        .iter()
+        .filter(|bind| bind.group.as_deref() == Some(group))
+        .cloned()
+        .collect()
+}
+
+/// DNS records rarely change; refetch them at most this often.
+const DOMAIN_RECORDS_TTL_SECS: i64 = 15 * 60;
+
+/// How long a remote-folder probe is reused before reopening the actions modal probes again.
+const RSYNC_REMOTE_CHECK_TTL_SECS: i64 = 60;
+
+fn rsync_remote_key(bind: &RsyncBind) -> String {
+    format!(
+        "{}@{}:{}:{}",
+        bind.ssh_user, bind.host, bind.ssh_port, bind.remote_path
+    )
+}
+
+/// The address to reach a bind's droplet at: the stored host while the droplet still has
+/// it, else the droplet's current preferred address, else the stored host when the droplet
+/// isn't in the loaded list (another context, or destroyed).
+fn resolve_rsync_host(bind: &RsyncBind, droplets: &[Droplet], preferred: BindInterface) -> String {
+    let droplet = bind
+        .droplet_id
+        .and_then(|id| droplets.iter().find(|droplet| droplet.id == id));
+    let Some(droplet) = droplet else {
+        return bind.host.clone();
+    };
+    if droplet_for_host(std::slice::from_ref(droplet), &bind.host).is_some() {
+        return bind.host.clone();
+    }
+    droplet
+        .preferred_ip(preferred)
+        .map_or_else(|| bind.host.clone(), str::to_string)
+}
+
 fn same_rsync_bind(a: &RsyncBind, b: &RsyncBind) -> bool {
     a.ssh_user == b.ssh_user
         && a.host == b.host
@@ -2474,6 +5797,42 @@ fn same_rsync_bind(a: &RsyncBind, b: &RsyncBind) -> bool {
         && a.local_path == b.local_path
 }
 
+/// Whether `key` moves focus off the current form field.
+fn leaves_field(key: &KeyEvent) -> bool {
+    matches!(
+        key.code,
+        KeyCode::Tab | KeyCode::BackTab | KeyCode::Up | KeyCode::Down | KeyCode::Enter
+    )
+}
+
+/// Trims an SSH key field as focus leaves it and refreshes its inline warning.
+fn check_ssh_key_field(input: &mut TextInput, use_agent: bool, warning: &mut Option<String>) {
+    let trimmed = input.value.trim();
+    if trimmed.len() != input.value.len() {
+        *input = TextInput::new(trimmed);
+    }
+    *warning = tasks::ssh_identity_problem(&input.value, use_agent);
+}
+
+/// Flips a form's agent toggle; the inline warning then describes the agent or the key.
+fn toggle_ssh_agent(use_agent: &mut bool, key_path: &TextInput, warning: &mut Option<String>) {
+    *use_agent = !*use_agent;
+    *warning = tasks::ssh_identity_problem(&key_path.value, *use_agent);
+}
+
+/// A remote folder typed into the manual bind form: absolute or `~`-relative, no trailing slash.
+fn validate_remote_path(value: &str) -> Result<String, &'static str> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err("Remote folder is required");
+    }
+    if !value.starts_with('/') && value != "~" && !value.starts_with("~/") {
+        return Err("Remote folder must be an absolute or ~/ path");
+    }
+    let trimmed = value.trim_end_matches('/');
+    Ok(if trimmed.is_empty() { "/" } else { trimmed }.to_string())
+}
+
 fn build_rsync_local_path(droplet_name: &str, remote_path: &str) -> String {
     let home = std::env::var("HOME").unwrap_or_else(|_| "~".to_string());
     let droplet = sanitize_path_component(droplet_name, "droplet");
@@ -2523,6 +5882,7 @@ pub(crate) enum MutagenActionKind {
     AddSync,
     RestoreSyncs,
     RemoveDropletSyncs,
+    Reconcile,
 }
 
 #[derive(Debug, Clone)]
@@ -2562,63 +5922,477 @@ fn parse_sync_paths(value: &str) -> anyhow::Result<Vec<SyncPath>> {
     Ok(paths)
 }
 
+fn sort_droplets(droplets: &mut [Droplet], favorites: &HashSet<u64>) {
+    droplets.sort_by(|a, b| {
+        favorites
+            .contains(&b.id)
+            .cmp(&favorites.contains(&a.id))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+}
+
+fn config_location_message(path: &std::path::Path, opened: Option<Result<&str, String>>) -> String {
+    let dir = path.parent().unwrap_or(path);
+    let mut message = format!(
+        "State file: {}\nConfig directory: {}\n\nSet {} to use a different state file.",
+        path.display(),
+        dir.display(),
+        config::CONFIG_ENV_VAR
+    );
+    match opened {
+        Some(Ok(program)) => message.push_str(&format!("\n\nOpened the directory with {program}.")),
+        Some(Err(err)) => message.push_str(&format!("\n\nCouldn't open the directory: {err}")),
+        None => {}
+    }
+    message
+}
+
+/// Interface a new bind starts on: the preferred one when the droplet has it, otherwise
+/// whichever address exists. `None` when the droplet has no address at all.
+fn default_bind_interface(droplet: &Droplet, preferred: BindInterface) -> Option<BindInterface> {
+    let has_public = droplet.public_ip().is_some();
+    let has_private = droplet.private_ipv4.is_some();
+    match (preferred, has_public, has_private) {
+        (BindInterface::Private, _, true) | (BindInterface::Public, false, true) => {
+            Some(BindInterface::Private)
+        }
+        (_, true, _) => Some(BindInterface::Public),
+        (_, false, false) => None,
+    }
+}
+
+fn droplet_for_host<'a>(droplets: &'a [Droplet], host: &str) -> Option<&'a Droplet> {
+    let host = mutagen::normalized_host(host);
+    droplets.iter().find(|droplet| {
+        [
+            &droplet.public_ipv4,
+            &droplet.public_ipv6,
+            &droplet.private_ipv4,
+        ]
+        .into_iter()
+        .flatten()
+        .any(|ip| mutagen::normalized_host(ip) == host)
+    })
+}
+
+/// Sets the remote side of the last `local->remote` item in the sync form's path list.
+/// With no local path typed yet, the cursor lands before `->` so it can be filled in.
+fn with_remote_sync_path(input: &TextInput, remote: &str) -> TextInput {
+    let (head, last) = match input.value.rfind(',') {
+        Some(idx) => (
+            format!("{} ", input.value[..=idx].trim_end()),
+            &input.value[idx + 1..],
+        ),
+        None => (String::new(), input.value.as_str()),
+    };
+    let local = last.split("->").next().unwrap_or("").trim();
+    let prefix = format!("{head}{local}");
+    let mut updated = TextInput::new(format!("{prefix}->{remote}"));
+    if local.is_empty() {
+        updated.cursor = prefix.len();
+    }
+    updated
+}
+
+/// Longest droplet name that is still a single hostname label.
+const MAX_DROPLET_NAME_LEN: usize = 63;
+
+/// Normalizes `name` into a hostname DigitalOcean accepts: lowercase letters, digits, and
+/// single hyphens (for underscores, dots, and spaces), no hyphen at either end, at most
+/// `MAX_DROPLET_NAME_LEN` characters.
 fn sanitize_name(name: &str) -> String {
     let mut out = String::with_capacity(name.len());
-    let mut last_dash = false;
     for ch in name.trim().chars() {
-        let next = match ch {
-            'a'..='z' | 'A'..='Z' | '0'..='9' => {
-                last_dash = false;
-                Some(ch)
-            }
-            '-' | '_' => {
-                last_dash = false;
-                Some(ch)
-            }
-            _ if ch.is_whitespace() || ch == '.' => {
-                if last_dash {
-                    None
-                } else {
-                    last_dash = true;
-                    Some('-')
-                }
-            }
-            _ => None,
-        };
-        if let Some(ch) = next {
-            out.push(ch);
+        match ch {
+            'a'..='z' | '0'..='9' => out.push(ch),
+            'A'..='Z' => out.push(ch.to_ascii_lowercase()),
+            '-' | '_' | '.' | ' ' | '\t' if !out.is_empty() && !out.ends_with('-') => out.push('-'),
+            _ => {}
         }
     }
-    let trimmed = out.trim_matches('-');
+    out.truncate(MAX_DROPLET_NAME_LEN);
+    let trimmed = out.trim_end_matches('-');
     if trimmed.is_empty() {
         "droplet".to_string()
-    } else if trimmed.len() == out.len() {
-        out
     } else {
         trimmed.to_string()
     }
 }
 
+fn parse_name_template(template: &str) -> Option<(&str, usize, &str)> {
+    let start = template.find("{n")?;
+    let end = start + template[start..].find('}')?;
+    let spec = &template[start + 2..end];
+    let width = match spec.strip_prefix(':') {
+        Some(width) => width.trim_start_matches('0').parse::<usize>().unwrap_or(1),
+        None if spec.is_empty() => 1,
+        None => return None,
+    };
+    Some((&template[..start], width, &template[end + 1..]))
+}
+
+fn next_template_name(template: &str, existing: &[&str]) -> Option<String> {
+    // sanitize_name lowercases the result, so the counters we look for are lowercase too.
+    let template = template.trim().to_ascii_lowercase();
+    if template.is_empty() {
+        return None;
+    }
+    let name = match parse_name_template(&template) {
+        Some((prefix, width, suffix)) => {
+            let highest = existing
+                .iter()
+                .filter_map(|name| name.strip_prefix(prefix)?.strip_suffix(suffix))
+                .filter(|counter| {
+                    !counter.is_empty() && counter.chars().all(|c| c.is_ascii_digit())
+                })
+                .filter_map(|counter| counter.parse::<u64>().ok())
+                .max()
+                .unwrap_or(0);
+            format!("{prefix}{:0width$}{suffix}", highest + 1)
+        }
+        None => template,
+    };
+    let name = sanitize_name(&name);
+    if is_valid_droplet_name(&name) {
+        Some(name)
+    } else {
+        None
+    }
+}
+
+/// Next free name after `name`: `web-07` becomes `web-08`, and `web` becomes `web-2`.
+fn duplicate_name(name: &str, existing: &[&str]) -> Option<String> {
+    let digits = name.len() - name.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    let (prefix, width, start) = if digits > 0 {
+        let (prefix, counter) = name.split_at(name.len() - digits);
+        (
+            prefix.to_string(),
+            digits,
+            counter.parse::<u64>().unwrap_or(0),
+        )
+    } else {
+        (format!("{name}-"), 1, 1)
+    };
+    let highest = existing
+        .iter()
+        .filter_map(|name| name.strip_prefix(prefix.as_str()))
+        .filter(|counter| !counter.is_empty() && counter.chars().all(|c| c.is_ascii_digit()))
+        .filter_map(|counter| counter.parse::<u64>().ok())
+        .fold(start, u64::max);
+    let name = sanitize_name(&format!("{prefix}{:0width$}", highest + 1));
+    is_valid_droplet_name(&name).then_some(name)
+}
+
+/// The same hostname rules `sanitize_name` produces: lowercase letters, digits and
+/// inner hyphens, at most `MAX_DROPLET_NAME_LEN` characters.
+fn is_valid_droplet_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= MAX_DROPLET_NAME_LEN
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '-')
+        && !name.starts_with('-')
+        && !name.ends_with('-')
+}
+
+fn binding_summary_lines(bindings: &[PortBinding]) -> String {
+    bindings
+        .iter()
+        .map(|binding| {
+            format!(
+                "  {}:{} -> {}:{}",
+                binding.bind_address, binding.local_port, binding.droplet_name, binding.remote_port
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Hotkeys offered by the command palette on each screen, with what they do.
+fn palette_actions(screen: Screen) -> &'static [(&'static str, &'static str)] {
+    match screen {
+        Screen::Home => &[
+            ("Enter", "Connect to droplet"),
+            ("x", "Connect with the other method"),
+            ("e", "Connect and run a command"),
+            ("g", "Refresh"),
+            ("G", "Refresh selected droplet"),
+            ("6", "Copy public IPv6 address"),
+            ("*", "Pin/unpin droplet as favorite"),
+            ("c", "Create droplet"),
+            ("D", "Create another like this droplet"),
+            ("r", "Restore droplet from snapshot"),
+            ("s", "Snapshot + delete droplet"),
+            ("d", "Delete droplet"),
+            ("b", "Bind local port"),
+            ("R", "Reconnect dead tunnels"),
+            ("m", "Mutagen config"),
+            ("M", "Delete all Mutagen syncs for droplet"),
+            ("C", "Switch doctl context"),
+            ("S", "Show config directory and state file"),
+            ("E", "Show recent errors"),
+            ("o", "Browse remote folders"),
+            ("u", "RSYNC binds"),
+            ("p", "Port bindings"),
+            ("f", "Toggle running-only filter"),
+            ("q", "Quit"),
+        ],
+        Screen::Bindings => &[
+            ("d", "Unbind selected port"),
+            ("r", "Restart tunnel"),
+            ("R", "Reconnect all dead tunnels"),
+            ("h", "Check which tunnels are serving"),
+            ("l", "Edit binding label"),
+            ("w", "Activate workspace"),
+            ("W", "Deactivate workspace"),
+            ("x", "Clean up stale bindings"),
+            ("/", "Filter bindings"),
+            ("s", "Toggle sort by local port"),
+            ("q", "Back to Home"),
+        ],
+        Screen::Syncs => &[
+            ("d", "Terminate selected sync"),
+            ("X", "Terminate all syncs"),
+            ("g", "Refresh"),
+            ("q", "Back to Home"),
+        ],
+        Screen::RsyncBinds => &[
+            ("Enter", "Open bind actions"),
+            ("n", "New bind from typed-in paths"),
+            ("?", "Show shortcuts"),
+            ("q", "Back to Home"),
+        ],
+    }
+}
+
+fn palette_key(key: &str) -> Option<KeyEvent> {
+    let code = match key {
+        "Enter" => KeyCode::Enter,
+        _ => {
+            let mut chars = key.chars();
+            let ch = chars.next()?;
+            if chars.next().is_some() {
+                return None;
+            }
+            KeyCode::Char(ch)
+        }
+    };
+    Some(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+fn sync_plan_lines(plan: &SyncPlan) -> String {
+    let sections = [
+        (SyncPlanAction::Create, "New"),
+        (SyncPlanAction::Resume, "Resumed"),
+        (SyncPlanAction::Skip, "Skipped duplicates"),
+    ];
+    let mut lines = Vec::new();
+    for (action, heading) in sections {
+        let items: Vec<_> = plan
+            .items
+            .iter()
+            .filter(|item| item.action == action)
+            .collect();
+        if items.is_empty() {
+            continue;
+        }
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.push(format!("{heading} ({}):", items.len()));
+        for item in items {
+            let note = if action == SyncPlanAction::Create && !item.new_entry {
+                " (in ~/.mountlist, session missing)"
+            } else {
+                ""
+            };
+            lines.push(format!("  {} -> {}{note}", item.local, item.remote));
+        }
+    }
+    lines.join("\n")
+}
+
+fn binding_matches(binding: &PortBinding, query: &str) -> bool {
+    query.is_empty()
+        || binding.droplet_name.to_lowercase().contains(query)
+        || binding.public_ip.contains(query)
+        || binding.local_port.to_string().contains(query)
+        || binding.remote_port.to_string().contains(query)
+        || binding
+            .label
+            .as_deref()
+            .is_some_and(|label| label.to_lowercase().contains(query))
+}
+
+/// Trims a label as typed; blank means no label.
+fn normalize_label(value: &str) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// An empty allow list (`allowed_regions`/`allowed_sizes`) allows everything.
+fn is_allowed(allowed: &[String], slug: &str) -> bool {
+    allowed.is_empty() || allowed.iter().any(|item| item.eq_ignore_ascii_case(slug))
+}
+
+/// Allowed regions, preferring those accepting new droplets unless none are.
+fn region_picker_items(regions: &[Region], allowed: &[String]) -> Vec<PickerItem> {
+    let allowed: Vec<&Region> = regions
+        .iter()
+        .filter(|region| is_allowed(allowed, &region.slug))
+        .collect();
+    let mut available: Vec<&Region> = allowed
+        .iter()
+        .copied()
+        .filter(|region| region.available)
+        .collect();
+    if available.is_empty() {
+        available = allowed;
+    }
+    available
+        .into_iter()
+        .map(|region| PickerItem {
+            label: if region.available {
+                format!("{} ({})", region.slug, region.name)
+            } else {
+                format!("{} ({}, unavailable)", region.slug, region.name)
+            },
+            value: region.slug.clone(),
+            meta: Some(region.name.clone()),
+            header: false,
+        })
+        .collect()
+}
+
+fn size_picker_items(sizes: &[Size], allowed: &[String]) -> Vec<PickerItem> {
+    sizes
+        .iter()
+        .filter(|size| is_allowed(allowed, &size.slug))
+        .map(|size| PickerItem {
+            label: format!(
+                "{} ({}MB, {} vCPU, {}GB)",
+                size.slug, size.memory_mb, size.vcpus, size.disk_gb
+            ),
+            value: size.slug.clone(),
+            meta: Some(format!("${:.2}/mo", size.price_monthly)),
+            header: false,
+        })
+        .collect()
+}
+
+/// Images sorted by distribution then name, each distribution under a heading row.
+fn image_picker_items(images: &[Image]) -> Vec<PickerItem> {
+    let mut sorted: Vec<&Image> = images.iter().collect();
+    sorted.sort_by(|a, b| {
+        let group = |image: &Image| (image.distribution.is_none(), image.distribution.clone());
+        group(a).cmp(&group(b)).then_with(|| a.name.cmp(&b.name))
+    });
+    let mut items = Vec::new();
+    let mut current_group = None;
+    for image in sorted {
+        let group = image.distribution.as_deref().unwrap_or("Other");
+        if current_group != Some(group) {
+            current_group = Some(group);
+            items.push(PickerItem {
+                label: group.to_string(),
+                value: String::new(),
+                meta: None,
+                header: true,
+            });
+        }
+        items.push(PickerItem {
+            label: format!(
+                "{}{}",
+                image.name,
+                image
+                    .slug
+                    .as_ref()
+                    .map(|slug| format!(" ({slug})"))
+                    .unwrap_or_default()
+            ),
+            value: image.slug.clone().unwrap_or_else(|| image.id.to_string()),
+            meta: image.distribution.clone(),
+            header: false,
+        });
+    }
+    items
+}
+
+fn source_region<'a>(snapshot: &Snapshot, regions: &'a [Region]) -> Option<&'a Region> {
+    snapshot.regions.iter().find_map(|slug| {
+        regions
+            .iter()
+            .find(|region| region.available && &region.slug == slug)
+    })
+}
+
+fn latest_snapshot_of(snapshots: &[Snapshot], droplet_id: u64) -> Option<&Snapshot> {
+    snapshots
+        .iter()
+        .filter(|snapshot| snapshot.resource_id == droplet_id)
+        .max_by(|a, b| a.created_at.cmp(&b.created_at))
+}
+
+fn smallest_compatible_size(min_disk_gb: u64, sizes: &[Size]) -> Option<&Size> {
+    sizes
+        .iter()
+        .filter(|size| size.disk_gb >= min_disk_gb)
+        .min_by(|a, b| {
+            a.disk_gb
+                .cmp(&b.disk_gb)
+                .then(a.price_monthly.total_cmp(&b.price_monthly))
+        })
+}
+
+/// Snapshots bill for the data actually stored, which is at most the droplet's disk.
+fn snapshot_cost_note(disk_gb: Option<u64>) -> String {
+    match disk_gb {
+        Some(disk_gb) => format!(
+            "The snapshot will be up to {disk_gb} GB and is billed for storage every month it is kept (up to ${:.2}/mo at ${SNAPSHOT_PRICE_PER_GB_MONTH:.2}/GB).",
+            disk_gb as f64 * SNAPSHOT_PRICE_PER_GB_MONTH
+        ),
+        None => format!(
+            "The snapshot is billed for storage every month it is kept (${SNAPSHOT_PRICE_PER_GB_MONTH:.2}/GB)."
+        ),
+    }
+}
+
 fn pending_label_for_task(task: &Task) -> &'static str {
     match task {
         Task::CheckDoctl => "Checking doctl authentication",
+        Task::CheckTools => "Checking installed tools",
         Task::RefreshDroplets => "Refreshing droplets",
+        Task::RefreshDroplet { .. } => "Refreshing droplet",
         Task::LoadSnapshots | Task::LoadSnapshotsDelayed { .. } => "Loading snapshots",
         Task::LoadRegions => "Loading regions",
         Task::LoadSizes => "Loading sizes",
         Task::LoadImages => "Loading images",
         Task::LoadSshKeys => "Loading SSH keys",
+        Task::LoadDomainRecords => "Loading DNS records",
+        Task::LoadContexts => "Loading doctl contexts",
         Task::CreateDroplet(_) => "Creating droplet",
+        Task::WaitForNetwork { .. } => "Waiting for network…",
         Task::RestoreDroplet(_) => "Restoring droplet",
         Task::SnapshotDelete { .. } => "Snapshotting and deleting droplet",
         Task::DeleteDroplet { .. } => "Deleting droplet",
         Task::StartTunnel(_) => "Starting SSH port tunnel",
+        Task::CheckBindingHealth(_) => "Checking tunnel ports",
+        Task::TestSshConnection(_) => "Testing SSH login",
+        Task::ReconnectTunnels { .. } => "Reconnecting tunnels",
+        Task::ActivateWorkspace { .. } => "Activating workspace",
+        Task::DeactivateWorkspace { .. } => "Deactivating workspace",
         Task::StopTunnel { .. } => "Stopping SSH port tunnel",
+        Task::PlanSyncs { .. } => "Planning Mutagen syncs",
         Task::CreateSyncs { .. } => "Creating Mutagen syncs",
+        Task::RollbackSyncs { .. } => "Rolling back Mutagen syncs",
         Task::RestoreSyncs { .. } => "Restoring Mutagen syncs",
         Task::LoadSyncs => "Loading Mutagen syncs",
+        Task::DeleteSync { flush: true, .. } => "Flushing and deleting Mutagen sync",
         Task::DeleteSync { .. } => "Deleting Mutagen sync",
         Task::CreateRsyncBind { .. } => "Creating RSYNC bind",
+        Task::CheckRsyncRemote { .. } => "Checking remote folder",
+        Task::MeasureLocalCopy { .. } => "Measuring local folder",
+        Task::RunRsyncGroup { .. } => "Running RSYNC group",
         Task::RunRsync { direction, .. } => match direction {
             RsyncDirection::Up => "Pushing files with rsync",
             RsyncDirection::Down => "Pulling files with rsync",
@@ -2627,46 +6401,233 @@ fn pending_label_for_task(task: &Task) -> &'static str {
         Task::ListRemoteDirectories { .. } => "Listing remote directories",
         Task::DeleteDropletSyncs { .. } => "Removing droplet Mutagen bindings",
         Task::TerminateAllSyncs => "Terminating all Mutagen syncs",
+        Task::ReconcileSyncs { .. } => "Reconciling mountlist with Mutagen",
+        Task::PruneMountEntries { .. } => "Pruning stale mountlist entries",
+    }
+}
+
+/// Tasks whose worker honors the cancel token; keep in sync with `is_cancellable_result`.
+fn is_cancellable_task(task: &Task) -> bool {
+    matches!(
+        task,
+        Task::CreateDroplet(_)
+            | Task::RestoreDroplet(_)
+            | Task::WaitForNetwork { .. }
+            | Task::SnapshotDelete { .. }
+            | Task::RunRsync { .. }
+            | Task::RunRsyncGroup { .. }
+    )
+}
+
+/// Tasks nothing waits on, which get the header spinner instead of the busy overlay: follow-up
+/// reloads, probes for the details pane and bind actions, and background creates. Keep in
+/// sync with `is_background_result`.
+fn is_background_task(task: &Task) -> bool {
+    matches!(
+        task,
+        Task::LoadSnapshotsDelayed { .. }
+            | Task::LoadDomainRecords
+            | Task::CheckRsyncRemote { .. }
+            | Task::WaitForNetwork {
+                background: true,
+                ..
+            }
+    )
+}
+
+fn is_background_result(result: &TaskResult) -> bool {
+    matches!(
+        result,
+        TaskResult::Snapshots {
+            background: true,
+            ..
+        } | TaskResult::DomainRecords(_)
+            | TaskResult::RsyncRemote { .. }
+            | TaskResult::DropletNetwork {
+                background: true,
+                ..
+            }
+    )
+}
+
+fn is_cancellable_result(result: &TaskResult) -> bool {
+    matches!(
+        result,
+        TaskResult::CreateDroplet { .. }
+            | TaskResult::RestoreDroplet { .. }
+            | TaskResult::DropletNetwork { .. }
+            | TaskResult::SnapshotDelete(_)
+            | TaskResult::RunRsync { .. }
+            | TaskResult::RsyncGroup { .. }
+    )
+}
+
+/// The 429 behind a failed doctl task, if that is why it failed.
+fn rate_limit_of(result: &TaskResult) -> Option<doctl::RateLimited> {
+    task_error(result)?
+        .downcast_ref::<doctl::RateLimited>()
+        .copied()
+}
+
+fn push_error_log(log: &mut VecDeque<ErrorLogEntry>, entry: ErrorLogEntry) {
+    if log.len() == ERROR_LOG_CAPACITY {
+        log.pop_front();
+    }
+    log.push_back(entry);
+}
+
+/// The errors view's text: newest first, each a local timestamp and task line followed by
+/// the untruncated message.
+fn error_log_message(log: &VecDeque<ErrorLogEntry>) -> String {
+    log.iter()
+        .rev()
+        .map(|entry| {
+            format!(
+                "{} {} failed\n{}",
+                entry.at.with_timezone(&chrono::Local).format("%H:%M:%S"),
+                entry.task,
+                entry.message
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// The error a failed task carried, if it failed outright.
+fn task_error(result: &TaskResult) -> Option<&anyhow::Error> {
+    match result {
+        TaskResult::DoctlCheck(Err(err))
+        | TaskResult::Droplets(Err(err))
+        | TaskResult::Droplet(Err(err))
+        | TaskResult::Snapshots {
+            result: Err(err), ..
+        }
+        | TaskResult::Regions(Err(err))
+        | TaskResult::Sizes(Err(err))
+        | TaskResult::Images(Err(err))
+        | TaskResult::SshKeys(Err(err))
+        | TaskResult::DomainRecords(Err(err))
+        | TaskResult::Contexts(Err(err))
+        | TaskResult::SnapshotDelete(Err(err))
+        | TaskResult::StartTunnel(Err(err))
+        | TaskResult::StopTunnel(Err(err))
+        | TaskResult::RestoreSyncs(Err(err))
+        | TaskResult::Syncs(Err(err))
+        | TaskResult::DeleteSync(Err(err))
+        | TaskResult::CreateRsyncBind(Err(err))
+        | TaskResult::DeleteRsyncBind(Err(err))
+        | TaskResult::DeleteDropletSyncs(Err(err))
+        | TaskResult::TerminateAllSyncs(Err(err))
+        | TaskResult::PruneMountEntries(Err(err)) => Some(err),
+        TaskResult::CreateDroplet {
+            result: Err(err), ..
+        }
+        | TaskResult::RestoreDroplet {
+            result: Err(err), ..
+        }
+        | TaskResult::DeleteDroplet {
+            result: Err(err), ..
+        }
+        | TaskResult::DropletNetwork {
+            result: Err(err), ..
+        }
+        | TaskResult::SshConnection {
+            result: Err(err), ..
+        }
+        | TaskResult::PlanSyncs {
+            result: Err(err), ..
+        }
+        | TaskResult::CreateSyncs {
+            result: Err(err), ..
+        }
+        | TaskResult::RunRsync {
+            result: Err(err), ..
+        }
+        | TaskResult::RemoteDirectories {
+            result: Err(err), ..
+        }
+        | TaskResult::ReconcileSyncs {
+            result: Err(err), ..
+        } => Some(err),
+        _ => None,
     }
 }
 
 fn pending_label_for_result(result: &TaskResult) -> &'static str {
     match result {
         TaskResult::DoctlCheck(_) => "Checking doctl authentication",
+        TaskResult::Tools(_) => "Checking installed tools",
         TaskResult::Droplets(_) => "Refreshing droplets",
-        TaskResult::Snapshots(_) => "Loading snapshots",
+        TaskResult::Droplet(_) => "Refreshing droplet",
+        TaskResult::Snapshots { .. } => "Loading snapshots",
         TaskResult::Regions(_) => "Loading regions",
         TaskResult::Sizes(_) => "Loading sizes",
         TaskResult::Images(_) => "Loading images",
         TaskResult::SshKeys(_) => "Loading SSH keys",
-        TaskResult::CreateDroplet(_) => "Creating droplet",
-        TaskResult::RestoreDroplet(_) => "Restoring droplet",
+        TaskResult::DomainRecords(_) => "Loading DNS records",
+        TaskResult::Contexts(_) => "Loading doctl contexts",
+        TaskResult::CreateDroplet { .. } => "Creating droplet",
+        TaskResult::DropletNetwork { .. } => "Waiting for network…",
+        TaskResult::RestoreDroplet { .. } => "Restoring droplet",
         TaskResult::SnapshotDelete(_) => "Snapshotting and deleting droplet",
-        TaskResult::DeleteDroplet(_) => "Deleting droplet",
+        TaskResult::ActionProgress { .. } => "Polling droplet action",
+        TaskResult::SystemResumed => "Resuming from sleep",
+        TaskResult::DeleteDroplet { .. } => "Deleting droplet",
         TaskResult::StartTunnel(_) => "Starting SSH port tunnel",
+        TaskResult::BindingHealth(_) => "Checking tunnel ports",
+        TaskResult::SshConnection { .. } => "Testing SSH login",
+        TaskResult::ReconnectTunnels { .. } => "Reconnecting tunnels",
+        TaskResult::ActivateWorkspace { .. } => "Activating workspace",
+        TaskResult::DeactivateWorkspace { .. } => "Deactivating workspace",
         TaskResult::StopTunnel(_) => "Stopping SSH port tunnel",
-        TaskResult::CreateSyncs(_) => "Creating Mutagen syncs",
+        TaskResult::PlanSyncs { .. } => "Planning Mutagen syncs",
+        TaskResult::CreateSyncs { .. } => "Creating Mutagen syncs",
+        TaskResult::RollbackSyncs(_) => "Rolling back Mutagen syncs",
         TaskResult::RestoreSyncs(_) => "Restoring Mutagen syncs",
         TaskResult::Syncs(_) => "Loading Mutagen syncs",
         TaskResult::DeleteSync(_) => "Deleting Mutagen sync",
         TaskResult::CreateRsyncBind(_) => "Creating RSYNC bind",
-        TaskResult::RunRsync(res) => match res {
-            Ok(outcome) => match outcome.direction {
-                RsyncDirection::Up => "Pushing files with rsync",
-                RsyncDirection::Down => "Pulling files with rsync",
-            },
-            Err(_) => "Running rsync",
+        TaskResult::RsyncRemote { .. } => "Checking remote folder",
+        TaskResult::LocalCopySize { .. } => "Measuring local folder",
+        TaskResult::RsyncGroup { .. } => "Running RSYNC group",
+        TaskResult::RunRsync { direction, .. } => match direction {
+            RsyncDirection::Up => "Pushing files with rsync",
+            RsyncDirection::Down => "Pulling files with rsync",
         },
         TaskResult::DeleteRsyncBind(_) => "Deleting RSYNC bind",
         TaskResult::RemoteDirectories { .. } => "Listing remote directories",
         TaskResult::DeleteDropletSyncs(_) => "Removing droplet Mutagen bindings",
         TaskResult::TerminateAllSyncs(_) => "Terminating all Mutagen syncs",
+        TaskResult::ReconcileSyncs { .. } => "Reconciling mountlist with Mutagen",
+        TaskResult::PruneMountEntries(_) => "Pruning stale mountlist entries",
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{join_remote_path, remote_parent_path, split_csv};
+    use std::collections::VecDeque;
+
+    use super::{
+        CreateForm, DropletDetail, ERROR_LOG_CAPACITY, ErrorLogEntry, Picker, PickerItem,
+        PickerTarget, RemoteBrowserView, Screen, Selection, binding_matches,
+        build_remote_browser_entries, config_location_message, create_summary,
+        default_bind_interface, doctl_connect_args, droplet_for_host, duplicate_name,
+        error_log_message, format_bytes, format_cost, image_picker_items, is_background_result,
+        is_background_task, is_valid_droplet_name, join_remote_path, latest_snapshot_of,
+        next_template_name, normalize_label, palette_actions, palette_key, parse_sync_paths,
+        push_error_log, region_picker_items, remote_parent_path, resolve_rsync_host,
+        rsync_group_members, rsync_result_label, sanitize_name, size_picker_items,
+        smallest_compatible_size, snapshot_cost_note, sort_droplets, source_region, split_csv,
+        ssh_connect_args, ssh_key_summary, validate_remote_path, with_remote_sync_path,
+    };
+    use crate::doctl::CreateDropletArgs;
+    use crate::input::TextInput;
+    use crate::model::{
+        BindInterface, Droplet, Image, PortBinding, Region, RsyncBind, Size, Snapshot, SshKey,
+    };
+    use crate::mutagen::SshConfig;
+    use crate::tasks::{RsyncDirection, Task, TaskResult};
+    use chrono::{TimeZone, Utc};
 
     #[test]
     fn split_csv_trims_and_filters() {
@@ -2692,4 +6653,799 @@ mod tests {
         assert_eq!(join_remote_path("/", "etc"), "/etc");
         assert_eq!(join_remote_path("/root", "work"), "/root/work");
     }
+
+    #[test]
+    fn remote_browser_entries_apply_view_options() {
+        let dirs: Vec<String> = ["b", ".cache", "Zeta", "alpha", "Beta"]
+            .iter()
+            .map(|dir| dir.to_string())
+            .collect();
+        let labels = |view: &RemoteBrowserView| -> Vec<String> {
+            build_remote_browser_entries("/root", &dirs, view)
+                .into_iter()
+                .map(|entry| entry.label)
+                .collect()
+        };
+        let mut view = RemoteBrowserView {
+            filter: "",
+            show_hidden: false,
+            case_insensitive: false,
+        };
+        assert_eq!(labels(&view), ["../", "b/", "Zeta/", "alpha/", "Beta/"]);
+        view.case_insensitive = true;
+        assert_eq!(labels(&view), ["../", "alpha/", "b/", "Beta/", "Zeta/"]);
+        view.show_hidden = true;
+        assert_eq!(labels(&view)[1], ".cache/");
+        view.filter = "ET";
+        assert_eq!(labels(&view), ["../", "Beta/", "Zeta/"]);
+        let root = build_remote_browser_entries("/", &dirs, &view);
+        assert_eq!(root[0].path, "/Beta");
+    }
+
+    #[test]
+    fn delayed_snapshot_reload_runs_in_the_background() {
+        assert!(is_background_task(&Task::LoadSnapshotsDelayed {
+            delay_ms: 4000
+        }));
+        assert!(!is_background_task(&Task::LoadSnapshots));
+        assert!(is_background_result(&TaskResult::Snapshots {
+            background: true,
+            result: Ok(Vec::new()),
+        }));
+        assert!(!is_background_result(&TaskResult::Snapshots {
+            background: false,
+            result: Ok(Vec::new()),
+        }));
+        assert!(is_background_task(&Task::LoadDomainRecords));
+        assert!(is_background_result(&TaskResult::DomainRecords(Ok(
+            Vec::new()
+        ))));
+        assert!(!is_background_task(&Task::RefreshDroplets));
+    }
+
+    #[test]
+    fn error_log_keeps_the_newest_entries_first() {
+        let mut log = VecDeque::new();
+        for idx in 0..ERROR_LOG_CAPACITY + 2 {
+            push_error_log(
+                &mut log,
+                ErrorLogEntry {
+                    at: Utc::now(),
+                    task: "Loading droplets",
+                    message: format!("error {idx}: doctl exited 1\nfull detail"),
+                },
+            );
+        }
+        assert_eq!(log.len(), ERROR_LOG_CAPACITY);
+        assert!(log.front().unwrap().message.starts_with("error 2:"));
+
+        let message = error_log_message(&log);
+        let newest = format!("error {}:", ERROR_LOG_CAPACITY + 1);
+        assert!(message.find(&newest).unwrap() < message.find("error 2:").unwrap());
+        assert!(message.contains("Loading droplets failed\nerror 2: doctl exited 1\nfull detail"));
+        assert!(!message.contains("error 1:"));
+    }
+
+    #[test]
+    fn format_bytes_picks_a_readable_unit() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MB");
+        assert_eq!(format_bytes(3 * 1024 * 1024 * 1024 / 2), "1.5 GB");
+    }
+
+    #[test]
+    fn sanitize_name_produces_valid_hostnames() {
+        assert_eq!(sanitize_name("Web-01"), "web-01");
+        assert_eq!(sanitize_name("my_App__server"), "my-app-server");
+        assert_eq!(sanitize_name(" -api. v2_ "), "api-v2");
+        assert_eq!(sanitize_name("__"), "droplet");
+        let long = format!("{}-tail", "a".repeat(62));
+        let name = sanitize_name(&long);
+        assert_eq!(name, "a".repeat(62));
+        assert!(is_valid_droplet_name(&name));
+        assert_eq!(sanitize_name(&"b".repeat(80)).len(), 63);
+        assert!(!is_valid_droplet_name("Web-01"));
+        assert!(!is_valid_droplet_name("web.example"));
+        assert!(!is_valid_droplet_name(&"c".repeat(64)));
+    }
+
+    #[test]
+    fn duplicate_name_suffixes_the_next_free_counter() {
+        let existing = ["web", "web-2", "api-07", "api-09", "db"];
+        assert_eq!(duplicate_name("web", &existing).as_deref(), Some("web-3"));
+        assert_eq!(duplicate_name("db", &existing).as_deref(), Some("db-2"));
+        assert_eq!(
+            duplicate_name("api-07", &existing).as_deref(),
+            Some("api-10")
+        );
+    }
+
+    #[test]
+    fn next_template_name_uses_highest_counter() {
+        let existing = ["web-01", "web-07", "web-x", "db-09"];
+        assert_eq!(
+            next_template_name("web-{n:02}", &existing).as_deref(),
+            Some("web-08")
+        );
+        assert_eq!(
+            next_template_name("api-{n}", &existing).as_deref(),
+            Some("api-1")
+        );
+        assert_eq!(
+            next_template_name("WEB-{n:02}", &existing).as_deref(),
+            Some("web-08")
+        );
+    }
+
+    #[test]
+    fn binding_matches_name_ports_and_ip() {
+        let binding = PortBinding {
+            droplet_id: 1,
+            droplet_name: "Web-01".to_string(),
+            public_ip: "203.0.113.7".to_string(),
+            local_port: 8080,
+            remote_port: 80,
+            ssh_user: "root".to_string(),
+            ssh_key_path: String::new(),
+            use_agent: false,
+            ssh_port: 22,
+            created_at: chrono::Utc::now(),
+            tunnel_pid: None,
+            interface: Default::default(),
+            bind_address: "127.0.0.1".to_string(),
+            label: None,
+        };
+        assert!(binding_matches(&binding, ""));
+        assert!(binding_matches(&binding, "web"));
+        assert!(binding_matches(&binding, "8080"));
+        assert!(binding_matches(&binding, "113.7"));
+        assert!(!binding_matches(&binding, "db"));
+
+        let labeled = PortBinding {
+            label: normalize_label("  Admin Panel "),
+            ..binding
+        };
+        assert_eq!(labeled.label.as_deref(), Some("Admin Panel"));
+        assert!(binding_matches(&labeled, "admin"));
+        assert_eq!(normalize_label("   "), None);
+    }
+
+    fn droplet_created(created_at: Option<&str>) -> Droplet {
+        Droplet {
+            id: 1,
+            name: "web".to_string(),
+            status: "active".to_string(),
+            region: "nyc1".to_string(),
+            size: Some("s-2vcpu-4gb".to_string()),
+            created_at: created_at.map(str::to_string),
+            features: Some(Vec::new()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn droplet_detail_formats_age_and_handles_bad_timestamps() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 10, 12, 0, 0).unwrap();
+
+        let detail = DropletDetail::new(
+            &droplet_created(Some("2024-03-05T09:30:00Z")),
+            &[],
+            &[],
+            now,
+        );
+        assert_eq!(detail.created.as_deref(), Some("2024-03-05 09:30 UTC"));
+        assert_eq!(detail.age.as_deref(), Some("5d ago"));
+        assert_eq!(detail.backups_enabled, Some(false));
+
+        let detail = DropletDetail::new(
+            &droplet_created(Some("2024-03-10T09:00:00Z")),
+            &[],
+            &[],
+            now,
+        );
+        assert_eq!(detail.age.as_deref(), Some("3h ago"));
+
+        let detail = DropletDetail::new(&droplet_created(Some("yesterday-ish")), &[], &[], now);
+        assert_eq!(detail.created.as_deref(), Some("yesterday-ish"));
+        assert_eq!(detail.age, None);
+
+        let detail = DropletDetail::new(&droplet_created(None), &[], &[], now);
+        assert_eq!(detail.created, None);
+        assert_eq!(detail.age, None);
+    }
+
+    #[test]
+    fn droplet_detail_enriches_region_and_size_when_loaded() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 10, 12, 0, 0).unwrap();
+        let droplet = droplet_created(None);
+
+        let detail = DropletDetail::new(&droplet, &[], &[], now);
+        assert_eq!(detail.region, "nyc1");
+        assert_eq!(detail.size.as_deref(), Some("s-2vcpu-4gb"));
+
+        let regions = vec![Region {
+            slug: "nyc1".to_string(),
+            name: "New York 1".to_string(),
+            available: true,
+        }];
+        let sizes = vec![Size {
+            slug: "s-2vcpu-4gb".to_string(),
+            memory_mb: 4096,
+            vcpus: 2,
+            disk_gb: 80,
+            price_monthly: 24.0,
+        }];
+        let detail = DropletDetail::new(&droplet, &regions, &sizes, now);
+        assert_eq!(detail.region, "New York 1 (nyc1)");
+        assert_eq!(
+            detail.size.as_deref(),
+            Some("s-2vcpu-4gb (4GB, 2 vCPU, 80GB disk, $24.00/mo)")
+        );
+    }
+
+    #[test]
+    fn droplet_detail_uses_the_droplets_own_specs_and_features() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 10, 12, 0, 0).unwrap();
+        let mut droplet = droplet_created(None);
+        assert_eq!(DropletDetail::new(&droplet, &[], &[], now).features, None);
+
+        droplet.vcpus = Some(2);
+        droplet.memory_mb = Some(4096);
+        droplet.disk_gb = Some(80);
+        droplet.features = Some(vec!["monitoring".to_string(), "ipv6".to_string()]);
+        let detail = DropletDetail::new(&droplet, &[], &[], now);
+        assert_eq!(
+            detail.size.as_deref(),
+            Some("s-2vcpu-4gb (4GB, 2 vCPU, 80GB disk)")
+        );
+        assert_eq!(detail.features.as_deref(), Some("monitoring, ipv6"));
+        assert_eq!(detail.backups_enabled, Some(false));
+    }
+
+    #[test]
+    fn ssh_connect_args_leave_out_the_key_with_an_agent() {
+        let mut ssh = SshConfig {
+            user: "root".to_string(),
+            host: "203.0.113.7".to_string(),
+            port: 2222,
+            key_path: "/tmp/id_rsa".to_string(),
+            use_agent: false,
+        };
+        assert_eq!(
+            ssh_connect_args(&ssh, None),
+            ["-i", "/tmp/id_rsa", "-p", "2222", "root@203.0.113.7"]
+        );
+        ssh.use_agent = true;
+        assert_eq!(
+            ssh_connect_args(&ssh, None),
+            ["-p", "2222", "root@203.0.113.7"]
+        );
+    }
+
+    #[test]
+    fn connect_args_pass_a_command_through() {
+        let ssh = SshConfig {
+            user: "root".to_string(),
+            host: "203.0.113.7".to_string(),
+            port: 22,
+            key_path: String::new(),
+            use_agent: true,
+        };
+        assert_eq!(
+            ssh_connect_args(&ssh, Some("htop")),
+            ["-p", "22", "-t", "root@203.0.113.7", "htop"]
+        );
+        assert_eq!(doctl_connect_args(42, None), ["compute", "ssh", "42"]);
+        assert_eq!(
+            doctl_connect_args(42, Some("df -h")),
+            ["compute", "ssh", "42", "--ssh-command", "df -h"]
+        );
+    }
+
+    #[test]
+    fn rsync_group_members_keep_registry_order() {
+        let bind = |remote: &str, group: Option<&str>| RsyncBind {
+            droplet_name: "web".to_string(),
+            droplet_id: None,
+            ssh_user: "root".to_string(),
+            host: "203.0.113.7".to_string(),
+            ssh_port: 22,
+            ssh_key_path: String::new(),
+            use_agent: false,
+            remote_path: remote.to_string(),
+            local_path: format!("/tmp{remote}"),
+            created_at: chrono::Utc::now(),
+            last_synced_at: None,
+            last_result: None,
+            rsync_profile: Default::default(),
+            compress: true,
+            checksum: false,
+            group: group.map(str::to_string),
+        };
+        let binds = vec![
+            bind("/srv/api", Some("app")),
+            bind("/srv/docs", None),
+            bind("/srv/web", Some("app")),
+            bind("/srv/db", Some("ops")),
+        ];
+        let members: Vec<String> = rsync_group_members(&binds, "app")
+            .into_iter()
+            .map(|bind| bind.remote_path)
+            .collect();
+        assert_eq!(members, vec!["/srv/api", "/srv/web"]);
+        assert!(rsync_group_members(&binds, "none").is_empty());
+    }
+
+    #[test]
+    fn validate_remote_path_requires_absolute_or_home_paths() {
+        assert_eq!(
+            validate_remote_path(" /srv/app/ "),
+            Ok("/srv/app".to_string())
+        );
+        assert_eq!(validate_remote_path("~/site"), Ok("~/site".to_string()));
+        assert_eq!(validate_remote_path("/"), Ok("/".to_string()));
+        assert!(validate_remote_path("").is_err());
+        assert!(validate_remote_path("srv/app").is_err());
+    }
+
+    #[test]
+    fn rsync_result_label_keeps_first_error_line() {
+        assert_eq!(
+            rsync_result_label(RsyncDirection::Up, Ok("3 files transferred (1.23K)")),
+            "pushed: 3 files transferred (1.23K)"
+        );
+        assert_eq!(
+            rsync_result_label(
+                RsyncDirection::Down,
+                Err("rsync failed (Some(23)).\nstdout:")
+            ),
+            "pull failed: rsync failed (Some(23))."
+        );
+    }
+
+    #[test]
+    fn source_specs_pick_available_region_and_smallest_disk() {
+        let snapshot = Snapshot {
+            id: 7,
+            name: "web-snap".to_string(),
+            created_at: String::new(),
+            regions: vec!["ams3".to_string(), "nyc1".to_string()],
+            resource_id: 1,
+            min_disk_size: 50,
+            size_gigabytes: 12.0,
+        };
+        let region = |slug: &str, available: bool| Region {
+            slug: slug.to_string(),
+            name: slug.to_uppercase(),
+            available,
+        };
+        let regions = vec![
+            region("ams3", false),
+            region("nyc1", true),
+            region("sfo3", true),
+        ];
+        assert_eq!(
+            source_region(&snapshot, &regions).map(|r| r.slug.as_str()),
+            Some("nyc1")
+        );
+        assert!(source_region(&snapshot, &[region("sfo3", true)]).is_none());
+
+        let size = |slug: &str, disk_gb: u64, price_monthly: f64| Size {
+            slug: slug.to_string(),
+            memory_mb: 1024,
+            vcpus: 1,
+            disk_gb,
+            price_monthly,
+        };
+        let sizes = vec![
+            size("s-1vcpu-1gb", 25, 6.0),
+            size("s-2vcpu-4gb", 80, 24.0),
+            size("c-2", 50, 42.0),
+            size("s-2vcpu-2gb", 60, 18.0),
+            size("s-1vcpu-2gb", 50, 12.0),
+        ];
+        assert_eq!(
+            smallest_compatible_size(50, &sizes).map(|s| s.slug.as_str()),
+            Some("s-1vcpu-2gb")
+        );
+        assert!(smallest_compatible_size(500, &sizes).is_none());
+    }
+
+    #[test]
+    fn latest_snapshot_of_picks_newest_for_droplet() {
+        let snapshot = |id: u64, resource_id: u64, created_at: &str| Snapshot {
+            id,
+            name: format!("snap-{id}"),
+            created_at: created_at.to_string(),
+            regions: Vec::new(),
+            resource_id,
+            min_disk_size: 25,
+            size_gigabytes: 1.0,
+        };
+        let snapshots = vec![
+            snapshot(1, 10, "2024-01-01T00:00:00Z"),
+            snapshot(2, 10, "2024-03-01T00:00:00Z"),
+            snapshot(3, 20, "2024-05-01T00:00:00Z"),
+        ];
+        assert_eq!(latest_snapshot_of(&snapshots, 10).map(|s| s.id), Some(2));
+        assert!(latest_snapshot_of(&snapshots, 30).is_none());
+    }
+
+    #[test]
+    fn picker_items_honor_allow_lists() {
+        let region = |slug: &str, available: bool| Region {
+            slug: slug.to_string(),
+            name: slug.to_uppercase(),
+            available,
+        };
+        let regions = vec![
+            region("nyc1", true),
+            region("nyc3", true),
+            region("sfo3", false),
+        ];
+        let slugs =
+            |items: Vec<PickerItem>| items.into_iter().map(|item| item.value).collect::<Vec<_>>();
+        assert_eq!(
+            slugs(region_picker_items(&regions, &[])),
+            vec!["nyc1", "nyc3"]
+        );
+        assert_eq!(
+            slugs(region_picker_items(&regions, &["NYC3".to_string()])),
+            vec!["nyc3"]
+        );
+        // An allowed region that's unavailable is still offered rather than nothing.
+        assert_eq!(
+            slugs(region_picker_items(&regions, &["sfo3".to_string()])),
+            vec!["sfo3"]
+        );
+
+        let size = |slug: &str| Size {
+            slug: slug.to_string(),
+            memory_mb: 1024,
+            vcpus: 1,
+            disk_gb: 25,
+            price_monthly: 6.0,
+        };
+        let sizes = vec![size("s-1vcpu-1gb"), size("s-2vcpu-4gb")];
+        assert_eq!(slugs(size_picker_items(&sizes, &[])).len(), 2);
+        assert_eq!(
+            slugs(size_picker_items(&sizes, &["s-2vcpu-4gb".to_string()])),
+            vec!["s-2vcpu-4gb"]
+        );
+        assert!(size_picker_items(&sizes, &["c-2".to_string()]).is_empty());
+    }
+
+    #[test]
+    fn image_picker_groups_by_distribution() {
+        let image = |id: u64, name: &str, distribution: Option<&str>| Image {
+            id,
+            name: name.to_string(),
+            slug: None,
+            distribution: distribution.map(str::to_string),
+        };
+        let images = vec![
+            image(1, "24.04 (LTS) x64", Some("Ubuntu")),
+            image(2, "12 x64", Some("Debian")),
+            image(3, "custom", None),
+            image(4, "22.04 (LTS) x64", Some("Ubuntu")),
+        ];
+        let items = image_picker_items(&images);
+        let labels: Vec<(&str, bool)> = items
+            .iter()
+            .map(|item| (item.label.as_str(), item.header))
+            .collect();
+        assert_eq!(
+            labels,
+            vec![
+                ("Debian", true),
+                ("12 x64", false),
+                ("Ubuntu", true),
+                ("22.04 (LTS) x64", false),
+                ("24.04 (LTS) x64", false),
+                ("Other", true),
+                ("custom", false),
+            ]
+        );
+
+        let mut picker = Picker::new(
+            "Select Image".to_string(),
+            items,
+            PickerTarget::CreateImage,
+            false,
+        );
+        assert_eq!(picker.selected_index(), Some(1));
+        picker.query = TextInput::new("lts");
+        picker.refresh_filter();
+        assert_eq!(picker.filtered, vec![2, 3, 4]);
+        assert_eq!(picker.selected_index(), Some(3));
+        picker.move_selection(-1);
+        assert_eq!(picker.selected_index(), Some(3));
+    }
+
+    #[test]
+    fn sort_droplets_puts_favorites_first() {
+        let droplet = |id: u64, name: &str| Droplet {
+            id,
+            name: name.to_string(),
+            status: "active".to_string(),
+            region: "nyc1".to_string(),
+            ..Default::default()
+        };
+        let mut droplets = vec![
+            droplet(1, "alpha"),
+            droplet(2, "zulu"),
+            droplet(3, "bravo"),
+            droplet(4, "mike"),
+        ];
+        sort_droplets(&mut droplets, &[4, 2].into_iter().collect());
+        let names: Vec<&str> = droplets.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["mike", "zulu", "alpha", "bravo"]);
+    }
+
+    #[test]
+    fn create_summary_lists_the_resolved_spec() {
+        let selection = |label: &str, value: &str| Selection {
+            label: label.to_string(),
+            value: value.to_string(),
+        };
+        let form = CreateForm {
+            name: TextInput::new("web-1"),
+            region: Some(selection("nyc3 (New York 3)", "nyc3")),
+            size: Some(selection("s-1vcpu-1gb", "s-1vcpu-1gb")),
+            image: Some(selection(
+                "Ubuntu 24.04 (ubuntu-24-04-x64)",
+                "ubuntu-24-04-x64",
+            )),
+            ssh_keys: vec![selection("laptop (aa:bb)", "11")],
+            tags: TextInput::new(""),
+            focus: 0,
+            background: true,
+        };
+        let args = CreateDropletArgs {
+            name: "web-1".to_string(),
+            region: Some("nyc3".to_string()),
+            size: "s-1vcpu-1gb".to_string(),
+            image: "ubuntu-24-04-x64".to_string(),
+            ssh_keys: vec!["11".to_string(), "12".to_string()],
+            tags: Vec::new(),
+            project_id: None,
+            wait: false,
+        };
+        let size = Size {
+            slug: "s-1vcpu-1gb".to_string(),
+            memory_mb: 1024,
+            vcpus: 1,
+            disk_gb: 25,
+            price_monthly: 6.0,
+        };
+        let keys = vec![SshKey {
+            id: 11,
+            name: "laptop".to_string(),
+            fingerprint: "aa:bb".to_string(),
+        }];
+        let summary = create_summary(&args, &form, Some(&size), &keys);
+        assert!(summary.contains("Region: nyc3 (New York 3)"));
+        assert!(summary.contains("Size: s-1vcpu-1gb (1GB, 1 vCPU, 25GB disk), $6.00/mo"));
+        assert!(summary.contains("Image: Ubuntu 24.04 (ubuntu-24-04-x64)"));
+        assert!(summary.contains("SSH keys: laptop, #12"));
+        assert!(summary.contains("Tags: none"));
+        assert!(summary.contains("Project: account default"));
+        assert!(summary.contains("Wait: no, provision in the background"));
+    }
+
+    #[test]
+    fn format_cost_derives_hourly_price() {
+        assert_eq!(format_cost(24.0), "$24.00/mo ($0.0357/hr)");
+        assert_eq!(format_cost(4.0), "$4.00/mo ($0.0060/hr)");
+    }
+
+    #[test]
+    fn snapshot_cost_note_estimates_from_disk() {
+        let note = snapshot_cost_note(Some(50));
+        assert!(note.contains("up to 50 GB"));
+        assert!(note.contains("$3.00/mo"));
+        assert!(!snapshot_cost_note(None).contains("GB and"));
+    }
+
+    #[test]
+    fn palette_actions_all_map_to_keys() {
+        for screen in [
+            Screen::Home,
+            Screen::Bindings,
+            Screen::Syncs,
+            Screen::RsyncBinds,
+        ] {
+            for (key, label) in palette_actions(screen) {
+                assert!(palette_key(key).is_some(), "{label} has no key");
+            }
+        }
+        assert!(palette_key("Ctrl+P").is_none());
+    }
+
+    #[test]
+    fn ssh_key_summary_lists_names_and_truncates() {
+        let keys = vec![
+            Selection {
+                label: "laptop (aa:bb)".to_string(),
+                value: "1".to_string(),
+            },
+            Selection {
+                label: "ci".to_string(),
+                value: "2".to_string(),
+            },
+        ];
+        assert_eq!(ssh_key_summary(&keys, 40), "2: laptop, ci");
+        assert_eq!(ssh_key_summary(&keys, 8), "2: lapt…");
+    }
+
+    #[test]
+    fn with_remote_sync_path_sets_last_item_remote() {
+        let updated = with_remote_sync_path(&TextInput::new("~/code"), "/srv/code");
+        assert_eq!(updated.value, "~/code->/srv/code");
+
+        let updated = with_remote_sync_path(&TextInput::new("~/a, ~/b->/old"), "/srv/b");
+        assert_eq!(updated.value, "~/a, ~/b->/srv/b");
+        let paths = parse_sync_paths(&updated.value).expect("paths");
+        assert_eq!(paths[1].remote, "/srv/b");
+
+        let updated = with_remote_sync_path(&TextInput::new("~/a,"), "/srv/x");
+        assert_eq!(updated.value, "~/a, ->/srv/x");
+        assert_eq!(updated.cursor, "~/a, ".len());
+
+        let updated = with_remote_sync_path(&TextInput::new(""), "/srv/x");
+        assert_eq!(updated.value, "->/srv/x");
+        assert_eq!(updated.cursor, 0);
+    }
+
+    #[test]
+    fn droplet_for_host_matches_any_address() {
+        let droplets = vec![Droplet {
+            id: 1,
+            name: "web".to_string(),
+            status: "active".to_string(),
+            region: "nyc1".to_string(),
+            public_ipv4: Some("203.0.113.10".to_string()),
+            public_ipv6: Some("2001:DB8::1".to_string()),
+            private_ipv4: Some("10.0.0.5".to_string()),
+            ..Default::default()
+        }];
+        assert_eq!(
+            droplet_for_host(&droplets, "203.0.113.10").map(|d| d.id),
+            Some(1)
+        );
+        assert!(droplet_for_host(&droplets, "[2001:db8::1]").is_some());
+        assert!(droplet_for_host(&droplets, "10.0.0.5").is_some());
+        assert!(droplet_for_host(&droplets, "198.51.100.1").is_none());
+    }
+
+    #[test]
+    fn resolve_rsync_host_follows_the_droplet_or_falls_back() {
+        let droplet = Droplet {
+            id: 7,
+            name: "web".to_string(),
+            status: "active".to_string(),
+            region: "nyc1".to_string(),
+            public_ipv4: Some("203.0.113.20".to_string()),
+            private_ipv4: Some("10.0.0.8".to_string()),
+            ..Default::default()
+        };
+        let bind = RsyncBind {
+            droplet_name: "web".to_string(),
+            droplet_id: Some(7),
+            ssh_user: "root".to_string(),
+            host: "203.0.113.10".to_string(),
+            ssh_port: 22,
+            ssh_key_path: String::new(),
+            use_agent: false,
+            remote_path: "/srv/app".to_string(),
+            local_path: "/tmp/app".to_string(),
+            created_at: chrono::Utc::now(),
+            last_synced_at: None,
+            last_result: None,
+            rsync_profile: Default::default(),
+            compress: true,
+            checksum: false,
+            group: None,
+        };
+        let droplets = std::slice::from_ref(&droplet);
+        assert_eq!(
+            resolve_rsync_host(&bind, droplets, BindInterface::Public),
+            "203.0.113.20"
+        );
+        assert_eq!(
+            resolve_rsync_host(&bind, droplets, BindInterface::Private),
+            "10.0.0.8"
+        );
+
+        let private = RsyncBind {
+            host: "10.0.0.8".to_string(),
+            ..bind.clone()
+        };
+        assert_eq!(
+            resolve_rsync_host(&private, droplets, BindInterface::Public),
+            "10.0.0.8"
+        );
+
+        assert_eq!(
+            resolve_rsync_host(&bind, &[], BindInterface::Public),
+            "203.0.113.10"
+        );
+        let untracked = RsyncBind {
+            droplet_id: None,
+            ..bind
+        };
+        assert_eq!(
+            resolve_rsync_host(&untracked, droplets, BindInterface::Public),
+            "203.0.113.10"
+        );
+    }
+
+    #[test]
+    fn config_location_message_shows_paths_and_open_result() {
+        let path = std::path::Path::new("/home/me/.config/doctl-tui/state.json");
+        let headless = config_location_message(path, None);
+        assert!(headless.contains("State file: /home/me/.config/doctl-tui/state.json"));
+        assert!(headless.contains("Config directory: /home/me/.config/doctl-tui\n"));
+        assert!(!headless.contains("Opened"));
+        let opened = config_location_message(path, Some(Ok("xdg-open")));
+        assert!(opened.ends_with("Opened the directory with xdg-open."));
+    }
+
+    #[test]
+    fn private_only_droplets_bind_over_the_private_ip() {
+        let mut droplet = Droplet {
+            id: 1,
+            name: "vpc-only".to_string(),
+            status: "active".to_string(),
+            region: "nyc1".to_string(),
+            private_ipv4: Some("10.0.0.5".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            default_bind_interface(&droplet, BindInterface::Public),
+            Some(BindInterface::Private)
+        );
+        droplet.public_ipv4 = Some("203.0.113.10".to_string());
+        assert_eq!(
+            default_bind_interface(&droplet, BindInterface::Public),
+            Some(BindInterface::Public)
+        );
+        assert_eq!(
+            default_bind_interface(&droplet, BindInterface::Private),
+            Some(BindInterface::Private)
+        );
+        droplet.public_ipv4 = None;
+        droplet.private_ipv4 = None;
+        assert_eq!(
+            default_bind_interface(&droplet, BindInterface::Public),
+            None
+        );
+    }
+
+    #[test]
+    fn preferred_ip_falls_back_to_public() {
+        let mut droplet = Droplet {
+            id: 1,
+            name: "web".to_string(),
+            status: "active".to_string(),
+            region: "nyc1".to_string(),
+            public_ipv4: Some("203.0.113.10".to_string()),
+            private_ipv4: Some("10.0.0.5".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            droplet.preferred_ip(BindInterface::Public),
+            Some("203.0.113.10")
+        );
+        assert_eq!(
+            droplet.preferred_ip(BindInterface::Private),
+            Some("10.0.0.5")
+        );
+        droplet.private_ipv4 = None;
+        assert_eq!(
+            droplet.preferred_ip(BindInterface::Private),
+            Some("203.0.113.10")
+        );
+    }
 }
@@ -0,0 +1,159 @@
+use std::io::Read;
+use std::process::{Command, Output, Stdio};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+
+use crate::logging;
+
+/// How often a cancellable wait checks whether it was cancelled.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Cooperative cancellation shared by the app and the tasks it spawns. Each task holds a
+/// `child()` snapshot; `cancel_all` cancels every task started before it without touching
+/// tasks started afterwards.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    generation: Arc<AtomicU64>,
+    started: u64,
+}
+
+impl CancelToken {
+    pub fn child(&self) -> Self {
+        Self {
+            generation: Arc::clone(&self.generation),
+            started: self.generation.load(Ordering::SeqCst),
+        }
+    }
+
+    pub fn cancel_all(&self) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.generation.load(Ordering::SeqCst) != self.started
+    }
+
+    pub fn check(&self) -> Result<()> {
+        if self.is_cancelled() {
+            return Err(Cancelled.into());
+        }
+        Ok(())
+    }
+
+    /// Sleeps for `duration`, waking early with `Cancelled` if the task is cancelled.
+    pub fn sleep(&self, duration: Duration) -> Result<()> {
+        let deadline = Instant::now() + duration;
+        loop {
+            self.check()?;
+            let now = Instant::now();
+            if now >= deadline {
+                return Ok(());
+            }
+            thread::sleep(POLL_INTERVAL.min(deadline - now));
+        }
+    }
+}
+
+/// A task stopped because the user cancelled it.
+#[derive(Debug, Clone, Copy)]
+pub struct Cancelled;
+
+impl std::fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+pub fn is_cancelled_error(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<Cancelled>().is_some()
+}
+
+/// `Command::output` that kills the child and returns `Cancelled` once `cancel` fires.
+pub fn output(cmd: &mut Command, cancel: &CancelToken) -> Result<Output> {
+    cancel.check()?;
+    logging::command(cmd);
+    let mut child = cmd
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to start {}", cmd.get_program().to_string_lossy()))?;
+    // Drain both pipes on their own threads so a chatty child can't block on a full pipe.
+    let stdout = child.stdout.take().map(drain);
+    let stderr = child.stderr.take().map(drain);
+    let collect = |reader: Option<thread::JoinHandle<Vec<u8>>>| {
+        reader
+            .and_then(|handle| handle.join().ok())
+            .unwrap_or_default()
+    };
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Output {
+                status,
+                stdout: collect(stdout),
+                stderr: collect(stderr),
+            });
+        }
+        if cancel.is_cancelled() {
+            let _ = child.kill();
+            let _ = child.wait();
+            logging::log(format!("cancelled pid {}", child.id()));
+            return Err(Cancelled.into());
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn drain(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = pipe.read_to_end(&mut buf);
+        buf
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancel_all_only_affects_tasks_started_before_it() {
+        let root = CancelToken::default();
+        let before = root.child();
+        root.cancel_all();
+        let after = root.child();
+        assert!(before.is_cancelled());
+        assert!(!after.is_cancelled());
+    }
+
+    #[test]
+    fn output_collects_and_cancellation_kills_the_child() {
+        let root = CancelToken::default();
+        let out = output(
+            Command::new("sh").arg("-c").arg("echo out; echo err >&2"),
+            &root.child(),
+        )
+        .expect("sh runs");
+        assert!(out.status.success());
+        assert_eq!(String::from_utf8_lossy(&out.stdout), "out\n");
+        assert_eq!(String::from_utf8_lossy(&out.stderr), "err\n");
+
+        let token = root.child();
+        let canceller = root.clone();
+        let started = Instant::now();
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(200));
+            canceller.cancel_all();
+        });
+        let err = output(Command::new("sleep").arg("10"), &token).expect_err("cancelled");
+        handle.join().unwrap();
+        assert!(is_cancelled_error(&err));
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+}
@@ -1,12 +1,30 @@
+use std::ffi::OsString;
 use std::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use directories::ProjectDirs;
+use serde_json::{Map, Value};
 
-use crate::model::{AppStateFile, Settings};
+use crate::model::{AppStateFile, BindInterface, ConnectMethod, Settings};
+
+/// Points the state file at a custom path instead of the OS config directory.
+pub const CONFIG_ENV_VAR: &str = "DOCTL_TUI_CONFIG";
 
 pub fn state_file_path() -> Result<PathBuf> {
+    state_file_path_from(std::env::var_os(CONFIG_ENV_VAR))
+}
+
+/// `state_file_path` with the `CONFIG_ENV_VAR` value passed in.
+fn state_file_path_from(override_path: Option<OsString>) -> Result<PathBuf> {
+    if let Some(path) = override_path.filter(|path| !path.is_empty()) {
+        let path = PathBuf::from(path);
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).context("Failed to create config directory")?;
+        }
+        return Ok(path);
+    }
     let proj = ProjectDirs::from("com", "digitalocean", "doctl-tui")
         .context("Unable to resolve config directory")?;
     let dir = proj.config_dir();
@@ -14,24 +32,155 @@ pub fn state_file_path() -> Result<PathBuf> {
     Ok(dir.join("state.json"))
 }
 
+/// Current `AppStateFile::version`; bump it and extend `migrate` when the shape changes.
+pub const STATE_VERSION: u32 = 1;
+
+/// The previous good state file, kept next to it by `save_state`.
+fn backup_path(path: &Path) -> PathBuf {
+    path.with_extension("json.bak")
+}
+
 pub fn load_state() -> Result<AppStateFile> {
     let path = state_file_path()?;
-    if !path.exists() {
-        return Ok(default_state());
+    let (state, rewrite) = load_state_from(&path)?;
+    if rewrite {
+        write_state_to(&path, &state).context("Failed to write recovered state file")?;
     }
-    let data = fs::read_to_string(&path).context("Failed to read state file")?;
+    Ok(state)
+}
+
+/// Reads the state file, falling back to its backup when the primary is missing or corrupt.
+/// The flag is set when the result should be written back (migrated or recovered).
+fn load_state_from(path: &Path) -> Result<(AppStateFile, bool)> {
+    let backup = backup_path(path);
+    if !path.exists() && !backup.exists() {
+        return Ok((default_state(), false));
+    }
+    read_state_file(path).or_else(|err| {
+        read_state_file(&backup)
+            .map(|(state, _)| (state, true))
+            .map_err(|_| err)
+    })
+}
+
+fn read_state_file(path: &Path) -> Result<(AppStateFile, bool)> {
+    let data = fs::read_to_string(path).context("Failed to read state file")?;
+    let mut raw: Value = serde_json::from_str(&data).context("Failed to parse state file")?;
+    let migrated = migrate(&mut raw);
     let mut state: AppStateFile =
-        serde_json::from_str(&data).context("Failed to parse state file")?;
+        serde_json::from_value(raw).context("Failed to parse state file")?;
     if state.settings.default_ssh_user.is_empty() {
         state.settings = default_settings();
     }
-    Ok(state)
+    Ok((state, migrated))
+}
+
+/// Upgrades an older state file in place to `STATE_VERSION`, returning whether it changed.
+/// Files from a newer version are left alone.
+pub fn migrate(raw: &mut Value) -> bool {
+    let Some(root) = raw.as_object_mut() else {
+        return false;
+    };
+    let version = root.get("version").and_then(Value::as_u64).unwrap_or(0);
+    if version >= u64::from(STATE_VERSION) {
+        return false;
+    }
+    if version < 1 {
+        migrate_v0(root);
+    }
+    root.insert("version".to_string(), Value::from(STATE_VERSION));
+    true
+}
+
+/// v0 files could keep the SSH defaults at the top level, call rsync binds `mounts`, and
+/// omit `bindings` or `settings` entirely.
+fn migrate_v0(root: &mut Map<String, Value>) {
+    if let Some(mounts) = root.remove("mounts") {
+        root.entry("rsync_binds").or_insert(mounts);
+    }
+    root.entry("bindings")
+        .or_insert_with(|| Value::Array(Vec::new()));
+
+    let mut settings = match root.remove("settings") {
+        Some(Value::Object(settings)) => settings,
+        _ => Map::new(),
+    };
+    for key in [
+        "default_ssh_user",
+        "default_ssh_key_path",
+        "default_ssh_port",
+    ] {
+        if let Some(value) = root.remove(key) {
+            settings.entry(key).or_insert(value);
+        }
+    }
+    if let Ok(Value::Object(defaults)) = serde_json::to_value(default_settings()) {
+        for (key, value) in defaults {
+            let missing = match settings.get(&key) {
+                None | Some(Value::Null) => true,
+                Some(Value::String(current)) => current.is_empty(),
+                Some(_) => false,
+            };
+            if missing && !value.is_null() {
+                settings.insert(key, value);
+            }
+        }
+    }
+    root.insert("settings".to_string(), Value::Object(settings));
+}
+
+/// Holds the pid of the running TUI, which rewrites the whole state file as it goes; the
+/// `workspace` CLI checks it instead of writing the file underneath.
+fn tui_pid_path() -> Result<PathBuf> {
+    Ok(state_file_path()?.with_extension("tui.pid"))
+}
+
+pub fn write_tui_pid() -> Result<()> {
+    fs::write(tui_pid_path()?, std::process::id().to_string()).context("Failed to write pid file")
+}
+
+/// Removes the pid file, unless another TUI has since taken it over.
+pub fn remove_tui_pid() {
+    let Ok(path) = tui_pid_path() else {
+        return;
+    };
+    if read_pid(&path) == Some(std::process::id()) {
+        let _ = fs::remove_file(path);
+    }
+}
+
+/// Pid recorded by the last TUI started; it may have exited without cleaning up.
+pub fn tui_pid() -> Option<u32> {
+    read_pid(&tui_pid_path().ok()?)
+}
+
+fn read_pid(path: &Path) -> Option<u32> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
 }
 
 pub fn save_state(state: &AppStateFile) -> Result<()> {
     let path = state_file_path()?;
+    write_state_to(&path, state)
+}
+
+/// Writes through a temp file renamed over `path`, so a kill mid-write never truncates it,
+/// and first copies the current file to the backup if it still parses.
+fn write_state_to(path: &Path, state: &AppStateFile) -> Result<()> {
     let data = serde_json::to_string_pretty(state).context("Failed to serialize state")?;
-    fs::write(&path, data).context("Failed to write state file")
+    let tmp = path.with_extension("json.tmp");
+    let mut file = fs::File::create(&tmp).context("Failed to create temp state file")?;
+    file.write_all(data.as_bytes())
+        .and_then(|_| file.sync_all())
+        .context("Failed to write temp state file")?;
+    drop(file);
+
+    let current_is_good = fs::read_to_string(path)
+        .ok()
+        .is_some_and(|current| serde_json::from_str::<Value>(&current).is_ok());
+    if current_is_good {
+        fs::copy(path, backup_path(path)).context("Failed to back up state file")?;
+    }
+    fs::rename(&tmp, path).context("Failed to replace state file")
 }
 
 pub fn default_settings() -> Settings {
@@ -40,15 +189,49 @@ pub fn default_settings() -> Settings {
         default_ssh_user: "root".to_string(),
         default_ssh_key_path: format!("{home}/.ssh/id_rsa"),
         default_ssh_port: 22,
+        last_create: None,
+        name_template: None,
+        connect_method: ConnectMethod::Doctl,
+        tmux_new_window: None,
+        doctl_context: None,
+        toast_ttl_secs: None,
+        toast_ttl_overrides: Default::default(),
+        overlay_delay_ms: 0,
+        remember_view: false,
+        last_view: None,
+        project_id: None,
+        favorites: Default::default(),
+        skip_bind_ssh_check: false,
+        tunnel_start_timeout_ms: None,
+        preferred_ip: BindInterface::Public,
+        reconnect_on_resume: false,
+        allowed_regions: Vec::new(),
+        allowed_sizes: Vec::new(),
+        show_dns_records: false,
     }
 }
 
 pub fn default_state() -> AppStateFile {
     AppStateFile {
+        version: STATE_VERSION,
         bindings: Vec::new(),
         rsync_binds: Vec::new(),
         settings: default_settings(),
+        droplet_ssh_keys: Default::default(),
+        port_history: Default::default(),
+        theme: Default::default(),
+        workspaces: Vec::new(),
+    }
+}
+
+/// Parses `#rrggbb` (leading `#` optional) into RGB components.
+pub fn parse_hex_color(value: &str) -> Option<(u8, u8, u8)> {
+    let hex = value.trim().trim_start_matches('#');
+    if hex.len() != 6 || !hex.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        return None;
     }
+    let channel = |range: std::ops::Range<usize>| u8::from_str_radix(&hex[range], 16).ok();
+    Some((channel(0..2)?, channel(2..4)?, channel(4..6)?))
 }
 
 #[cfg(test)]
@@ -75,6 +258,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_hex_color_accepts_rgb_and_rejects_garbage() {
+        assert_eq!(parse_hex_color("#00b4aa"), Some((0, 180, 170)));
+        assert_eq!(parse_hex_color("FFFFFF"), Some((255, 255, 255)));
+        assert_eq!(parse_hex_color("#fff"), None);
+        assert_eq!(parse_hex_color("#gggggg"), None);
+        assert_eq!(parse_hex_color(""), None);
+    }
+
     #[test]
     fn default_state_is_empty() {
         let state = default_state();
@@ -82,4 +274,153 @@ mod tests {
         assert!(state.rsync_binds.is_empty());
         assert_eq!(state.settings.default_ssh_user, "root");
     }
+
+    #[test]
+    fn settings_without_last_create_still_parse() {
+        let raw = r#"{
+            "bindings": [],
+            "settings": {
+                "default_ssh_user": "root",
+                "default_ssh_key_path": "/tmp/id_rsa",
+                "default_ssh_port": 22
+            }
+        }"#;
+        let state: AppStateFile = serde_json::from_str(raw).expect("state");
+        assert!(state.settings.last_create.is_none());
+        assert!(!state.settings.skip_bind_ssh_check);
+        assert_eq!(state.settings.preferred_ip, BindInterface::Public);
+        assert!(!state.settings.reconnect_on_resume);
+        assert!(state.settings.allowed_regions.is_empty());
+        assert!(state.rsync_binds.is_empty());
+    }
+
+    #[test]
+    fn legacy_rsync_binds_keep_compression_on() {
+        let raw = r#"{
+            "bindings": [],
+            "rsync_binds": [{
+                "droplet_name": "web",
+                "ssh_user": "root",
+                "host": "203.0.113.10",
+                "ssh_port": 22,
+                "ssh_key_path": "/tmp/id_rsa",
+                "remote_path": "/srv/app",
+                "local_path": "/tmp/app",
+                "created_at": "2024-01-01T00:00:00Z"
+            }],
+            "settings": {
+                "default_ssh_user": "root",
+                "default_ssh_key_path": "/tmp/id_rsa",
+                "default_ssh_port": 22
+            }
+        }"#;
+        let state: AppStateFile = serde_json::from_str(raw).expect("state");
+        let bind = &state.rsync_binds[0];
+        assert!(bind.compress);
+        assert!(!bind.checksum);
+        assert_eq!(bind.rsync_flags(), vec!["-a", "-z"]);
+    }
+
+    #[test]
+    fn migrate_upgrades_v0_file() {
+        let mut raw: Value = serde_json::from_str(
+            r#"{
+                "default_ssh_user": "deploy",
+                "default_ssh_port": 2222,
+                "mounts": [{
+                    "droplet_name": "web",
+                    "ssh_user": "root",
+                    "host": "203.0.113.10",
+                    "ssh_port": 22,
+                    "ssh_key_path": "/tmp/id_rsa",
+                    "remote_path": "/srv/app",
+                    "local_path": "/tmp/app",
+                    "created_at": "2024-01-01T00:00:00Z"
+                }]
+            }"#,
+        )
+        .expect("json");
+        assert!(migrate(&mut raw));
+        let state: AppStateFile = serde_json::from_value(raw.clone()).expect("state");
+        assert_eq!(state.version, STATE_VERSION);
+        assert_eq!(state.settings.default_ssh_user, "deploy");
+        assert_eq!(state.settings.default_ssh_port, 2222);
+        assert!(
+            state
+                .settings
+                .default_ssh_key_path
+                .ends_with("/.ssh/id_rsa")
+        );
+        assert!(state.bindings.is_empty());
+        assert_eq!(state.rsync_binds.len(), 1);
+        assert_eq!(state.rsync_binds[0].droplet_name, "web");
+        assert!(raw.get("default_ssh_user").is_none());
+
+        assert!(!migrate(&mut raw));
+    }
+
+    #[test]
+    fn migrate_leaves_newer_files_alone() {
+        let mut raw: Value =
+            serde_json::from_str(r#"{"version": 99, "bindings": [], "settings": {}}"#)
+                .expect("json");
+        let before = raw.clone();
+        assert!(!migrate(&mut raw));
+        assert_eq!(raw, before);
+    }
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("doctl-tui-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).expect("scratch dir");
+        dir
+    }
+
+    #[test]
+    fn save_keeps_backup_and_load_recovers_from_corruption() {
+        let dir = scratch_dir("atomic");
+        let path = dir.join("state.json");
+
+        let mut first = default_state();
+        first.settings.default_ssh_user = "first".to_string();
+        write_state_to(&path, &first).expect("first save");
+        assert!(!backup_path(&path).exists());
+
+        let mut second = default_state();
+        second.settings.default_ssh_user = "second".to_string();
+        write_state_to(&path, &second).expect("second save");
+        assert!(!path.with_extension("json.tmp").exists());
+
+        fs::write(&path, "{\"bindings\": [").expect("truncate");
+        let (state, rewrite) = load_state_from(&path).expect("recovered");
+        assert_eq!(state.settings.default_ssh_user, "first");
+        assert!(rewrite);
+
+        // A corrupt primary must not replace the good backup.
+        write_state_to(&path, &state).expect("rewrite");
+        let backup = fs::read_to_string(backup_path(&path)).expect("backup");
+        assert!(backup.contains("\"first\""));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn load_fails_when_primary_and_backup_are_corrupt() {
+        let dir = scratch_dir("corrupt");
+        let path = dir.join("state.json");
+        fs::write(&path, "not json").expect("primary");
+        fs::write(backup_path(&path), "also not json").expect("backup");
+        assert!(load_state_from(&path).is_err());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn state_file_path_honors_env_override() {
+        let dir = scratch_dir("env-override");
+        let path = dir.join("nested").join("custom.json");
+        let resolved = state_file_path_from(Some(path.clone().into_os_string()));
+        assert_eq!(resolved.expect("path"), path);
+        assert!(dir.join("nested").is_dir());
+        let _ = fs::remove_dir_all(&dir);
+    }
 }
@@ -1,10 +1,20 @@
 use std::process::Command;
+use std::sync::RwLock;
 
 use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, Duration, TimeZone, Utc};
 use serde::Deserialize;
+use serde::de::DeserializeOwned;
 use serde::de::{Error as DeError, Unexpected, Visitor};
 
-use crate::model::{Droplet, Image, Region, Size, Snapshot, SshKey};
+use crate::cancel::{self, CancelToken};
+use crate::logging::LoggedCommand;
+use crate::model::{
+    AccountInfo, DoctlContext, DomainRecord, Droplet, Image, Region, Size, Snapshot, SshKey,
+};
+use crate::tasks::shell_escape;
+
+static CONTEXT: RwLock<Option<String>> = RwLock::new(None);
 
 #[derive(Debug, Deserialize)]
 struct DropletApi {
@@ -16,6 +26,24 @@ struct DropletApi {
     created_at: Option<String>,
     tags: Option<Vec<String>>,
     networks: Option<NetworksApi>,
+    #[serde(default)]
+    features: Option<Vec<String>>,
+    #[serde(default)]
+    image: Option<DropletImageApi>,
+    #[serde(default)]
+    vcpus: Option<u32>,
+    /// Megabytes.
+    #[serde(default)]
+    memory: Option<u64>,
+    /// Gigabytes.
+    #[serde(default)]
+    disk: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DropletImageApi {
+    #[serde(default)]
+    slug: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -25,7 +53,17 @@ struct RegionApi {
 
 #[derive(Debug, Deserialize)]
 struct NetworksApi {
+    #[serde(default)]
     v4: Vec<NetworkV4>,
+    #[serde(default)]
+    v6: Vec<NetworkV6>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NetworkV6 {
+    ip_address: String,
+    #[serde(rename = "type")]
+    kind: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -67,6 +105,44 @@ struct ImageApi {
     distribution: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+struct AccountApi {
+    email: String,
+    #[serde(default)]
+    status: String,
+    #[serde(default)]
+    status_message: String,
+}
+
+/// A `droplet-action` as doctl reports it; `status` is `in-progress`, `completed`, or `errored`.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct DropletAction {
+    pub id: u64,
+    pub status: String,
+    #[serde(rename = "type", default)]
+    pub kind: String,
+}
+
+impl DropletAction {
+    pub fn is_done(&self) -> bool {
+        self.status != "in-progress"
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct DomainApi {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct DomainRecordApi {
+    #[serde(rename = "type")]
+    kind: String,
+    name: String,
+    #[serde(default)]
+    data: String,
+}
+
 #[derive(Debug, Deserialize)]
 struct SshKeyApi {
     id: u64,
@@ -182,29 +258,130 @@ where
     deserializer.deserialize_any(F64Visitor)
 }
 
-pub fn check_doctl() -> Result<()> {
+/// Sets the `--context` passed to every doctl invocation; `None` uses doctl's current context.
+pub fn set_context(context: Option<String>) {
+    let context = context
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty());
+    if let Ok(mut guard) = CONTEXT.write() {
+        *guard = context;
+    }
+}
+
+pub fn context_args() -> Vec<String> {
+    match CONTEXT.read().ok().and_then(|guard| guard.clone()) {
+        Some(name) => vec!["--context".to_string(), name],
+        None => Vec::new(),
+    }
+}
+
+fn doctl_command() -> Command {
+    let mut cmd = Command::new("doctl");
+    cmd.args(context_args());
+    cmd
+}
+
+pub fn list_contexts() -> Result<Vec<DoctlContext>> {
     let output = Command::new("doctl")
+        .args(["auth", "list"])
+        .logged_output()
+        .context("Failed to execute doctl")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow!(
+            "doctl auth list failed: {}",
+            friendly_doctl_error(&stderr)
+        ));
+    }
+    Ok(parse_auth_list(&String::from_utf8_lossy(&output.stdout)))
+}
+
+fn parse_auth_list(raw: &str) -> Vec<DoctlContext> {
+    raw.lines()
+        .filter_map(|line| {
+            let name = line.split_whitespace().next()?;
+            Some(DoctlContext {
+                name: name.to_string(),
+                current: line.contains("(current)"),
+            })
+        })
+        .collect()
+}
+
+pub fn check_doctl() -> Result<AccountInfo> {
+    let output = doctl_command()
         .args(["account", "get", "-o", "json"])
-        .output()
+        .logged_output()
         .context("Failed to execute doctl")?;
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
+        if let Some(limited) = rate_limited(&stderr) {
+            return Err(limited.into());
+        }
         return Err(anyhow!(
-            "doctl is not authenticated or failed to run: {stderr}"
+            "doctl is not authenticated for context '{}' (token missing, expired or revoked): {}",
+            active_context(),
+            friendly_doctl_error(&stderr)
         ));
     }
-    Ok(())
+    let api: AccountApi =
+        serde_json::from_slice(&output.stdout).context("Failed to parse doctl account output")?;
+    Ok(AccountInfo {
+        email: api.email,
+        context: active_context(),
+        status: api.status,
+        status_message: api.status_message,
+        token_from_env: std::env::var_os("DIGITALOCEAN_ACCESS_TOKEN")
+            .is_some_and(|v| !v.is_empty()),
+    })
+}
+
+/// The context doctl runs under: the pinned one, else whatever `doctl auth list` marks current.
+fn active_context() -> String {
+    if let Some(name) = CONTEXT.read().ok().and_then(|guard| guard.clone()) {
+        return name;
+    }
+    list_contexts()
+        .ok()
+        .and_then(|contexts| contexts.into_iter().find(|context| context.current))
+        .map(|context| context.name)
+        .unwrap_or_else(|| "default".to_string())
+}
+
+/// The page size doctl requests from the API when it paginates list commands.
+const DOCTL_PAGE_SIZE: usize = 200;
+
+/// Warns when a list length lands exactly on a page boundary, the one shape a silently
+/// truncated paginated response would have.
+pub fn truncation_warning(what: &str, count: usize) -> Option<String> {
+    if count == 0 || !count.is_multiple_of(DOCTL_PAGE_SIZE) {
+        return None;
+    }
+    Some(format!(
+        "doctl returned exactly {count} {what} ({} page(s) of {DOCTL_PAGE_SIZE}); the list may be truncated. Check with `doctl` directly if some are missing.",
+        count / DOCTL_PAGE_SIZE
+    ))
 }
 
 pub fn list_droplets() -> Result<Vec<Droplet>> {
     let raw = run_doctl_json(&["compute", "droplet", "list"])?;
-    let api: Vec<DropletApi> = serde_json::from_value(raw)?;
+    let api: Vec<DropletApi> = parse_json_list(raw, "droplet")?;
     Ok(api.into_iter().map(map_droplet).collect())
 }
 
+pub fn get_droplet(id: u64) -> Result<Droplet> {
+    let id = id.to_string();
+    let raw = run_doctl_json(&["compute", "droplet", "get", &id])?;
+    let api: Vec<DropletApi> = parse_json_list(raw, "droplet")?;
+    api.into_iter()
+        .next()
+        .map(map_droplet)
+        .ok_or_else(|| anyhow!("doctl returned no droplet for #{id}"))
+}
+
 pub fn list_snapshots() -> Result<Vec<Snapshot>> {
     let raw = run_doctl_json(&["compute", "snapshot", "list", "--resource", "droplet"])?;
-    let api: Vec<SnapshotApi> = serde_json::from_value(raw)?;
+    let api: Vec<SnapshotApi> = parse_json_list(raw, "snapshot")?;
     Ok(api
         .into_iter()
         .map(|snap| Snapshot {
@@ -301,7 +478,7 @@ pub fn list_regions() -> Result<Vec<Region>> {
 
 pub fn list_sizes() -> Result<Vec<Size>> {
     let raw = run_doctl_json(&["compute", "size", "list"])?;
-    let api: Vec<SizeListApi> = serde_json::from_value(raw)?;
+    let api: Vec<SizeListApi> = parse_json_list(raw, "size")?;
     Ok(api
         .into_iter()
         .map(|size| Size {
@@ -316,7 +493,7 @@ pub fn list_sizes() -> Result<Vec<Size>> {
 
 pub fn list_images() -> Result<Vec<Image>> {
     let raw = run_doctl_json(&["compute", "image", "list-distribution"])?;
-    let api: Vec<ImageApi> = serde_json::from_value(raw)?;
+    let api: Vec<ImageApi> = parse_json_list(raw, "image")?;
     Ok(api
         .into_iter()
         .map(|image| Image {
@@ -330,7 +507,7 @@ pub fn list_images() -> Result<Vec<Image>> {
 
 pub fn list_ssh_keys() -> Result<Vec<SshKey>> {
     let raw = run_doctl_json(&["compute", "ssh-key", "list"])?;
-    let api: Vec<SshKeyApi> = serde_json::from_value(raw)?;
+    let api: Vec<SshKeyApi> = parse_json_list(raw, "SSH key")?;
     Ok(api
         .into_iter()
         .map(|key| SshKey {
@@ -341,9 +518,38 @@ pub fn list_ssh_keys() -> Result<Vec<SshKey>> {
         .collect())
 }
 
-pub fn create_droplet(args: &CreateDropletArgs) -> Result<Droplet> {
-    let raw = run_doctl_json_owned(build_create_command(args))?;
-    let api: Vec<DropletApi> = serde_json::from_value(raw)?;
+pub fn list_domains() -> Result<Vec<String>> {
+    let raw = run_doctl_json(&["compute", "domain", "list"])?;
+    let api: Vec<DomainApi> = parse_json_list(raw, "domain")?;
+    Ok(api.into_iter().map(|domain| domain.name).collect())
+}
+
+pub fn list_domain_records(domain: &str) -> Result<Vec<DomainRecord>> {
+    let raw = run_doctl_json(&["compute", "domain", "records", "list", domain])?;
+    let api: Vec<DomainRecordApi> = parse_json_list(raw, "domain record")?;
+    Ok(api
+        .into_iter()
+        .map(|record| DomainRecord {
+            domain: domain.to_string(),
+            name: record.name,
+            kind: record.kind,
+            data: record.data,
+        })
+        .collect())
+}
+
+/// Every record across every domain on the account: one call per domain.
+pub fn list_all_domain_records() -> Result<Vec<DomainRecord>> {
+    let mut records = Vec::new();
+    for domain in list_domains()? {
+        records.extend(list_domain_records(&domain)?);
+    }
+    Ok(records)
+}
+
+pub fn create_droplet(args: &CreateDropletArgs, cancel: &CancelToken) -> Result<Droplet> {
+    let raw = run_doctl_json_owned(build_create_command(args), cancel)?;
+    let api: Vec<DropletApi> = parse_json_list(raw, "droplet")?;
     let droplet = api
         .into_iter()
         .next()
@@ -351,8 +557,21 @@ pub fn create_droplet(args: &CreateDropletArgs) -> Result<Droplet> {
     Ok(map_droplet(droplet))
 }
 
-pub fn create_droplet_from_snapshot(args: &CreateDropletArgs) -> Result<Droplet> {
-    create_droplet(args)
+pub fn create_droplet_from_snapshot(
+    args: &CreateDropletArgs,
+    cancel: &CancelToken,
+) -> Result<Droplet> {
+    create_droplet(args, cancel)
+}
+
+/// The exact `doctl` invocation `create_droplet` would run, as a copy-pasteable shell line.
+pub fn create_command_line(args: &CreateDropletArgs) -> String {
+    std::iter::once("doctl".to_string())
+        .chain(context_args())
+        .chain(build_create_command(args))
+        .map(|arg| shell_escape(&arg))
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
 fn build_create_command(args: &CreateDropletArgs) -> Vec<String> {
@@ -365,8 +584,10 @@ fn build_create_command(args: &CreateDropletArgs) -> Vec<String> {
         args.size.clone(),
         "--image".to_string(),
         args.image.clone(),
-        "--wait".to_string(),
     ];
+    if args.wait {
+        cmd.push("--wait".to_string());
+    }
 
     if let Some(region) = args.region.as_ref() {
         if !region.trim().is_empty() {
@@ -385,10 +606,21 @@ fn build_create_command(args: &CreateDropletArgs) -> Vec<String> {
         cmd.push(args.tags.join(","));
     }
 
+    if let Some(project_id) = args.project_id.as_ref().filter(|id| !id.trim().is_empty()) {
+        cmd.push("--project-id".to_string());
+        cmd.push(project_id.clone());
+    }
+
     cmd
 }
 
-pub fn snapshot_droplet(droplet_id: u64, snapshot_name: &str) -> Result<()> {
+/// Starts a snapshot without `--wait`, which can time out on large disks; poll the
+/// returned action with `get_droplet_action`.
+pub fn start_snapshot(
+    droplet_id: u64,
+    snapshot_name: &str,
+    cancel: &CancelToken,
+) -> Result<DropletAction> {
     let cmd = vec![
         "compute".to_string(),
         "droplet-action".to_string(),
@@ -396,14 +628,46 @@ pub fn snapshot_droplet(droplet_id: u64, snapshot_name: &str) -> Result<()> {
         droplet_id.to_string(),
         "--snapshot-name".to_string(),
         snapshot_name.to_string(),
+    ];
+    let raw = run_doctl_json_owned(cmd, cancel)?;
+    parse_json_list::<DropletAction>(raw, "droplet action")?
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow!("doctl returned no action for the snapshot"))
+}
+
+/// Shuts the droplet down and waits for it to be off, so a following snapshot is consistent.
+pub fn power_off_droplet(droplet_id: u64, cancel: &CancelToken) -> Result<()> {
+    let cmd = vec![
+        "compute".to_string(),
+        "droplet-action".to_string(),
+        "power-off".to_string(),
+        droplet_id.to_string(),
         "--wait".to_string(),
     ];
-    run_doctl_json_owned(cmd)?;
+    run_doctl_json_owned(cmd, cancel)?;
     Ok(())
 }
 
+pub fn get_droplet_action(droplet_id: u64, action_id: u64) -> Result<DropletAction> {
+    let droplet_id = droplet_id.to_string();
+    let action_id = action_id.to_string();
+    let raw = run_doctl_json(&[
+        "compute",
+        "droplet-action",
+        "get",
+        &droplet_id,
+        "--action-id",
+        &action_id,
+    ])?;
+    parse_json_list::<DropletAction>(raw, "droplet action")?
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow!("doctl returned no droplet action #{action_id}"))
+}
+
 pub fn delete_droplet(droplet_id: u64) -> Result<()> {
-    let output = Command::new("doctl")
+    let output = doctl_command()
         .args([
             "compute",
             "droplet",
@@ -411,11 +675,17 @@ pub fn delete_droplet(droplet_id: u64) -> Result<()> {
             &droplet_id.to_string(),
             "--force",
         ])
-        .output()
+        .logged_output()
         .context("Failed to execute doctl delete")?;
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(anyhow!("doctl delete failed: {stderr}"));
+        if let Some(limited) = rate_limited(&stderr) {
+            return Err(limited.into());
+        }
+        return Err(anyhow!(
+            "doctl delete failed: {}",
+            friendly_doctl_error(&stderr)
+        ));
     }
     Ok(())
 }
@@ -437,6 +707,13 @@ fn map_droplet(droplet: DropletApi) -> Droplet {
             (public_ip, private_ip)
         })
         .unwrap_or((None, None));
+    let public_ipv6 = droplet.networks.as_ref().and_then(|networks| {
+        networks
+            .v6
+            .iter()
+            .find(|net| net.kind == "public")
+            .map(|net| net.ip_address.clone())
+    });
 
     Droplet {
         id: droplet.id,
@@ -445,40 +722,179 @@ fn map_droplet(droplet: DropletApi) -> Droplet {
         region: droplet.region.slug,
         size: droplet.size_slug,
         public_ipv4,
+        public_ipv6,
         private_ipv4,
         created_at: droplet.created_at,
         tags: droplet.tags.unwrap_or_default(),
+        image: droplet.image.and_then(|image| image.slug),
+        features: droplet.features,
+        vcpus: droplet.vcpus,
+        memory_mb: droplet.memory,
+        disk_gb: droplet.disk,
     }
 }
 
+/// doctl walks every API page itself (at its fixed maximum of 200 per page) before printing
+/// `-o json`, so list callers get the full result set; there is no page-size flag to raise.
+/// A failure on any page fails the whole command, surfacing here as a non-zero exit.
 fn run_doctl_json(args: &[&str]) -> Result<serde_json::Value> {
-    let output = Command::new("doctl")
+    let output = doctl_command()
         .args(args)
         .args(["-o", "json"])
-        .output()
+        .logged_output()
         .context("Failed to execute doctl")?;
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(anyhow!("doctl failed: {stderr}"));
+        if let Some(limited) = rate_limited(&stderr) {
+            return Err(limited.into());
+        }
+        return Err(anyhow!("doctl failed: {}", friendly_doctl_error(&stderr)));
     }
     let stdout = String::from_utf8_lossy(&output.stdout);
-    serde_json::from_str(&stdout).context("Failed to parse doctl JSON output")
+    serde_json::from_str(&stdout)
+        .context("Failed to parse doctl JSON output (a list may have been cut off mid-page)")
 }
 
-fn run_doctl_json_owned(args: Vec<String>) -> Result<serde_json::Value> {
-    let output = Command::new("doctl")
-        .args(args)
-        .args(["-o", "json"])
-        .output()
+/// For the long `--wait` calls; killed if the task is cancelled.
+fn run_doctl_json_owned(args: Vec<String>, cancel: &CancelToken) -> Result<serde_json::Value> {
+    let output = cancel::output(doctl_command().args(args).args(["-o", "json"]), cancel)
         .context("Failed to execute doctl")?;
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(anyhow!("doctl failed: {stderr}"));
+        if let Some(limited) = rate_limited(&stderr) {
+            return Err(limited.into());
+        }
+        return Err(anyhow!("doctl failed: {}", friendly_doctl_error(&stderr)));
     }
     let stdout = String::from_utf8_lossy(&output.stdout);
     serde_json::from_str(&stdout).context("Failed to parse doctl JSON output")
 }
 
+/// Seconds to back off when a 429 does not say when the window resets; the tightest
+/// DigitalOcean limit is per minute.
+const RATE_LIMIT_FALLBACK_SECS: i64 = 60;
+
+/// DigitalOcean answered 429 Too Many Requests; further API calls should wait for `reset_at`.
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimited {
+    pub reset_at: DateTime<Utc>,
+}
+
+impl std::fmt::Display for RateLimited {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DigitalOcean API rate limit hit; retrying after {}",
+            self.reset_at
+                .with_timezone(&chrono::Local)
+                .format("%H:%M:%S")
+        )
+    }
+}
+
+impl std::error::Error for RateLimited {}
+
+/// Recognizes a 429 in doctl's stderr, taking the reset time from a `RateLimit-Reset`
+/// header when doctl echoed one (e.g. with `--trace`).
+fn rate_limited(stderr: &str) -> Option<RateLimited> {
+    let lower = stderr.to_ascii_lowercase();
+    if !lower.contains("too many requests") && !lower.contains(": 429 ") {
+        return None;
+    }
+    let reset_at = lower
+        .find("ratelimit-reset")
+        .and_then(|start| {
+            let rest = &lower[start..];
+            let digits_start = rest.find(|c: char| c.is_ascii_digit())?;
+            let digits: String = rest[digits_start..]
+                .chars()
+                .take_while(|c| c.is_ascii_digit())
+                .collect();
+            digits.parse::<i64>().ok()
+        })
+        .and_then(|secs| Utc.timestamp_opt(secs, 0).single())
+        .unwrap_or_else(|| Utc::now() + Duration::seconds(RATE_LIMIT_FALLBACK_SECS));
+    Some(RateLimited { reset_at })
+}
+
+/// Pulls `detail`/`message` out of doctl's JSON error payloads, falling back to raw stderr.
+fn friendly_doctl_error(stderr: &str) -> String {
+    let trimmed = stderr.trim();
+    let parsed = trimmed
+        .find('{')
+        .and_then(|start| serde_json::from_str::<serde_json::Value>(&trimmed[start..]).ok());
+    if let Some(value) = parsed {
+        let field = |item: &serde_json::Value| {
+            item.get("detail")
+                .or_else(|| item.get("message"))
+                .and_then(|v| v.as_str())
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+        };
+        let mut messages: Vec<String> = value
+            .get("errors")
+            .and_then(|errors| errors.as_array())
+            .map(|errors| errors.iter().filter_map(field).collect())
+            .unwrap_or_default();
+        if messages.is_empty() {
+            messages.extend(field(&value));
+        }
+        if !messages.is_empty() {
+            return messages.join("; ");
+        }
+    }
+    trimmed.trim_start_matches("Error:").trim().to_string()
+}
+
+/// Accepts doctl list output as an array, a single object (some doctl versions print one
+/// item unwrapped), or `null` for an empty list; anything else is reported with its shape.
+fn parse_json_list<T: DeserializeOwned>(raw: serde_json::Value, what: &str) -> Result<Vec<T>> {
+    match raw {
+        serde_json::Value::Array(_) => serde_json::from_value(raw)
+            .with_context(|| format!("Unexpected {what} list format in doctl output")),
+        serde_json::Value::Object(_) => {
+            if let Some(message) = json_error_message(&raw) {
+                return Err(anyhow!(
+                    "doctl returned an error for {what} list: {message}"
+                ));
+            }
+            let item = serde_json::from_value(raw)
+                .with_context(|| format!("Unexpected {what} object in doctl output"))?;
+            Ok(vec![item])
+        }
+        serde_json::Value::Null => Ok(Vec::new()),
+        other => Err(anyhow!(
+            "Expected {what} list from doctl, got {}",
+            json_kind(&other)
+        )),
+    }
+}
+
+/// An error payload that doctl printed to stdout while still exiting 0.
+fn json_error_message(value: &serde_json::Value) -> Option<String> {
+    if value.get("id").is_some_and(|id| id.is_u64()) {
+        return None;
+    }
+    let has_errors = value.get("errors").is_some();
+    let has_message = value.get("message").is_some() || value.get("detail").is_some();
+    if has_errors || has_message {
+        Some(friendly_doctl_error(&value.to_string()))
+    } else {
+        None
+    }
+}
+
+fn json_kind(value: &serde_json::Value) -> &'static str {
+    match value {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "a boolean",
+        serde_json::Value::Number(_) => "a number",
+        serde_json::Value::String(_) => "a string",
+        serde_json::Value::Array(_) => "an array",
+        serde_json::Value::Object(_) => "an object",
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CreateDropletArgs {
     pub name: String,
@@ -487,6 +903,9 @@ pub struct CreateDropletArgs {
     pub image: String,
     pub ssh_keys: Vec<String>,
     pub tags: Vec<String>,
+    pub project_id: Option<String>,
+    /// Pass `--wait`; off returns as soon as DigitalOcean accepts the create.
+    pub wait: bool,
 }
 
 #[cfg(test)]
@@ -516,12 +935,71 @@ mod tests {
                         kind: "public".to_string(),
                     },
                 ],
+                v6: Vec::new(),
+            }),
+            features: Some(vec!["backups".to_string(), "ipv6".to_string()]),
+            image: Some(DropletImageApi {
+                slug: Some("ubuntu-24-04-x64".to_string()),
             }),
+            vcpus: Some(1),
+            memory: Some(1024),
+            disk: Some(25),
         };
         let droplet = map_droplet(api);
         assert_eq!(droplet.public_ipv4.as_deref(), Some("203.0.113.10"));
         assert_eq!(droplet.private_ipv4.as_deref(), Some("10.0.0.2"));
         assert_eq!(droplet.tags.len(), 0);
+        assert_eq!(droplet.backups_enabled(), Some(true));
+        assert_eq!(droplet.image.as_deref(), Some("ubuntu-24-04-x64"));
+        assert_eq!(
+            droplet.features,
+            Some(vec!["backups".to_string(), "ipv6".to_string()])
+        );
+        assert_eq!(
+            (droplet.vcpus, droplet.memory_mb, droplet.disk_gb),
+            (Some(1), Some(1024), Some(25))
+        );
+    }
+
+    #[test]
+    fn map_droplet_handles_ipv6_only_droplet() {
+        let api: DropletApi = serde_json::from_value(serde_json::json!({
+            "id": 7,
+            "name": "v6-only",
+            "status": "active",
+            "region": { "slug": "ams3" },
+            "networks": {
+                "v4": [],
+                "v6": [{ "ip_address": "2001:db8::10", "type": "public" }]
+            }
+        }))
+        .expect("droplet json");
+        let droplet = map_droplet(api);
+        assert_eq!(droplet.public_ipv4, None);
+        assert_eq!(droplet.public_ipv6.as_deref(), Some("2001:db8::10"));
+        assert_eq!(droplet.public_ip(), Some("2001:db8::10"));
+        // Older doctl output without features or specs still parses.
+        assert_eq!(droplet.features, None);
+        assert_eq!(droplet.backups_enabled(), None);
+        assert_eq!((droplet.vcpus, droplet.memory_mb), (None, None));
+    }
+
+    #[test]
+    fn map_droplet_handles_private_only_droplet() {
+        let api: DropletApi = serde_json::from_value(serde_json::json!({
+            "id": 8,
+            "name": "vpc-only",
+            "status": "active",
+            "region": { "slug": "fra1" },
+            "networks": {
+                "v4": [{ "ip_address": "10.114.0.3", "type": "private" }],
+                "v6": []
+            }
+        }))
+        .expect("droplet json");
+        let droplet = map_droplet(api);
+        assert_eq!(droplet.public_ip(), None);
+        assert_eq!(droplet.private_ipv4.as_deref(), Some("10.114.0.3"));
     }
 
     #[test]
@@ -533,6 +1011,8 @@ mod tests {
             image: "ubuntu-22-04-x64".to_string(),
             ssh_keys: vec!["123".to_string(), "456".to_string()],
             tags: vec!["dev".to_string(), "test".to_string()],
+            project_id: Some("4e8b1c2a-0f6d-4a55-9c1e-7b2f3d9a8e61".to_string()),
+            wait: true,
         };
         let cmd = build_create_command(&args);
         let joined = cmd.join(" ");
@@ -540,6 +1020,27 @@ mod tests {
         assert!(joined.contains("--region nyc1"));
         assert!(joined.contains("--ssh-keys 123,456"));
         assert!(joined.contains("--tag-names dev,test"));
+        assert!(joined.contains("--project-id 4e8b1c2a-0f6d-4a55-9c1e-7b2f3d9a8e61"));
+    }
+
+    #[test]
+    fn create_command_line_quotes_every_word() {
+        let args = CreateDropletArgs {
+            name: "demo box".to_string(),
+            region: Some("nyc1".to_string()),
+            size: "s-1vcpu-1gb".to_string(),
+            image: "ubuntu-22-04-x64".to_string(),
+            ssh_keys: vec!["123".to_string()],
+            tags: vec!["it's".to_string()],
+            project_id: None,
+            wait: true,
+        };
+        assert_eq!(
+            create_command_line(&args),
+            "'doctl' 'compute' 'droplet' 'create' 'demo box' '--size' 's-1vcpu-1gb' '--image' \
+             'ubuntu-22-04-x64' '--wait' '--region' 'nyc1' '--ssh-keys' '123' '--tag-names' \
+             'it'\"'\"'s'"
+        );
     }
 
     #[test]
@@ -551,12 +1052,42 @@ mod tests {
             image: "ubuntu-22-04-x64".to_string(),
             ssh_keys: vec![],
             tags: vec![],
+            project_id: Some(" ".to_string()),
+            wait: false,
         };
         let cmd = build_create_command(&args);
         let joined = cmd.join(" ");
+        assert!(!joined.contains("--wait"));
         assert!(!joined.contains("--region"));
         assert!(!joined.contains("--ssh-keys"));
         assert!(!joined.contains("--tag-names"));
+        assert!(!joined.contains("--project-id"));
+    }
+
+    #[test]
+    fn friendly_doctl_error_extracts_detail() {
+        let stderr =
+            r#"{"errors":[{"detail":"droplet limit exceeded","code":"unprocessable_entity"}]}"#;
+        assert_eq!(friendly_doctl_error(stderr), "droplet limit exceeded");
+        let message = r#"Error: {"id":"not_found","message":"The resource you requested could not be found."}"#;
+        assert_eq!(
+            friendly_doctl_error(message),
+            "The resource you requested could not be found."
+        );
+        assert_eq!(
+            friendly_doctl_error("Error: unable to authenticate\n"),
+            "unable to authenticate"
+        );
+    }
+
+    #[test]
+    fn parse_auth_list_marks_current_context() {
+        let contexts = parse_auth_list("default\nwork (current)\n\n");
+        assert_eq!(contexts.len(), 2);
+        assert_eq!(contexts[0].name, "default");
+        assert!(!contexts[0].current);
+        assert_eq!(contexts[1].name, "work");
+        assert!(contexts[1].current);
     }
 
     #[test]
@@ -568,4 +1099,153 @@ mod tests {
         let sfo1 = regions.iter().find(|r| r.slug == "sfo1").unwrap();
         assert!(!sfo1.available);
     }
+
+    #[test]
+    fn parse_json_list_accepts_array_or_single_object() {
+        let keys: Vec<SshKeyApi> = parse_json_list(
+            serde_json::json!([
+                {"id": 1, "name": "laptop", "fingerprint": "aa"},
+                {"id": 2, "name": "desktop", "fingerprint": "bb"}
+            ]),
+            "SSH key",
+        )
+        .expect("array");
+        assert_eq!(keys.len(), 2);
+        assert_eq!(keys[1].name, "desktop");
+
+        let keys: Vec<SshKeyApi> = parse_json_list(
+            serde_json::json!({"id": 3, "name": "ci", "fingerprint": "cc"}),
+            "SSH key",
+        )
+        .expect("single object");
+        assert_eq!(keys.len(), 1);
+        assert_eq!(keys[0].id, 3);
+
+        let keys: Vec<SshKeyApi> =
+            parse_json_list(serde_json::Value::Null, "SSH key").expect("null");
+        assert!(keys.is_empty());
+    }
+
+    #[test]
+    fn domain_records_parse_and_match_by_ip() {
+        let api: Vec<DomainRecordApi> = parse_json_list(
+            serde_json::json!([
+                {"id": 1, "type": "A", "name": "@", "data": "203.0.113.10", "ttl": 1800},
+                {"id": 2, "type": "A", "name": "www", "data": "203.0.113.10", "ttl": 1800},
+                {"id": 3, "type": "NS", "name": "@", "data": "ns1.digitalocean.com"},
+                {"id": 4, "type": "A", "name": "api", "data": "198.51.100.2"}
+            ]),
+            "domain record",
+        )
+        .expect("records");
+        let records: Vec<DomainRecord> = api
+            .into_iter()
+            .map(|record| DomainRecord {
+                domain: "example.com".to_string(),
+                name: record.name,
+                kind: record.kind,
+                data: record.data,
+            })
+            .collect();
+        assert_eq!(
+            crate::model::hostnames_for_ip(&records, "203.0.113.10"),
+            vec!["example.com", "www.example.com"]
+        );
+        assert!(crate::model::hostnames_for_ip(&records, "10.0.0.1").is_empty());
+    }
+
+    #[test]
+    fn droplet_action_parses_doctl_output() {
+        let actions: Vec<DropletAction> = parse_json_list(
+            serde_json::json!([{
+                "id": 36804745,
+                "status": "in-progress",
+                "type": "snapshot",
+                "started_at": "2024-01-01T00:00:00Z",
+                "resource_id": 3164450
+            }]),
+            "droplet action",
+        )
+        .expect("action");
+        assert_eq!(actions[0].id, 36804745);
+        assert_eq!(actions[0].kind, "snapshot");
+        assert!(!actions[0].is_done());
+    }
+
+    #[test]
+    fn parse_json_list_explains_unexpected_shapes() {
+        let err = parse_json_list::<SshKeyApi>(
+            serde_json::json!({"id": "unauthorized", "message": "Unable to authenticate you"}),
+            "SSH key",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "doctl returned an error for SSH key list: Unable to authenticate you"
+        );
+
+        let err =
+            parse_json_list::<SshKeyApi>(serde_json::json!({"id": 4}), "SSH key").unwrap_err();
+        assert_eq!(err.to_string(), "Unexpected SSH key object in doctl output");
+
+        let err = parse_json_list::<SshKeyApi>(
+            serde_json::json!({"errors": [{"detail": "rate limited"}]}),
+            "SSH key",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "doctl returned an error for SSH key list: rate limited"
+        );
+
+        let err = parse_json_list::<SshKeyApi>(serde_json::json!("oops"), "SSH key").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Expected SSH key list from doctl, got a string"
+        );
+    }
+
+    #[test]
+    fn truncation_warning_only_on_page_boundaries() {
+        assert!(truncation_warning("droplets", 0).is_none());
+        assert!(truncation_warning("droplets", 199).is_none());
+        assert!(truncation_warning("droplets", 201).is_none());
+        let warning = truncation_warning("snapshots", 400).expect("warning");
+        assert!(warning.starts_with("doctl returned exactly 400 snapshots (2 page(s) of 200)"));
+    }
+
+    #[test]
+    fn account_api_tolerates_missing_status() {
+        let api: AccountApi =
+            serde_json::from_str(r#"{"email":"me@example.com","uuid":"abc"}"#).expect("account");
+        assert_eq!(api.email, "me@example.com");
+        assert!(api.status.is_empty());
+        let locked: AccountApi = serde_json::from_str(
+            r#"{"email":"me@example.com","status":"locked","status_message":"Billing overdue"}"#,
+        )
+        .expect("account");
+        let info = AccountInfo {
+            email: locked.email,
+            context: "default".to_string(),
+            status: locked.status,
+            status_message: locked.status_message,
+            token_from_env: false,
+        };
+        assert_eq!(
+            info.warning().as_deref(),
+            Some("account locked: Billing overdue")
+        );
+    }
+
+    #[test]
+    fn rate_limited_reads_reset_header() {
+        let stderr = r#"Error: GET https://api.digitalocean.com/v2/droplets: 429 (request "abc") Too many requests
+"Ratelimit-Reset": ["1700000000"]"#;
+        let limited = rate_limited(stderr).expect("rate limited");
+        assert_eq!(limited.reset_at.timestamp(), 1_700_000_000);
+        let bare = rate_limited("Error: GET https://api.digitalocean.com/v2/account: 429 ")
+            .expect("rate limited");
+        assert!(bare.reset_at > Utc::now());
+        assert!(rate_limited("Error: droplet limit exceeded").is_none());
+    }
 }
@@ -0,0 +1,628 @@
+use std::collections::HashSet;
+
+use anyhow::{Result, anyhow};
+
+use crate::config;
+use crate::doctl;
+use crate::model::{AppStateFile, Droplet, PortBinding, Workspace, WorkspaceSync};
+use crate::mutagen::{self, SshConfig};
+use crate::ports;
+
+pub const USAGE: &str =
+    "Usage: digital-ocean-droplet-manager [--verbose] [--restore-tunnels] [--restore-syncs]
+       digital-ocean-droplet-manager [--verbose] list [--json] [--running]
+       digital-ocean-droplet-manager [--verbose] workspace <command> [name]
+
+Without flags the interactive TUI starts. With flags nothing is drawn: the
+requested work runs, a summary is printed, and the exit code is non-zero if
+anything failed.
+
+  --restore-tunnels  Start every saved port binding whose tunnel is not running
+  --restore-syncs    Restore Mutagen syncs from ~/.mountlist on every host that
+                     has a saved port binding or RSYNC bind
+  -v, --verbose      Append task, result, and subprocess traces to debug.log next
+                     to the state file (or set DOCTL_TUI_LOG=1)
+  -h, --help         Show this help
+
+  list               Print the droplets as a table and exit
+    --json           Print them as JSON instead
+    --running        Only running droplets, like the TUI's `f` filter
+
+  workspace list               Print the saved workspaces
+  workspace save <name>        Record the running tunnels and syncs as <name>,
+                               replacing any workspace with that name
+  workspace activate <name>    Start its tunnels and syncs, skipping running ones
+  workspace deactivate <name>  Stop its tunnels and pause its syncs
+  workspace delete <name>      Forget <name>; nothing is stopped";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorkspaceCommand {
+    List,
+    Save(String),
+    Activate(String),
+    Deactivate(String),
+    Delete(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mode {
+    Tui,
+    Help,
+    Headless {
+        restore_tunnels: bool,
+        restore_syncs: bool,
+    },
+    List {
+        json: bool,
+        running: bool,
+    },
+    Workspace(WorkspaceCommand),
+}
+
+/// Pulls `--verbose`/`-v` out of the arguments, wherever it appears.
+pub fn take_verbose(args: impl IntoIterator<Item = String>) -> (bool, Vec<String>) {
+    let (flags, rest): (Vec<String>, Vec<String>) = args
+        .into_iter()
+        .partition(|arg| arg == "--verbose" || arg == "-v");
+    (!flags.is_empty(), rest)
+}
+
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Mode> {
+    let mut args = args.into_iter().peekable();
+    if args.peek().is_some_and(|arg| arg == "list") {
+        args.next();
+        return parse_list_args(args);
+    }
+    if args.peek().is_some_and(|arg| arg == "workspace") {
+        args.next();
+        return parse_workspace_args(args);
+    }
+    let mut restore_tunnels = false;
+    let mut restore_syncs = false;
+    for arg in args {
+        match arg.as_str() {
+            "--restore-tunnels" => restore_tunnels = true,
+            "--restore-syncs" => restore_syncs = true,
+            "-h" | "--help" => return Ok(Mode::Help),
+            other => return Err(anyhow!("Unknown argument '{other}'\n\n{USAGE}")),
+        }
+    }
+    if !restore_tunnels && !restore_syncs {
+        return Ok(Mode::Tui);
+    }
+    Ok(Mode::Headless {
+        restore_tunnels,
+        restore_syncs,
+    })
+}
+
+fn parse_list_args(args: impl Iterator<Item = String>) -> Result<Mode> {
+    let (mut json, mut running) = (false, false);
+    for arg in args {
+        match arg.as_str() {
+            "--json" => json = true,
+            "--running" => running = true,
+            "-h" | "--help" => return Ok(Mode::Help),
+            other => return Err(anyhow!("Unknown list argument '{other}'\n\n{USAGE}")),
+        }
+    }
+    Ok(Mode::List { json, running })
+}
+
+fn parse_workspace_args(args: impl Iterator<Item = String>) -> Result<Mode> {
+    let args: Vec<String> = args.collect();
+    let name = || match args.get(1..) {
+        Some([name]) if !name.trim().is_empty() => Ok(name.trim().to_string()),
+        _ => Err(anyhow!(
+            "workspace {} takes one workspace name\n\n{USAGE}",
+            args[0]
+        )),
+    };
+    let command = match args.first().map(String::as_str) {
+        Some("list") if args.len() == 1 => WorkspaceCommand::List,
+        Some("list") => return Err(anyhow!("workspace list takes no arguments\n\n{USAGE}")),
+        Some("save") => WorkspaceCommand::Save(name()?),
+        Some("activate") => WorkspaceCommand::Activate(name()?),
+        Some("deactivate") => WorkspaceCommand::Deactivate(name()?),
+        Some("delete") => WorkspaceCommand::Delete(name()?),
+        Some("-h" | "--help") => return Ok(Mode::Help),
+        Some(other) => return Err(anyhow!("Unknown workspace command '{other}'\n\n{USAGE}")),
+        None => return Err(anyhow!("Missing workspace command\n\n{USAGE}")),
+    };
+    Ok(Mode::Workspace(command))
+}
+
+/// Prints the droplet list for scripts, honoring the saved doctl context.
+pub fn run_list(json: bool, running: bool) -> Result<()> {
+    let state = config::load_state().unwrap_or_else(|_| config::default_state());
+    doctl::set_context(state.settings.doctl_context.clone());
+    let mut droplets = doctl::list_droplets()?;
+    if let Some(warning) = doctl::truncation_warning("droplets", droplets.len()) {
+        eprintln!("warning: {warning}");
+    }
+    if running {
+        droplets.retain(Droplet::is_running);
+    }
+    if json {
+        println!("{}", serde_json::to_string_pretty(&droplets)?);
+    } else {
+        print!("{}", droplet_table(&droplets));
+    }
+    Ok(())
+}
+
+fn droplet_table(droplets: &[Droplet]) -> String {
+    let header = [
+        "NAME",
+        "STATUS",
+        "REGION",
+        "SIZE",
+        "PUBLIC IP",
+        "PRIVATE IP",
+    ];
+    let rows: Vec<[String; 6]> = droplets
+        .iter()
+        .map(|droplet| {
+            [
+                droplet.name.clone(),
+                droplet.status.clone(),
+                droplet.region.clone(),
+                droplet.size.clone().unwrap_or_else(|| "-".to_string()),
+                droplet.public_ip().unwrap_or("-").to_string(),
+                droplet
+                    .private_ipv4
+                    .clone()
+                    .unwrap_or_else(|| "-".to_string()),
+            ]
+        })
+        .collect();
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    let format_row = |cells: &[&str]| {
+        let line = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        format!("{}\n", line.trim_end())
+    };
+    let mut out = format_row(&header);
+    for row in &rows {
+        out.push_str(&format_row(&row.each_ref().map(String::as_str)));
+    }
+    out
+}
+
+/// Runs the requested restores, printing one line per item. Returns whether all succeeded.
+pub fn run(restore_tunnels: bool, restore_syncs: bool) -> Result<bool> {
+    let mut ok = true;
+    if restore_tunnels {
+        ok &= run_restore_tunnels()?;
+    }
+    if restore_syncs {
+        ok &= run_restore_syncs()?;
+    }
+    Ok(ok)
+}
+
+enum TunnelOutcome {
+    AlreadyRunning,
+    Started(u32),
+    Failed(String),
+}
+
+/// Starts a saved binding's tunnel unless it is already running.
+fn restore_tunnel(binding: &mut PortBinding) -> TunnelOutcome {
+    if binding.tunnel_pid.is_some_and(ports::is_pid_running) {
+        return TunnelOutcome::AlreadyRunning;
+    }
+    binding.tunnel_pid = None;
+    if !ports::is_port_available(&binding.bind_address, binding.local_port) {
+        return TunnelOutcome::Failed("local port is in use".to_string());
+    }
+    match ports::start_tunnel(binding) {
+        Ok(pid) => TunnelOutcome::Started(pid),
+        Err(err) => TunnelOutcome::Failed(err.to_string().trim().to_string()),
+    }
+}
+
+fn tunnel_label(binding: &PortBinding) -> String {
+    format!(
+        "{}:{} -> {}:{}",
+        binding.bind_address, binding.local_port, binding.droplet_name, binding.remote_port
+    )
+}
+
+fn run_restore_tunnels() -> Result<bool> {
+    let mut state = config::load_state()?;
+    ports::set_tunnel_start_timeout(state.settings.tunnel_start_timeout_ms);
+    let (mut started, mut running, mut failed) = (0usize, 0usize, 0usize);
+    for binding in &mut state.bindings {
+        let label = tunnel_label(binding);
+        match restore_tunnel(binding) {
+            TunnelOutcome::AlreadyRunning => {
+                println!("tunnel {label}: already running");
+                running += 1;
+            }
+            TunnelOutcome::Started(pid) => {
+                println!("tunnel {label}: started (pid {pid})");
+                started += 1;
+            }
+            TunnelOutcome::Failed(err) => {
+                println!("tunnel {label}: FAILED, {err}");
+                failed += 1;
+            }
+        }
+    }
+    config::save_state(&state)?;
+    println!("tunnels: {started} started, {running} already running, {failed} failed");
+    Ok(failed == 0)
+}
+
+fn run_restore_syncs() -> Result<bool> {
+    let hosts = sync_hosts(&config::load_state()?);
+    if hosts.is_empty() {
+        println!("syncs: no saved hosts to restore from");
+        return Ok(true);
+    }
+    let (mut restored, mut failed) = (0usize, 0usize);
+    for (_, ssh) in &hosts {
+        match mutagen::restore_syncs(ssh) {
+            Ok(outcome) => {
+                println!(
+                    "syncs {}@{}: restored {}{}",
+                    ssh.user,
+                    ssh.host,
+                    outcome.restored,
+                    if outcome.skipped_lines.is_empty() {
+                        String::new()
+                    } else {
+                        format!(
+                            " ({} malformed mountlist line(s) skipped)",
+                            outcome.skipped_lines.len()
+                        )
+                    }
+                );
+                restored += outcome.restored;
+            }
+            Err(err) => {
+                println!("syncs {}@{}: FAILED, {err}", ssh.user, ssh.host);
+                failed += 1;
+            }
+        }
+    }
+    println!(
+        "syncs: {restored} restored across {} host(s), {failed} host(s) failed",
+        hosts.len()
+    );
+    Ok(failed == 0)
+}
+
+/// One droplet name and SSH config per distinct host among saved port bindings and
+/// RSYNC binds.
+fn sync_hosts(state: &AppStateFile) -> Vec<(String, SshConfig)> {
+    let bindings = state.bindings.iter().map(|binding| {
+        let ssh = SshConfig {
+            user: binding.ssh_user.clone(),
+            host: binding.public_ip.clone(),
+            port: binding.ssh_port,
+            key_path: binding.ssh_key_path.clone(),
+            use_agent: binding.use_agent,
+        };
+        (binding.droplet_name.clone(), ssh)
+    });
+    let rsync_binds = state.rsync_binds.iter().map(|bind| {
+        let ssh = SshConfig {
+            user: bind.ssh_user.clone(),
+            host: bind.host.clone(),
+            port: bind.ssh_port,
+            key_path: bind.ssh_key_path.clone(),
+            use_agent: bind.use_agent,
+        };
+        (bind.droplet_name.clone(), ssh)
+    });
+    let mut seen = HashSet::new();
+    bindings
+        .chain(rsync_binds)
+        .filter(|(_, ssh)| seen.insert(ssh.host.clone()))
+        .collect()
+}
+
+/// Runs a `workspace` subcommand, printing one line per item. Returns whether all succeeded.
+pub fn run_workspace(command: WorkspaceCommand) -> Result<bool> {
+    match command {
+        WorkspaceCommand::List => {
+            let state = config::load_state()?;
+            if state.workspaces.is_empty() {
+                println!("no saved workspaces");
+            }
+            for workspace in &state.workspaces {
+                println!(
+                    "{}: {} tunnel(s), {} sync(s)",
+                    workspace.name,
+                    workspace.bindings.len(),
+                    workspace.syncs.len()
+                );
+            }
+            Ok(true)
+        }
+        _ if tui_running() => Err(anyhow!(
+            "The TUI is running and would overwrite the change; use its Bindings screen \
+             (w/W) or quit it first"
+        )),
+        WorkspaceCommand::Save(name) => save_workspace(name),
+        WorkspaceCommand::Activate(name) => activate_workspace(&name),
+        WorkspaceCommand::Deactivate(name) => deactivate_workspace(&name),
+        WorkspaceCommand::Delete(name) => {
+            let mut state = config::load_state()?;
+            find_workspace(&state, &name)?;
+            state.workspaces.retain(|workspace| workspace.name != name);
+            config::save_state(&state)?;
+            println!("workspace {name}: deleted");
+            Ok(true)
+        }
+    }
+}
+
+/// A TUI keeps its own copy of the state file and writes all of it back, so changes made
+/// here while it runs would be lost.
+fn tui_running() -> bool {
+    config::tui_pid().is_some_and(|pid| pid != std::process::id() && ports::is_pid_running(pid))
+}
+
+fn find_workspace(state: &AppStateFile, name: &str) -> Result<Workspace> {
+    state
+        .workspaces
+        .iter()
+        .find(|workspace| workspace.name == name)
+        .cloned()
+        .ok_or_else(|| anyhow!("No workspace named '{name}'"))
+}
+
+/// Records the running tunnels, and the running syncs on every saved host, as `name`.
+/// Hosts that can't be reached (a destroyed droplet still referenced by an old binding, say)
+/// are reported and skipped.
+fn save_workspace(name: String) -> Result<bool> {
+    let mut state = config::load_state()?;
+    let bindings: Vec<PortBinding> = state
+        .bindings
+        .iter()
+        .filter(|binding| binding.tunnel_pid.is_some_and(ports::is_pid_running))
+        .map(|binding| PortBinding {
+            tunnel_pid: None,
+            ..binding.clone()
+        })
+        .collect();
+    let mut syncs = Vec::new();
+    let mut skipped = 0usize;
+    for (droplet_name, ssh) in sync_hosts(&state) {
+        let paths = match mutagen::running_sync_paths(&ssh) {
+            Ok(paths) => paths,
+            Err(err) => {
+                println!("syncs {}@{}: skipped, {err:#}", ssh.user, ssh.host);
+                skipped += 1;
+                continue;
+            }
+        };
+        syncs.extend(paths.into_iter().map(|path| WorkspaceSync {
+            droplet_name: droplet_name.clone(),
+            ssh_user: ssh.user.clone(),
+            host: ssh.host.clone(),
+            ssh_port: ssh.port,
+            ssh_key_path: ssh.key_path.clone(),
+            use_agent: ssh.use_agent,
+            local: path.local,
+            remote: path.remote,
+        }));
+    }
+    if bindings.is_empty() && syncs.is_empty() {
+        return Err(anyhow!("No running tunnels or syncs to save as '{name}'"));
+    }
+    println!(
+        "workspace {name}: saved {} tunnel(s) and {} sync(s)",
+        bindings.len(),
+        syncs.len()
+    );
+    let workspace = Workspace {
+        name,
+        bindings,
+        syncs,
+    };
+    match state
+        .workspaces
+        .iter_mut()
+        .find(|existing| existing.name == workspace.name)
+    {
+        Some(existing) => *existing = workspace,
+        None => state.workspaces.push(workspace),
+    }
+    config::save_state(&state)?;
+    Ok(skipped == 0)
+}
+
+fn activate_workspace(name: &str) -> Result<bool> {
+    let mut state = config::load_state()?;
+    let workspace = find_workspace(&state, name)?;
+    ports::set_tunnel_start_timeout(state.settings.tunnel_start_timeout_ms);
+    let mut ok = true;
+    for recorded in &workspace.bindings {
+        let label = tunnel_label(recorded);
+        let index = match ports::place_recorded_binding(&mut state.bindings, recorded) {
+            Ok(index) => index,
+            Err(index) => {
+                println!(
+                    "tunnel {label}: FAILED, local port is in use by {}",
+                    tunnel_label(&state.bindings[index])
+                );
+                ok = false;
+                continue;
+            }
+        };
+        match restore_tunnel(&mut state.bindings[index]) {
+            TunnelOutcome::AlreadyRunning => println!("tunnel {label}: already running"),
+            TunnelOutcome::Started(pid) => println!("tunnel {label}: started (pid {pid})"),
+            TunnelOutcome::Failed(err) => {
+                println!("tunnel {label}: FAILED, {err}");
+                ok = false;
+            }
+        }
+    }
+    config::save_state(&state)?;
+    for (line, synced) in mutagen::activate_workspace_syncs(&workspace.syncs) {
+        println!("{line}");
+        ok &= synced;
+    }
+    Ok(ok)
+}
+
+/// Stops and unbinds the workspace's tunnels and pauses its syncs.
+fn deactivate_workspace(name: &str) -> Result<bool> {
+    let mut state = config::load_state()?;
+    let workspace = find_workspace(&state, name)?;
+    let mut ok = true;
+    for recorded in &workspace.bindings {
+        let label = tunnel_label(recorded);
+        let Some(index) = state
+            .bindings
+            .iter()
+            .position(|binding| ports::same_forward(binding, recorded))
+        else {
+            println!("tunnel {label}: not bound");
+            continue;
+        };
+        match state.bindings[index]
+            .tunnel_pid
+            .filter(|pid| ports::is_pid_running(*pid))
+        {
+            Some(pid) => match ports::stop_tunnel(pid) {
+                Ok(()) => println!("tunnel {label}: stopped"),
+                Err(err) => {
+                    println!("tunnel {label}: FAILED, {err}");
+                    ok = false;
+                    continue;
+                }
+            },
+            None => println!("tunnel {label}: not running"),
+        }
+        state.bindings.remove(index);
+    }
+    config::save_state(&state)?;
+    for (line, paused) in mutagen::pause_workspace_syncs(&workspace.syncs) {
+        println!("{line}");
+        ok &= paused;
+    }
+    Ok(ok)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_args_picks_mode() {
+        assert_eq!(parse_args(args(&[])).unwrap(), Mode::Tui);
+        assert_eq!(parse_args(args(&["--help"])).unwrap(), Mode::Help);
+        assert_eq!(
+            parse_args(args(&["--restore-syncs", "--restore-tunnels"])).unwrap(),
+            Mode::Headless {
+                restore_tunnels: true,
+                restore_syncs: true,
+            }
+        );
+        assert!(parse_args(args(&["--restore"])).is_err());
+        assert_eq!(
+            parse_args(args(&["list", "--running"])).unwrap(),
+            Mode::List {
+                json: false,
+                running: true,
+            }
+        );
+        assert!(parse_args(args(&["list", "--restore-tunnels"])).is_err());
+    }
+
+    #[test]
+    fn take_verbose_strips_flag_anywhere() {
+        assert_eq!(
+            take_verbose(args(&["list", "-v", "--json"])),
+            (true, args(&["list", "--json"]))
+        );
+        assert_eq!(take_verbose(args(&["list"])), (false, args(&["list"])));
+    }
+
+    #[test]
+    fn droplet_table_aligns_columns() {
+        let droplet = Droplet {
+            id: 1,
+            name: "web-01".to_string(),
+            status: "active".to_string(),
+            region: "nyc1".to_string(),
+            public_ipv4: Some("203.0.113.10".to_string()),
+            ..Default::default()
+        };
+        let table = droplet_table(&[droplet]);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            lines[0],
+            "NAME    STATUS  REGION  SIZE  PUBLIC IP     PRIVATE IP"
+        );
+        assert_eq!(lines[1], "web-01  active  nyc1    -     203.0.113.10  -");
+    }
+
+    #[test]
+    fn sync_hosts_dedupes_by_host() {
+        let mut state = config::default_state();
+        state.bindings.push(ports::new_binding(
+            1,
+            "web".to_string(),
+            "203.0.113.10".to_string(),
+            8080,
+            80,
+            "root".to_string(),
+            "/tmp/id_rsa".to_string(),
+            22,
+            Default::default(),
+            "127.0.0.1".to_string(),
+        ));
+        state.bindings.push(ports::new_binding(
+            1,
+            "web".to_string(),
+            "203.0.113.10".to_string(),
+            5432,
+            5432,
+            "root".to_string(),
+            "/tmp/id_rsa".to_string(),
+            22,
+            Default::default(),
+            "127.0.0.1".to_string(),
+        ));
+        let hosts = sync_hosts(&state);
+        assert_eq!(hosts.len(), 1);
+        assert_eq!(hosts[0].0, "web");
+        assert_eq!(hosts[0].1.host, "203.0.113.10");
+    }
+
+    #[test]
+    fn parse_workspace_args_needs_one_name() {
+        assert_eq!(
+            parse_args(args(&["workspace", "activate", "clientA dev"])).unwrap(),
+            Mode::Workspace(WorkspaceCommand::Activate("clientA dev".to_string()))
+        );
+        assert_eq!(
+            parse_args(args(&["workspace", "list"])).unwrap(),
+            Mode::Workspace(WorkspaceCommand::List)
+        );
+        assert!(parse_args(args(&["workspace", "save"])).is_err());
+        assert!(parse_args(args(&["workspace", "save", "a", "b"])).is_err());
+        assert!(parse_args(args(&["workspace", "start", "a"])).is_err());
+        assert!(parse_args(args(&["workspace"])).is_err());
+    }
+}
@@ -0,0 +1,264 @@
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Child, Command, ExitStatus, Output};
+use std::sync::{Mutex, OnceLock};
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+
+use crate::config;
+
+/// Turns on the debug log without `--verbose`, e.g. `DOCTL_TUI_LOG=1`.
+pub const LOG_ENV_VAR: &str = "DOCTL_TUI_LOG";
+
+static LOG_FILE: OnceLock<Mutex<File>> = OnceLock::new();
+
+/// `debug.log`, next to the state file.
+pub fn log_file_path() -> Result<PathBuf> {
+    Ok(config::state_file_path()?.with_file_name("debug.log"))
+}
+
+pub fn env_enabled() -> bool {
+    std::env::var(LOG_ENV_VAR).is_ok_and(|value| !value.is_empty() && value != "0")
+}
+
+/// Opens the log file for appending. Until this succeeds every `log` call is a no-op;
+/// nothing is ever written to the terminal.
+pub fn init() -> Result<PathBuf> {
+    let path = log_file_path()?;
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Failed to open log file {}", path.display()))?;
+    let _ = LOG_FILE.set(Mutex::new(file));
+    log(format!("logging started (pid {})", std::process::id()));
+    Ok(path)
+}
+
+pub fn enabled() -> bool {
+    LOG_FILE.get().is_some()
+}
+
+pub fn log(message: impl AsRef<str>) {
+    let Some(file) = LOG_FILE.get() else {
+        return;
+    };
+    if let Ok(mut file) = file.lock() {
+        let _ = writeln!(
+            file,
+            "{} {}",
+            Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ"),
+            redact(message.as_ref())
+        );
+    }
+}
+
+/// Masks the home directory and login names in text meant for screens or bug reports,
+/// e.g. `/home/jane/.ssh/id_ed25519` becomes `~/.ssh/id_ed25519` and `jane@host`
+/// becomes `<user>@host`. `root` is left alone since it identifies nobody.
+pub fn redact(text: &str) -> String {
+    let home = std::env::var("HOME").ok();
+    redact_with_home(text, home.as_deref())
+}
+
+fn redact_with_home(text: &str, home: Option<&str>) -> String {
+    let mut out = text.to_string();
+    if let Some(home) = home
+        .map(|home| home.trim_end_matches('/'))
+        .filter(|home| !home.is_empty())
+    {
+        out = replace_home(&out, home);
+    }
+    for root in ["/home/", "/Users/"] {
+        out = mask_user_dirs(&out, root);
+    }
+    mask_login_names(&out)
+}
+
+/// Swaps `home` for `~` only where the path ends or continues with `/`, so a sibling
+/// like `/home/janet` is left for `mask_user_dirs` rather than becoming `~t`.
+fn replace_home(text: &str, home: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(pos) = rest.find(home) {
+        out.push_str(&rest[..pos]);
+        let after = &rest[pos + home.len()..];
+        let at_boundary = after
+            .chars()
+            .next()
+            .is_none_or(|c| !(c.is_alphanumeric() || matches!(c, '.' | '_' | '-')));
+        out.push_str(if at_boundary { "~" } else { home });
+        rest = after;
+    }
+    out.push_str(rest);
+    out
+}
+
+/// `/home/<name>/...` -> `~/...` for homes other than the current one.
+fn mask_user_dirs(text: &str, root: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(pos) = rest.find(root) {
+        out.push_str(&rest[..pos]);
+        let after = &rest[pos + root.len()..];
+        let name_len = after.find('/').unwrap_or(after.len());
+        if name_len == 0 || after[..name_len].contains(char::is_whitespace) {
+            out.push_str(root);
+            rest = after;
+            continue;
+        }
+        out.push('~');
+        rest = &after[name_len..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn mask_login_names(text: &str) -> String {
+    let is_name_char = |ch: char| ch.is_ascii_alphanumeric() || "._-".contains(ch);
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(at) = rest.find('@') {
+        let before = &rest[..at];
+        let name_start = before
+            .char_indices()
+            .rev()
+            .take_while(|(_, ch)| is_name_char(*ch))
+            .last()
+            .map_or(at, |(idx, _)| idx);
+        let name = &before[name_start..];
+        let host_follows = rest[at + 1..].starts_with(|ch: char| ch.is_ascii_alphanumeric());
+        out.push_str(&before[..name_start]);
+        if !name.is_empty() && name != "root" && host_follows {
+            out.push_str("<user>");
+        } else {
+            out.push_str(name);
+        }
+        out.push('@');
+        rest = &rest[at + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Records a subprocess about to run, with secret-bearing arguments redacted.
+pub fn command(cmd: &Command) {
+    if !enabled() {
+        return;
+    }
+    let args: Vec<String> = cmd
+        .get_args()
+        .map(|arg| arg.to_string_lossy().into_owned())
+        .collect();
+    log(format!(
+        "exec: {} {}",
+        cmd.get_program().to_string_lossy(),
+        redact_args(&args).join(" ")
+    ));
+}
+
+/// `Command::output`/`spawn`/`status` that trace the command line to the debug log first.
+pub trait LoggedCommand {
+    fn logged_output(&mut self) -> std::io::Result<Output>;
+    fn logged_spawn(&mut self) -> std::io::Result<Child>;
+    fn logged_status(&mut self) -> std::io::Result<ExitStatus>;
+}
+
+impl LoggedCommand for Command {
+    fn logged_output(&mut self) -> std::io::Result<Output> {
+        command(self);
+        self.output()
+    }
+
+    fn logged_spawn(&mut self) -> std::io::Result<Child> {
+        command(self);
+        self.spawn()
+    }
+
+    fn logged_status(&mut self) -> std::io::Result<ExitStatus> {
+        command(self);
+        self.status()
+    }
+}
+
+/// Flags whose value is a key path or token and must not land in the log.
+const SECRET_FLAGS: &[&str] = &["-i", "--access-token"];
+
+fn redact_args(args: &[String]) -> Vec<String> {
+    let mut redacted = Vec::with_capacity(args.len());
+    let mut hide_next = false;
+    for arg in args {
+        if hide_next {
+            redacted.push("<redacted>".to_string());
+            hide_next = false;
+            continue;
+        }
+        if let Some((flag, _)) = arg.split_once('=')
+            && SECRET_FLAGS.contains(&flag)
+        {
+            redacted.push(format!("{flag}=<redacted>"));
+            continue;
+        }
+        hide_next = SECRET_FLAGS.contains(&arg.as_str());
+        redacted.push(arg.clone());
+    }
+    redacted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn redact_masks_home_and_login_names() {
+        assert_eq!(
+            redact_with_home(
+                "ssh jane@203.0.113.10 -i /home/jane/.ssh/id_ed25519 failed",
+                Some("/home/jane")
+            ),
+            "ssh <user>@203.0.113.10 -i ~/.ssh/id_ed25519 failed"
+        );
+        assert_eq!(
+            redact_with_home("Permission denied for root@web: /Users/bob/keys/ci", None),
+            "Permission denied for root@web: ~/keys/ci"
+        );
+    }
+
+    #[test]
+    fn redact_leaves_sibling_home_prefixes_alone() {
+        assert_eq!(
+            redact_with_home("/srv/jane /srv/janet/notes /srv/jane,", Some("/srv/jane")),
+            "~ /srv/janet/notes ~,"
+        );
+        assert_eq!(
+            redact_with_home("cp /home/janet/a /home/jane/b", Some("/home/jane")),
+            "cp ~/a ~/b"
+        );
+    }
+
+    #[test]
+    fn redact_args_hides_key_paths_and_tokens() {
+        assert_eq!(
+            redact_args(&args(&[
+                "-N",
+                "-i",
+                "/home/me/.ssh/id_ed25519",
+                "--access-token=dop_v1_abc",
+                "root@203.0.113.10",
+            ])),
+            args(&[
+                "-N",
+                "-i",
+                "<redacted>",
+                "--access-token=<redacted>",
+                "root@203.0.113.10",
+            ])
+        );
+    }
+}
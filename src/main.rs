@@ -1,12 +1,17 @@
 mod app;
+mod cancel;
 mod config;
 mod doctl;
+mod headless;
 mod input;
+mod logging;
 mod model;
 mod mutagen;
 mod ports;
 mod tasks;
+mod tools;
 mod ui;
+mod wake;
 
 use std::time::{Duration, Instant};
 
@@ -16,9 +21,40 @@ use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
 use crate::app::App;
 
 fn main() -> anyhow::Result<()> {
+    let (verbose, args) = headless::take_verbose(std::env::args().skip(1));
+    if verbose || logging::env_enabled() {
+        let path = logging::init()?;
+        eprintln!("Logging to {}", path.display());
+    }
+
+    match headless::parse_args(args)? {
+        headless::Mode::Tui => {}
+        headless::Mode::Help => {
+            println!("{}", headless::USAGE);
+            return Ok(());
+        }
+        headless::Mode::Headless {
+            restore_tunnels,
+            restore_syncs,
+        } => {
+            let ok = headless::run(restore_tunnels, restore_syncs)?;
+            std::process::exit(if ok { 0 } else { 1 });
+        }
+        headless::Mode::List { json, running } => return headless::run_list(json, running),
+        headless::Mode::Workspace(command) => {
+            let ok = headless::run_workspace(command)?;
+            std::process::exit(if ok { 0 } else { 1 });
+        }
+    }
+
     let (tx, rx) = unbounded();
     let mut app = App::new(tx.clone());
     app.bootstrap();
+    if let Err(err) = config::write_tui_pid() {
+        logging::log(format!("{err:#}"));
+    }
+
+    let theme = ui::Theme::from_config(&app.state.theme);
 
     let mut terminal = ui::setup_terminal()?;
     let tick_rate = Duration::from_millis(120);
@@ -30,15 +66,15 @@ fn main() -> anyhow::Result<()> {
             terminal = ui::setup_terminal()?;
         }
 
-        terminal.draw(|f| ui::draw(f, &app))?;
+        terminal.draw(|f| ui::draw(f, &app, &theme))?;
 
         let timeout = tick_rate
             .checked_sub(last_tick.elapsed())
             .unwrap_or_else(|| Duration::from_secs(0));
 
         if event::poll(timeout)? {
-            if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Press {
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
                     if key.code == KeyCode::Char('c')
                         && key.modifiers.contains(KeyModifiers::CONTROL)
                     {
@@ -47,6 +83,8 @@ fn main() -> anyhow::Result<()> {
                         app.handle_key(key);
                     }
                 }
+                Event::Mouse(mouse) => app.handle_mouse(mouse),
+                _ => {}
             }
         }
 
@@ -55,6 +93,7 @@ fn main() -> anyhow::Result<()> {
         }
 
         if last_tick.elapsed() >= tick_rate {
+            app.tick();
             last_tick = Instant::now();
         }
 
@@ -64,6 +103,7 @@ fn main() -> anyhow::Result<()> {
     }
 
     app.shutdown();
+    config::remove_tui_pid();
     ui::restore_terminal(terminal)?;
     Ok(())
 }
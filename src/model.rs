@@ -1,7 +1,9 @@
+use std::collections::{HashMap, HashSet};
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Droplet {
     pub id: u64,
     pub name: String,
@@ -9,15 +11,75 @@ pub struct Droplet {
     pub region: String,
     pub size: Option<String>,
     pub public_ipv4: Option<String>,
+    #[serde(default)]
+    pub public_ipv6: Option<String>,
     pub private_ipv4: Option<String>,
     pub created_at: Option<String>,
     pub tags: Vec<String>,
+    /// Slug of the image it was built from; `None` for custom images, snapshots, and
+    /// images DigitalOcean has since retired.
+    #[serde(default)]
+    pub image: Option<String>,
+    /// Enabled features as doctl names them (`monitoring`, `ipv6`, `backups`, ...); `None`
+    /// when doctl didn't report them.
+    #[serde(default)]
+    pub features: Option<Vec<String>>,
+    #[serde(default)]
+    pub vcpus: Option<u32>,
+    #[serde(default)]
+    pub memory_mb: Option<u64>,
+    #[serde(default)]
+    pub disk_gb: Option<u64>,
 }
 
 impl Droplet {
     pub fn is_running(&self) -> bool {
         self.status == "active"
     }
+
+    /// `None` when doctl did not report the droplet's feature list.
+    pub fn backups_enabled(&self) -> Option<bool> {
+        self.features
+            .as_ref()
+            .map(|features| features.iter().any(|feature| feature == "backups"))
+    }
+
+    /// Active with a public IPv4, i.e. past the window where `create --wait` can leave it.
+    pub fn has_network(&self) -> bool {
+        self.is_running() && self.public_ipv4.is_some()
+    }
+
+    /// Public address to reach the droplet on, preferring IPv4 over IPv6.
+    pub fn public_ip(&self) -> Option<&str> {
+        self.public_ipv4.as_deref().or(self.public_ipv6.as_deref())
+    }
+
+    /// Address to reach the droplet on per the `preferred_ip` setting. Preferring private
+    /// falls back to the public address for droplets without a VPC interface.
+    pub fn preferred_ip(&self, preferred: BindInterface) -> Option<&str> {
+        match preferred {
+            BindInterface::Public => self.public_ip(),
+            BindInterface::Private => self.private_ipv4.as_deref().or(self.public_ip()),
+        }
+    }
+}
+
+/// Wraps IPv6 literals in brackets for `host:path` style targets (rsync, mutagen).
+pub fn host_for_remote_spec(host: &str) -> String {
+    if host.contains(':') && !host.starts_with('[') {
+        format!("[{host}]")
+    } else {
+        host.to_string()
+    }
+}
+
+/// `-i <key>` for ssh, or nothing when the identity comes from `ssh-agent`.
+pub fn ssh_identity_args(key_path: &str, use_agent: bool) -> Vec<String> {
+    if use_agent {
+        Vec::new()
+    } else {
+        vec!["-i".to_string(), key_path.to_string()]
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -62,30 +124,261 @@ pub struct SshKey {
     pub fingerprint: String,
 }
 
+/// A record from DigitalOcean DNS, tagged with the domain it belongs to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DomainRecord {
+    pub domain: String,
+    /// Record name relative to the domain; `@` is the apex.
+    pub name: String,
+    pub kind: String,
+    pub data: String,
+}
+
+impl DomainRecord {
+    pub fn hostname(&self) -> String {
+        if self.name == "@" || self.name.is_empty() {
+            self.domain.clone()
+        } else {
+            format!("{}.{}", self.name, self.domain)
+        }
+    }
+}
+
+/// Hostnames whose A/AAAA records point at `ip`, sorted and deduplicated.
+pub fn hostnames_for_ip(records: &[DomainRecord], ip: &str) -> Vec<String> {
+    let mut names: Vec<String> = records
+        .iter()
+        .filter(|record| matches!(record.kind.as_str(), "A" | "AAAA"))
+        .filter(|record| record.data.eq_ignore_ascii_case(ip))
+        .map(DomainRecord::hostname)
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DoctlContext {
+    pub name: String,
+    pub current: bool,
+}
+
+/// The DigitalOcean account doctl is authenticated against, and through which context.
+#[derive(Debug, Clone)]
+pub struct AccountInfo {
+    pub email: String,
+    pub context: String,
+    pub status: String,
+    pub status_message: String,
+    /// `DIGITALOCEAN_ACCESS_TOKEN` was set, so doctl ignored the context's own token.
+    pub token_from_env: bool,
+}
+
+impl AccountInfo {
+    /// Set when the account is not active or the context's token was overridden.
+    pub fn warning(&self) -> Option<String> {
+        if self.token_from_env {
+            return Some("DIGITALOCEAN_ACCESS_TOKEN overrides the context token".to_string());
+        }
+        if self.status.is_empty() || self.status == "active" {
+            return None;
+        }
+        let message = self.status_message.trim();
+        if message.is_empty() {
+            Some(format!("account {}", self.status))
+        } else {
+            Some(format!("account {}: {message}", self.status))
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PortBinding {
     pub droplet_id: u64,
     pub droplet_name: String,
+    /// SSH host for the tunnel; the private address when `interface` is `Private`.
     pub public_ip: String,
     pub local_port: u16,
     pub remote_port: u16,
     pub ssh_user: String,
     pub ssh_key_path: String,
+    /// Authenticate with `ssh-agent` instead of passing `ssh_key_path` as `-i`.
+    #[serde(default)]
+    pub use_agent: bool,
     pub ssh_port: u16,
     pub created_at: DateTime<Utc>,
     pub tunnel_pid: Option<u32>,
+    #[serde(default)]
+    pub interface: BindInterface,
+    /// Local address the forward listens on; anything but loopback exposes it to the network.
+    #[serde(default = "default_bind_address")]
+    pub bind_address: String,
+    /// Free-form note such as "admin panel", shown next to the ports.
+    #[serde(default)]
+    pub label: Option<String>,
+}
+
+pub fn default_bind_address() -> String {
+    "127.0.0.1".to_string()
+}
+
+/// Whether a local bind address keeps a forwarded port reachable only from this machine.
+pub fn is_loopback_address(address: &str) -> bool {
+    address.eq_ignore_ascii_case("localhost")
+        || address
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback())
+}
+
+/// Which droplet address a port binding tunnels through, or the `preferred_ip` setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum BindInterface {
+    #[default]
+    Public,
+    Private,
+}
+
+impl BindInterface {
+    pub fn label(self) -> &'static str {
+        match self {
+            BindInterface::Public => "public",
+            BindInterface::Private => "private",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RsyncBind {
     pub droplet_name: String,
+    /// Droplet the bind was made against, used to follow it to a new IP; `None` for binds
+    /// typed in by hand for an unknown host and for binds saved before this was recorded.
+    #[serde(default)]
+    pub droplet_id: Option<u64>,
     pub ssh_user: String,
+    /// Address last used to reach the droplet; the fallback when it isn't in the list.
     pub host: String,
     pub ssh_port: u16,
     pub ssh_key_path: String,
+    /// Authenticate with `ssh-agent` instead of passing `ssh_key_path` as `-i`.
+    #[serde(default)]
+    pub use_agent: bool,
     pub remote_path: String,
     pub local_path: String,
     pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub last_synced_at: Option<DateTime<Utc>>,
+    /// "pushed: <stats summary>"/"pulled: ..." after a successful run, otherwise "push failed: ..." or "pull failed: ...".
+    #[serde(default)]
+    pub last_result: Option<String>,
+    #[serde(default)]
+    pub rsync_profile: RsyncProfile,
+    #[serde(default = "default_true")]
+    pub compress: bool,
+    #[serde(default)]
+    pub checksum: bool,
+    /// Binds sharing a group name are pushed or pulled together, one after another.
+    #[serde(default)]
+    pub group: Option<String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// How rsync treats files that exist on only one side of a bind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum RsyncProfile {
+    #[default]
+    Merge,
+    Mirror,
+    Archive,
+}
+
+impl RsyncProfile {
+    pub fn flags(self) -> &'static [&'static str] {
+        match self {
+            RsyncProfile::Merge => &["-a"],
+            RsyncProfile::Mirror => &["-a", "--delete"],
+            RsyncProfile::Archive => &["-aH", "--numeric-ids"],
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            RsyncProfile::Merge => "Merge",
+            RsyncProfile::Mirror => "Mirror",
+            RsyncProfile::Archive => "Archive",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            RsyncProfile::Merge => RsyncProfile::Mirror,
+            RsyncProfile::Mirror => RsyncProfile::Archive,
+            RsyncProfile::Archive => RsyncProfile::Merge,
+        }
+    }
+}
+
+impl RsyncBind {
+    /// rsync flags from the bind's profile plus its compression and checksum toggles.
+    pub fn rsync_flags(&self) -> Vec<&'static str> {
+        let mut flags = self.rsync_profile.flags().to_vec();
+        if self.compress {
+            flags.push("-z");
+        }
+        if self.checksum {
+            flags.push("--checksum");
+        }
+        flags
+    }
+
+    pub fn last_run_failed(&self) -> bool {
+        self.last_result
+            .as_deref()
+            .is_some_and(|result| result.contains(" failed"))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedSelection {
+    pub label: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CreateDefaults {
+    pub region: Option<SavedSelection>,
+    pub size: Option<SavedSelection>,
+    pub image: Option<SavedSelection>,
+    #[serde(default)]
+    pub ssh_keys: Vec<SavedSelection>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ConnectMethod {
+    #[default]
+    Doctl,
+    Ssh,
+}
+
+impl ConnectMethod {
+    pub fn other(self) -> Self {
+        match self {
+            ConnectMethod::Doctl => ConnectMethod::Ssh,
+            ConnectMethod::Ssh => ConnectMethod::Doctl,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ConnectMethod::Doctl => "doctl ssh",
+            ConnectMethod::Ssh => "ssh",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -93,12 +386,153 @@ pub struct Settings {
     pub default_ssh_user: String,
     pub default_ssh_key_path: String,
     pub default_ssh_port: u16,
+    #[serde(default)]
+    pub last_create: Option<CreateDefaults>,
+    #[serde(default)]
+    pub name_template: Option<String>,
+    #[serde(default)]
+    pub connect_method: ConnectMethod,
+    #[serde(default)]
+    pub tmux_new_window: Option<bool>,
+    #[serde(default)]
+    pub doctl_context: Option<String>,
+    /// How long toasts stay visible; unset keeps the 6 second default.
+    #[serde(default)]
+    pub toast_ttl_secs: Option<u64>,
+    #[serde(default)]
+    pub toast_ttl_overrides: ToastTtlOverrides,
+    /// Hide the loading overlay until work has been pending this long.
+    #[serde(default)]
+    pub overlay_delay_ms: u64,
+    /// Restore the last screen and list filters on launch.
+    #[serde(default)]
+    pub remember_view: bool,
+    #[serde(default)]
+    pub last_view: Option<SavedView>,
+    /// Project new droplets are created in; unset leaves them in the account's default project.
+    #[serde(default)]
+    pub project_id: Option<String>,
+    /// Droplet IDs pinned to the top of the Home list.
+    #[serde(default)]
+    pub favorites: HashSet<u64>,
+    /// Start new tunnels without first checking that an SSH login succeeds.
+    #[serde(default)]
+    pub skip_bind_ssh_check: bool,
+    /// How long a new tunnel may take to start listening; unset keeps the 1 second default.
+    #[serde(default)]
+    pub tunnel_start_timeout_ms: Option<u64>,
+    /// Address that prefills SSH forms and leads the details pane.
+    #[serde(default)]
+    pub preferred_ip: BindInterface,
+    /// Reconnect dead tunnels when the machine wakes from sleep.
+    #[serde(default)]
+    pub reconnect_on_resume: bool,
+    /// Region slugs the region pickers offer; empty offers every region.
+    #[serde(default)]
+    pub allowed_regions: Vec<String>,
+    /// Size slugs the size pickers offer; empty offers every size.
+    #[serde(default)]
+    pub allowed_sizes: Vec<String>,
+    /// Look up DigitalOcean DNS records pointing at the selected droplet (extra API calls).
+    #[serde(default)]
+    pub show_dns_records: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SavedScreen {
+    #[default]
+    Home,
+    Bindings,
+    Syncs,
+    RsyncBinds,
+}
+
+/// The view state persisted when `remember_view` is on.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SavedView {
+    #[serde(default)]
+    pub screen: SavedScreen,
+    #[serde(default)]
+    pub filter_running: bool,
+    #[serde(default)]
+    pub binding_sort_by_port: bool,
+}
+
+/// Per-level toast lifetimes that take precedence over `toast_ttl_secs`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ToastTtlOverrides {
+    #[serde(default)]
+    pub info: Option<u64>,
+    #[serde(default)]
+    pub success: Option<u64>,
+    #[serde(default)]
+    pub warning: Option<u64>,
+    #[serde(default)]
+    pub error: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct AppStateFile {
+    /// Schema version; files without one are version 0 and get migrated on load.
+    #[serde(default)]
+    pub version: u32,
     pub bindings: Vec<PortBinding>,
     #[serde(default, alias = "mounts")]
     pub rsync_binds: Vec<RsyncBind>,
     pub settings: Settings,
+    /// SSH key ids chosen when the app created each droplet, keyed by droplet id.
+    #[serde(default)]
+    pub droplet_ssh_keys: HashMap<u64, Vec<String>>,
+    /// Droplet each local port was last bound to, kept after unbinding.
+    #[serde(default)]
+    pub port_history: HashMap<u16, String>,
+    #[serde(default)]
+    pub theme: ThemeConfig,
+    #[serde(default)]
+    pub workspaces: Vec<Workspace>,
+}
+
+/// A named set of tunnels and syncs that are started and stopped together.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Workspace {
+    pub name: String,
+    /// Binding definitions as recorded; `tunnel_pid` is always `None`.
+    #[serde(default)]
+    pub bindings: Vec<PortBinding>,
+    #[serde(default)]
+    pub syncs: Vec<WorkspaceSync>,
+}
+
+/// One Mutagen sync in a workspace, with the SSH details of the droplet it syncs to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkspaceSync {
+    pub droplet_name: String,
+    pub ssh_user: String,
+    pub host: String,
+    pub ssh_port: u16,
+    pub ssh_key_path: String,
+    #[serde(default)]
+    pub use_agent: bool,
+    pub local: String,
+    pub remote: String,
+}
+
+/// Optional `#rrggbb` overrides for the TUI colors.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ThemeConfig {
+    #[serde(default)]
+    pub bg: Option<String>,
+    #[serde(default)]
+    pub muted: Option<String>,
+    #[serde(default)]
+    pub accent: Option<String>,
+    #[serde(default)]
+    pub success: Option<String>,
+    #[serde(default)]
+    pub warning: Option<String>,
+    #[serde(default)]
+    pub error: Option<String>,
+    #[serde(default)]
+    pub border: Option<String>,
 }
@@ -5,6 +5,9 @@ use std::process::Command;
 use anyhow::{Context, Result, anyhow};
 use chrono::Utc;
 
+use crate::logging::LoggedCommand;
+use crate::model::{WorkspaceSync, host_for_remote_spec, ssh_identity_args};
+
 #[derive(Debug, Clone)]
 pub struct SyncPath {
     pub local: String,
@@ -17,14 +20,112 @@ pub struct SshConfig {
     pub host: String,
     pub port: u16,
     pub key_path: String,
+    /// Leave out `-i key_path` and let `ssh-agent` supply the identity.
+    pub use_agent: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct SyncSession {
     pub name: String,
+    pub identifier: Option<String>,
     pub status: Option<String>,
     pub beta_url: Option<String>,
     pub beta_host: Option<String>,
+    /// Unresolved conflicts mutagen reported; only the JSON listing carries them.
+    pub conflicts: usize,
+}
+
+impl SyncSession {
+    pub fn state(&self) -> SyncState {
+        self.status
+            .as_deref()
+            .map(SyncState::from_status)
+            .unwrap_or(SyncState::Unknown)
+    }
+}
+
+/// A session status narrowed to the phases mutagen cycles through. Accepts both the JSON
+/// slugs (`staging-beta`) and the text listing's descriptions (`Staging files on beta`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncState {
+    Watching,
+    Connecting,
+    Scanning,
+    WaitingForRescan,
+    Reconciling,
+    Staging,
+    Transitioning,
+    Saving,
+    Syncing,
+    Paused,
+    Disconnected,
+    Halted,
+    Unknown,
+}
+
+impl SyncState {
+    pub fn from_status(status: &str) -> Self {
+        let status = status.trim().to_ascii_lowercase();
+        let has = |prefix: &str| status.starts_with(prefix);
+        if has("watching") || status == "monitoring" {
+            SyncState::Watching
+        } else if has("connecting") {
+            SyncState::Connecting
+        } else if has("scanning") {
+            SyncState::Scanning
+        } else if has("waiting") {
+            SyncState::WaitingForRescan
+        } else if has("reconciling") {
+            SyncState::Reconciling
+        } else if has("staging") {
+            SyncState::Staging
+        } else if has("transitioning") || has("applying") {
+            SyncState::Transitioning
+        } else if has("saving") {
+            SyncState::Saving
+        } else if status == "syncing" {
+            SyncState::Syncing
+        } else if status == "paused" || status == "stopped" || has("[paused]") {
+            SyncState::Paused
+        } else if has("disconnected") {
+            SyncState::Disconnected
+        } else if has("halted") {
+            SyncState::Halted
+        } else {
+            SyncState::Unknown
+        }
+    }
+
+    /// Files are moving right now, as opposed to idle watching or a connection state.
+    pub fn is_busy(self) -> bool {
+        matches!(
+            self,
+            SyncState::Scanning
+                | SyncState::Reconciling
+                | SyncState::Staging
+                | SyncState::Transitioning
+                | SyncState::Saving
+                | SyncState::Syncing
+        )
+    }
+
+    pub fn icon(self) -> &'static str {
+        match self {
+            SyncState::Watching => "✓",
+            SyncState::Connecting => "…",
+            SyncState::Scanning => "⟳",
+            SyncState::WaitingForRescan => "◷",
+            SyncState::Reconciling => "⇄",
+            SyncState::Staging => "↓",
+            SyncState::Transitioning => "→",
+            SyncState::Saving => "⤓",
+            SyncState::Syncing => "⟳",
+            SyncState::Paused => "‖",
+            SyncState::Disconnected => "✗",
+            SyncState::Halted => "!",
+            SyncState::Unknown => "?",
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -40,24 +141,141 @@ pub struct DeleteDropletSyncsOutcome {
     pub mount_removed: usize,
 }
 
+#[derive(Debug, Clone)]
+pub struct RestoreSyncsOutcome {
+    pub restored: usize,
+    pub skipped_lines: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MountSummary {
+    pub name: String,
+    pub local: String,
+    pub remote: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReconcileReport {
+    pub matched: usize,
+    pub stale_entries: Vec<MountSummary>,
+    pub untracked_sessions: Vec<SyncSession>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncPlanAction {
+    /// Start a new Mutagen session.
+    Create,
+    /// A session with this name already exists; resume it.
+    Resume,
+    /// Repeats an earlier path in the same request.
+    Skip,
+}
+
+#[derive(Debug, Clone)]
+pub struct PlannedSync {
+    pub name: String,
+    pub local: String,
+    pub remote: String,
+    pub action: SyncPlanAction,
+    /// Whether the pair is missing from ~/.mountlist and will be appended.
+    pub new_entry: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SyncPlan {
+    pub items: Vec<PlannedSync>,
+}
+
+impl SyncPlan {
+    pub fn count(&self, action: SyncPlanAction) -> usize {
+        self.items
+            .iter()
+            .filter(|item| item.action == action)
+            .count()
+    }
+}
+
+/// A session started by a batch that later failed, with enough detail to undo it.
+#[derive(Debug, Clone)]
+pub struct CreatedSync {
+    pub name: String,
+    pub identifier: Option<String>,
+    pub local: String,
+    pub remote: String,
+    /// Whether the batch appended this session to ~/.mountlist.
+    pub in_mountlist: bool,
+}
+
+/// Error returned by `create_syncs` when a batch stops partway.
+#[derive(Debug, Clone)]
+pub struct SyncBatchFailure {
+    pub created: Vec<CreatedSync>,
+    pub resumed: usize,
+    pub failed_local: String,
+    pub failed_remote: String,
+    pub error: String,
+    pub mountlist_error: Option<String>,
+}
+
+impl std::fmt::Display for SyncBatchFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Sync {} -> {} failed: {}. Before that, {} session(s) were created and {} resumed.",
+            self.failed_local,
+            self.failed_remote,
+            self.error,
+            self.created.len(),
+            self.resumed
+        )?;
+        if let Some(err) = &self.mountlist_error {
+            write!(f, " Recording them in ~/.mountlist also failed: {err}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for SyncBatchFailure {}
+
+#[derive(Debug, Clone)]
+pub struct SyncRollbackOutcome {
+    pub terminated: Vec<String>,
+    pub mount_removed: usize,
+    pub errors: Vec<String>,
+}
+
 #[derive(Debug, Clone)]
 struct MountEntry {
     name: String,
     local: String,
     remote: String,
+    identifier: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Mountlist {
+    entries: Vec<MountEntry>,
+    skipped: Vec<String>,
+}
+
+/// Works out what `create_syncs` would do for `paths` without touching anything.
+pub fn plan_syncs(ssh: &SshConfig, droplet_name: &str, paths: Vec<SyncPath>) -> Result<SyncPlan> {
+    let entries = read_mountlist(ssh)?.entries;
+    let existing_names = mutagen_existing_names()?;
+    build_sync_plan(&entries, &existing_names, droplet_name, paths)
 }
 
-pub fn create_syncs(ssh: &SshConfig, droplet_name: &str, paths: Vec<SyncPath>) -> Result<usize> {
+fn build_sync_plan(
+    entries: &[MountEntry],
+    existing_names: &HashSet<String>,
+    droplet_name: &str,
+    paths: Vec<SyncPath>,
+) -> Result<SyncPlan> {
     if paths.is_empty() {
         return Err(anyhow!("No folders provided for sync"));
     }
 
-    let mut existing_entries = read_mountlist(ssh)?;
-    let mut existing_names = mutagen_existing_names()?;
-    let mut new_entries = Vec::new();
-    let mut created = 0usize;
-
-    let mut seen_pairs = HashSet::new();
+    let mut items: Vec<PlannedSync> = Vec::new();
     let mut index = 1usize;
 
     for path in paths {
@@ -67,50 +285,185 @@ pub fn create_syncs(ssh: &SshConfig, droplet_name: &str, paths: Vec<SyncPath>) -
             return Err(anyhow!("Remote path cannot be empty"));
         }
 
-        let key = format!("{}\n{}", local, remote);
-        if !seen_pairs.insert(key) {
+        if let Some(first) = items
+            .iter()
+            .find(|item| item.local == local && item.remote == remote)
+        {
+            let name = first.name.clone();
+            items.push(PlannedSync {
+                name,
+                local,
+                remote,
+                action: SyncPlanAction::Skip,
+                new_entry: false,
+            });
             continue;
         }
 
-        let name = match existing_entries
+        let (name, new_entry) = match entries
             .iter()
             .find(|entry| entry.local == local && entry.remote == remote)
         {
-            Some(entry) => entry.name.clone(),
+            Some(entry) => (entry.name.clone(), false),
             None => {
                 let name = generate_sync_name(droplet_name, &local, index);
                 index += 1;
-                let entry = MountEntry {
-                    name: name.clone(),
-                    local: local.clone(),
-                    remote: remote.clone(),
-                };
-                existing_entries.push(entry.clone());
-                new_entries.push(entry);
-                name
+                (name, true)
             }
         };
+        let action = if existing_names.contains(&name) {
+            SyncPlanAction::Resume
+        } else {
+            SyncPlanAction::Create
+        };
+        items.push(PlannedSync {
+            name,
+            local,
+            remote,
+            action,
+            new_entry,
+        });
+    }
 
-        ensure_remote_dir(ssh, &remote)?;
-        if existing_names.contains(&name) {
-            mutagen_resume(&name)?;
+    Ok(SyncPlan { items })
+}
+
+/// Carries out a plan from `plan_syncs`, returning how many syncs were created or resumed.
+///
+/// If a step fails, the sessions created so far are still recorded in ~/.mountlist and the
+/// error is a [`SyncBatchFailure`] listing them, so the caller can offer `rollback_syncs`.
+pub fn create_syncs(ssh: &SshConfig, plan: &SyncPlan) -> Result<usize> {
+    let batch = run_sync_batch(plan, |item| {
+        ensure_remote_dir(ssh, &item.remote)?;
+        if item.action == SyncPlanAction::Resume {
+            mutagen_resume(&item.name)?;
+            Ok(None)
         } else {
-            mutagen_create(ssh, &name, &local, &remote)?;
-            existing_names.insert(name);
+            mutagen_create(ssh, &item.name, &item.local, &item.remote)
+        }
+    });
+
+    let Some((failed, error)) = batch.failed else {
+        append_mountlist(ssh, &batch.new_entries)?;
+        return Ok(batch.created.len() + batch.resumed);
+    };
+
+    let mut failure = SyncBatchFailure {
+        created: batch.created,
+        resumed: batch.resumed,
+        failed_local: failed.local,
+        failed_remote: failed.remote,
+        error,
+        mountlist_error: None,
+    };
+    match append_mountlist(ssh, &batch.new_entries) {
+        Ok(()) => {
+            for sync in &mut failure.created {
+                sync.in_mountlist = batch
+                    .new_entries
+                    .iter()
+                    .any(|entry| entry.name == sync.name);
+            }
         }
-        created += 1;
+        Err(err) => failure.mountlist_error = Some(err.to_string()),
     }
+    Err(anyhow::Error::new(failure))
+}
 
-    if !new_entries.is_empty() {
-        append_mountlist(ssh, &new_entries)?;
+#[derive(Debug, Default)]
+struct SyncBatchProgress {
+    created: Vec<CreatedSync>,
+    resumed: usize,
+    new_entries: Vec<MountEntry>,
+    /// The item that stopped the batch and its error.
+    failed: Option<(PlannedSync, String)>,
+}
+
+/// Runs `apply` over the non-skipped plan items in order, stopping at the first error.
+/// `apply` returns the new session identifier for creates, or `None` for resumes.
+fn run_sync_batch(
+    plan: &SyncPlan,
+    mut apply: impl FnMut(&PlannedSync) -> Result<Option<String>>,
+) -> SyncBatchProgress {
+    let mut progress = SyncBatchProgress::default();
+
+    for item in &plan.items {
+        if item.action == SyncPlanAction::Skip {
+            continue;
+        }
+        let identifier = match apply(item) {
+            Ok(identifier) => identifier,
+            Err(err) => {
+                progress.failed = Some((item.clone(), format!("{err:#}")));
+                break;
+            }
+        };
+        if item.action == SyncPlanAction::Resume {
+            progress.resumed += 1;
+        } else {
+            progress.created.push(CreatedSync {
+                name: item.name.clone(),
+                identifier: identifier.clone(),
+                local: item.local.clone(),
+                remote: item.remote.clone(),
+                in_mountlist: false,
+            });
+        }
+        if item.new_entry {
+            progress.new_entries.push(MountEntry {
+                name: item.name.clone(),
+                local: item.local.clone(),
+                remote: item.remote.clone(),
+                identifier,
+            });
+        }
     }
 
-    Ok(created)
+    progress
 }
 
-pub fn restore_syncs(ssh: &SshConfig) -> Result<usize> {
-    let entries = read_mountlist(ssh)?;
+/// Terminates sessions left behind by a failed batch and removes the mountlist lines that
+/// batch appended. Keeps going past individual failures and reports them.
+pub fn rollback_syncs(ssh: &SshConfig, created: &[CreatedSync]) -> SyncRollbackOutcome {
+    let mut terminated = Vec::new();
+    let mut errors = Vec::new();
+    for sync in created {
+        match terminate_sync(&sync.name, sync.identifier.as_deref()) {
+            Ok(()) => terminated.push(sync.name.clone()),
+            Err(err) => errors.push(format!("{}: {err}", sync.name)),
+        }
+    }
+
+    let names: Vec<String> = created
+        .iter()
+        .filter(|sync| sync.in_mountlist)
+        .map(|sync| sync.name.clone())
+        .collect();
+    let mount_removed = match delete_mount_entries(ssh, &names) {
+        Ok(count) => count,
+        Err(err) => {
+            errors.push(format!("~/.mountlist: {err}"));
+            0
+        }
+    };
+
+    SyncRollbackOutcome {
+        terminated,
+        mount_removed,
+        errors,
+    }
+}
+
+pub fn restore_syncs(ssh: &SshConfig) -> Result<RestoreSyncsOutcome> {
+    let Mountlist { entries, skipped } = read_mountlist(ssh)?;
     if entries.is_empty() {
+        if !skipped.is_empty() {
+            return Err(anyhow!(
+                "No valid mounts found in ~/.mountlist ({} malformed line{} skipped)",
+                skipped.len(),
+                if skipped.len() == 1 { "" } else { "s" }
+            ));
+        }
         return Err(anyhow!("No mounts found in ~/.mountlist"));
     }
 
@@ -129,7 +482,151 @@ pub fn restore_syncs(ssh: &SshConfig) -> Result<usize> {
         restored += 1;
     }
 
-    Ok(restored)
+    Ok(RestoreSyncsOutcome {
+        restored,
+        skipped_lines: skipped,
+    })
+}
+
+/// How `activate_syncs` brought up one host's syncs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ActivateSyncsOutcome {
+    /// Sessions created or resumed from pause.
+    pub started: usize,
+    pub already_running: usize,
+}
+
+/// `~/.mountlist` pairs on `ssh` whose sessions are currently running (not paused).
+pub fn running_sync_paths(ssh: &SshConfig) -> Result<Vec<SyncPath>> {
+    let entries = read_mountlist(ssh)?.entries;
+    let running = running_session_names()?;
+    Ok(entries
+        .into_iter()
+        .filter(|entry| running.contains(&entry.name))
+        .map(|entry| SyncPath {
+            local: entry.local,
+            remote: entry.remote,
+        })
+        .collect())
+}
+
+/// Creates or resumes the syncs for `paths` like `create_syncs`, leaving sessions that
+/// are already running alone.
+pub fn activate_syncs(
+    ssh: &SshConfig,
+    droplet_name: &str,
+    paths: Vec<SyncPath>,
+) -> Result<ActivateSyncsOutcome> {
+    let mut plan = plan_syncs(ssh, droplet_name, paths)?;
+    let already_running = skip_running(&mut plan, &running_session_names()?);
+    let started = create_syncs(ssh, &plan)?;
+    Ok(ActivateSyncsOutcome {
+        started,
+        already_running,
+    })
+}
+
+/// Pauses the running sessions for `paths`, keeping their `~/.mountlist` lines so
+/// `activate_syncs` can resume them. Returns how many were paused.
+pub fn pause_syncs(ssh: &SshConfig, droplet_name: &str, paths: Vec<SyncPath>) -> Result<usize> {
+    let plan = plan_syncs(ssh, droplet_name, paths)?;
+    let running = running_session_names()?;
+    let mut paused = 0usize;
+    for item in &plan.items {
+        if item.action == SyncPlanAction::Resume && running.contains(&item.name) {
+            run_mutagen(&["sync", "pause", &item.name])?;
+            paused += 1;
+        }
+    }
+    Ok(paused)
+}
+
+/// Starts a workspace's syncs host by host: one report line per host, and whether that
+/// host's syncs came up.
+pub fn activate_workspace_syncs(syncs: &[WorkspaceSync]) -> Vec<(String, bool)> {
+    sync_groups(syncs)
+        .into_iter()
+        .map(
+            |(droplet_name, ssh, paths)| match activate_syncs(&ssh, &droplet_name, paths) {
+                Ok(outcome) => (
+                    format!(
+                        "syncs {}@{}: {} started, {} already running",
+                        ssh.user, ssh.host, outcome.started, outcome.already_running
+                    ),
+                    true,
+                ),
+                Err(err) => (
+                    format!("syncs {}@{}: FAILED, {err}", ssh.user, ssh.host),
+                    false,
+                ),
+            },
+        )
+        .collect()
+}
+
+/// Pauses a workspace's syncs host by host, reported like `activate_workspace_syncs`.
+pub fn pause_workspace_syncs(syncs: &[WorkspaceSync]) -> Vec<(String, bool)> {
+    sync_groups(syncs)
+        .into_iter()
+        .map(
+            |(droplet_name, ssh, paths)| match pause_syncs(&ssh, &droplet_name, paths) {
+                Ok(paused) => (
+                    format!("syncs {}@{}: {paused} paused", ssh.user, ssh.host),
+                    true,
+                ),
+                Err(err) => (
+                    format!("syncs {}@{}: FAILED, {err}", ssh.user, ssh.host),
+                    false,
+                ),
+            },
+        )
+        .collect()
+}
+
+/// A workspace's syncs grouped by host, in the order the hosts first appear.
+fn sync_groups(syncs: &[WorkspaceSync]) -> Vec<(String, SshConfig, Vec<SyncPath>)> {
+    let mut groups: Vec<(String, SshConfig, Vec<SyncPath>)> = Vec::new();
+    for sync in syncs {
+        let path = SyncPath {
+            local: sync.local.clone(),
+            remote: sync.remote.clone(),
+        };
+        match groups.iter_mut().find(|(_, ssh, _)| ssh.host == sync.host) {
+            Some((_, _, paths)) => paths.push(path),
+            None => groups.push((
+                sync.droplet_name.clone(),
+                SshConfig {
+                    user: sync.ssh_user.clone(),
+                    host: sync.host.clone(),
+                    port: sync.ssh_port,
+                    key_path: sync.ssh_key_path.clone(),
+                    use_agent: sync.use_agent,
+                },
+                vec![path],
+            )),
+        }
+    }
+    groups
+}
+
+/// Turns resumes of sessions in `running` into skips, returning how many were skipped.
+fn skip_running(plan: &mut SyncPlan, running: &HashSet<String>) -> usize {
+    let mut skipped = 0usize;
+    for item in &mut plan.items {
+        if item.action == SyncPlanAction::Resume && running.contains(&item.name) {
+            item.action = SyncPlanAction::Skip;
+            skipped += 1;
+        }
+    }
+    skipped
+}
+
+fn running_session_names() -> Result<HashSet<String>> {
+    Ok(list_syncs()?
+        .into_iter()
+        .filter(|session| session.state() != SyncState::Paused)
+        .map(|session| session.name)
+        .collect())
 }
 
 pub fn list_syncs() -> Result<Vec<SyncSession>> {
@@ -145,13 +642,38 @@ pub fn list_syncs() -> Result<Vec<SyncSession>> {
     Ok(sessions_from_text(&output))
 }
 
-pub fn terminate_sync(name: &str) -> Result<()> {
-    run_mutagen(&["sync", "terminate", name])?;
+/// Terminates a session by its identifier when known, otherwise by name.
+pub fn terminate_sync(name: &str, identifier: Option<&str>) -> Result<()> {
+    let target = identifier.filter(|id| !id.is_empty()).unwrap_or(name);
+    run_mutagen(&["sync", "terminate", target])?;
     Ok(())
 }
 
-pub fn delete_sync(name: &str, ssh: Option<&SshConfig>) -> Result<DeleteSyncOutcome> {
-    terminate_sync(name)?;
+/// Whether a session status means files are moving right now (as opposed to idle
+/// `watching` or a connection state).
+pub fn is_syncing_status(status: &str) -> bool {
+    SyncState::from_status(status).is_busy()
+}
+
+/// Waits for the session to finish a full sync cycle.
+pub fn flush_sync(name: &str, identifier: Option<&str>) -> Result<()> {
+    let target = identifier.filter(|id| !id.is_empty()).unwrap_or(name);
+    run_mutagen(&["sync", "flush", target])?;
+    Ok(())
+}
+
+/// Terminates a session (optionally after flushing it) and drops its `~/.mountlist` line.
+pub fn delete_sync(
+    name: &str,
+    identifier: Option<&str>,
+    ssh: Option<&SshConfig>,
+    flush: bool,
+) -> Result<DeleteSyncOutcome> {
+    if flush {
+        flush_sync(name, identifier)
+            .context("Flush failed; the session was left running so no changes are lost")?;
+    }
+    terminate_sync(name, identifier)?;
     let mut mount_removed = false;
     let mut mount_error = None;
     if let Some(ssh) = ssh {
@@ -176,38 +698,34 @@ pub fn delete_syncs_for_droplet(
     droplet_name: &str,
 ) -> Result<DeleteDropletSyncsOutcome> {
     let sessions = list_syncs()?;
-    let mount_entries = read_mountlist(ssh)?;
-    let mount_names: HashSet<String> = mount_entries
-        .iter()
-        .map(|entry| entry.name.clone())
-        .collect();
+    let mount_entries = read_mountlist(ssh)?.entries;
 
     let droplet_prefix = format!("sync-{}-", sanitize_name(droplet_name));
     let target_host = normalized_host(&ssh.host);
-    let mut target_names = HashSet::new();
+    let mut targets = Vec::new();
     for session in sessions {
         let host_match = session
             .beta_host
             .as_deref()
             .map(|host| normalized_host(host) == target_host)
             .unwrap_or(false);
-        if host_match
-            || mount_names.contains(&session.name)
-            || session.name.starts_with(&droplet_prefix)
-        {
-            target_names.insert(session.name);
+        let mount_match = mount_entries
+            .iter()
+            .any(|entry| entry_matches_session(entry, &session));
+        if host_match || mount_match || session.name.starts_with(&droplet_prefix) {
+            targets.push(session);
         }
     }
 
-    for name in &target_names {
-        terminate_sync(name)?;
+    for session in &targets {
+        terminate_sync(&session.name, session.identifier.as_deref())?;
     }
 
     let mount_names: Vec<String> = mount_entries.into_iter().map(|entry| entry.name).collect();
     let mount_removed = delete_mount_entries(ssh, &mount_names)?;
 
     Ok(DeleteDropletSyncsOutcome {
-        terminated: target_names.len(),
+        terminated: targets.len(),
         mount_removed,
     })
 }
@@ -216,12 +734,65 @@ pub fn terminate_all_syncs() -> Result<usize> {
     let sessions = list_syncs()?;
     let mut count = 0usize;
     for session in sessions {
-        terminate_sync(&session.name)?;
+        terminate_sync(&session.name, session.identifier.as_deref())?;
         count += 1;
     }
     Ok(count)
 }
 
+/// Compares the droplet's ~/.mountlist with live sessions in both directions.
+pub fn reconcile(ssh: &SshConfig) -> Result<ReconcileReport> {
+    let sessions = list_syncs()?;
+    let entries = read_mountlist(ssh)?.entries;
+    let target_host = normalized_host(&ssh.host);
+
+    let mut matched = 0usize;
+    let mut stale_entries = Vec::new();
+    for entry in &entries {
+        if sessions
+            .iter()
+            .any(|session| entry_matches_session(entry, session))
+        {
+            matched += 1;
+        } else {
+            stale_entries.push(MountSummary {
+                name: entry.name.clone(),
+                local: entry.local.clone(),
+                remote: entry.remote.clone(),
+            });
+        }
+    }
+
+    let untracked_sessions = sessions
+        .into_iter()
+        .filter(|session| {
+            session
+                .beta_host
+                .as_deref()
+                .map(|host| normalized_host(host) == target_host)
+                .unwrap_or(false)
+        })
+        .filter(|session| {
+            !entries
+                .iter()
+                .any(|entry| entry_matches_session(entry, session))
+        })
+        .collect();
+
+    Ok(ReconcileReport {
+        matched,
+        stale_entries,
+        untracked_sessions,
+    })
+}
+
+fn entry_matches_session(entry: &MountEntry, session: &SyncSession) -> bool {
+    match (entry.identifier.as_deref(), session.identifier.as_deref()) {
+        (Some(expected), Some(actual)) => expected == actual,
+        _ => entry.name == session.name,
+    }
+}
+
 fn mutagen_existing_names() -> Result<HashSet<String>> {
     if let Ok(output) = run_mutagen(&["sync", "list", "--json"]) {
         if let Ok(names) = names_from_json(&output) {
@@ -236,10 +807,27 @@ fn mutagen_existing_names() -> Result<HashSet<String>> {
     Ok(sessions.into_iter().map(|s| s.name).collect())
 }
 
-fn mutagen_create(ssh: &SshConfig, name: &str, local: &str, remote: &str) -> Result<()> {
-    let remote_target = format!("{}@{}:{}", ssh.user, ssh.host, remote);
-    run_mutagen(&["sync", "create", "--name", name, local, &remote_target])?;
-    Ok(())
+fn mutagen_create(
+    ssh: &SshConfig,
+    name: &str,
+    local: &str,
+    remote: &str,
+) -> Result<Option<String>> {
+    let remote_target = format!(
+        "{}@{}:{}",
+        ssh.user,
+        host_for_remote_spec(&ssh.host),
+        remote
+    );
+    let output = run_mutagen(&["sync", "create", "--name", name, local, &remote_target])?;
+    Ok(identifier_from_create_output(&output))
+}
+
+fn identifier_from_create_output(output: &str) -> Option<String> {
+    output
+        .split_whitespace()
+        .find(|token| token.starts_with("sync_"))
+        .map(|token| token.trim_end_matches('.').to_string())
 }
 
 fn mutagen_resume(name: &str) -> Result<()> {
@@ -250,7 +838,7 @@ fn mutagen_resume(name: &str) -> Result<()> {
 fn run_mutagen(args: &[&str]) -> Result<String> {
     let output = Command::new("mutagen")
         .args(args)
-        .output()
+        .logged_output()
         .context("Failed to execute mutagen")?;
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -290,11 +878,25 @@ fn sessions_from_json(raw: &str) -> Result<Vec<SyncSession>> {
                 .or_else(|| item.get("Name"))
                 .and_then(|v| v.as_str());
             if let Some(name) = name {
-                let status = item
-                    .get("status")
-                    .or_else(|| item.get("Status"))
-                    .and_then(|v| v.as_str())
-                    .map(|s| s.to_string());
+                let paused = item
+                    .get("paused")
+                    .or_else(|| item.get("Paused"))
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                // A paused session keeps reporting its last phase; paused is what matters.
+                let status = if paused {
+                    Some("paused".to_string())
+                } else {
+                    item.get("status")
+                        .or_else(|| item.get("Status"))
+                        .and_then(|v| v.as_str())
+                        .map(|s| s.to_string())
+                };
+                let conflicts = item
+                    .get("conflicts")
+                    .or_else(|| item.get("Conflicts"))
+                    .and_then(|v| v.as_array())
+                    .map_or(0, Vec::len);
                 let beta_url = item
                     .get("beta")
                     .and_then(|v| v.get("url").or_else(|| v.get("URL")))
@@ -307,11 +909,19 @@ fn sessions_from_json(raw: &str) -> Result<Vec<SyncSession>> {
                             .map(|s| s.to_string())
                     });
                 let beta_host = beta_url.as_deref().and_then(parse_host_from_url);
+                let identifier = item
+                    .get("identifier")
+                    .or_else(|| item.get("Identifier"))
+                    .and_then(|v| v.as_str())
+                    .filter(|id| !id.is_empty())
+                    .map(|id| id.to_string());
                 sessions.push(SyncSession {
                     name: name.to_string(),
+                    identifier,
                     status,
                     beta_url,
                     beta_host,
+                    conflicts,
                 });
             }
         }
@@ -333,15 +943,26 @@ fn sessions_from_text(raw: &str) -> Vec<SyncSession> {
             if !name.is_empty() {
                 sessions.push(SyncSession {
                     name: name.to_string(),
+                    identifier: None,
                     status: None,
                     beta_url: None,
                     beta_host: None,
+                    conflicts: 0,
                 });
                 current = Some(sessions.len() - 1);
                 in_beta = false;
             }
             continue;
         }
+        if let Some(rest) = trimmed.strip_prefix("Identifier:") {
+            if let Some(idx) = current {
+                let identifier = rest.trim();
+                if !identifier.is_empty() {
+                    sessions[idx].identifier = Some(identifier.to_string());
+                }
+            }
+            continue;
+        }
         if let Some(rest) = trimmed.strip_prefix("Status:") {
             if let Some(idx) = current {
                 let status = rest.trim();
@@ -377,9 +998,11 @@ fn sessions_from_text(raw: &str) -> Vec<SyncSession> {
             if !name.is_empty() {
                 sessions.push(SyncSession {
                     name: name.to_string(),
+                    identifier: None,
                     status: None,
                     beta_url: None,
                     beta_host: None,
+                    conflicts: 0,
                 });
                 current = Some(sessions.len() - 1);
                 in_beta = false;
@@ -421,9 +1044,11 @@ fn sessions_from_text(raw: &str) -> Vec<SyncSession> {
                 }
                 sessions.push(SyncSession {
                     name: first.to_string(),
+                    identifier: None,
                     status: None,
                     beta_url: None,
                     beta_host: None,
+                    conflicts: 0,
                 });
             }
         }
@@ -437,6 +1062,12 @@ fn parse_host_from_url(url: &str) -> Option<String> {
         return None;
     }
 
+    // Bracketed IPv6 literal, e.g. `root@[2001:db8::1]:/srv`.
+    if let Some(start) = trimmed.find("@[").map(|pos| pos + 2) {
+        let end = trimmed[start..].find(']')?;
+        return Some(trimmed[start..start + end].to_string());
+    }
+
     if let Some(pos) = trimmed.find("://") {
         let rest = &trimmed[pos + 3..];
         let hostport = rest.split('/').next().unwrap_or(rest);
@@ -462,14 +1093,14 @@ fn parse_host_from_url(url: &str) -> Option<String> {
     }
 }
 
-fn normalized_host(host: &str) -> String {
+pub(crate) fn normalized_host(host: &str) -> String {
     host.trim()
         .trim_matches('[')
         .trim_matches(']')
         .to_lowercase()
 }
 
-fn read_mountlist(ssh: &SshConfig) -> Result<Vec<MountEntry>> {
+fn read_mountlist(ssh: &SshConfig) -> Result<Mountlist> {
     let output = run_ssh(ssh, "cat ~/.mountlist 2>/dev/null || true")?;
     Ok(parse_mountlist(&output))
 }
@@ -478,7 +1109,7 @@ pub fn delete_mount_entries(ssh: &SshConfig, names: &[String]) -> Result<usize>
     if names.is_empty() {
         return Ok(0);
     }
-    let entries = read_mountlist(ssh)?;
+    let entries = read_mountlist(ssh)?.entries;
     if entries.is_empty() {
         return Ok(0);
     }
@@ -509,12 +1140,21 @@ fn append_mountlist(ssh: &SshConfig, entries: &[MountEntry]) -> Result<()> {
     }
     let mut lines = String::new();
     for entry in entries {
-        lines.push_str(&format!(
-            "printf '%s\\t%s\\t%s\\n' {} {} {} >> ~/.mountlist\n",
-            shell_escape(&entry.name),
-            shell_escape(&entry.local),
-            shell_escape(&entry.remote)
-        ));
+        match entry.identifier.as_deref() {
+            Some(identifier) => lines.push_str(&format!(
+                "printf '%s\\t%s\\t%s\\t%s\\n' {} {} {} {} >> ~/.mountlist\n",
+                shell_escape(&escape_mount_field(&entry.name)),
+                shell_escape(&escape_mount_field(&entry.local)),
+                shell_escape(&escape_mount_field(&entry.remote)),
+                shell_escape(&escape_mount_field(identifier))
+            )),
+            None => lines.push_str(&format!(
+                "printf '%s\\t%s\\t%s\\n' {} {} {} >> ~/.mountlist\n",
+                shell_escape(&escape_mount_field(&entry.name)),
+                shell_escape(&escape_mount_field(&entry.local)),
+                shell_escape(&escape_mount_field(&entry.remote))
+            )),
+        }
     }
     run_ssh(ssh, &lines)?;
     Ok(())
@@ -529,15 +1169,14 @@ fn ensure_remote_dir(ssh: &SshConfig, remote: &str) -> Result<()> {
 fn run_ssh(ssh: &SshConfig, command: &str) -> Result<String> {
     let key_path = expand_local_path(&ssh.key_path);
     let output = Command::new("ssh")
-        .arg("-i")
-        .arg(&key_path)
+        .args(ssh_identity_args(&key_path, ssh.use_agent))
         .arg("-p")
         .arg(ssh.port.to_string())
         .arg("-o")
         .arg("BatchMode=yes")
         .arg(format!("{}@{}", ssh.user, ssh.host))
         .arg(command)
-        .output()
+        .logged_output()
         .context("Failed to execute ssh")?;
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -546,30 +1185,62 @@ fn run_ssh(ssh: &SshConfig, command: &str) -> Result<String> {
     Ok(String::from_utf8_lossy(&output.stdout).to_string())
 }
 
-fn parse_mountlist(content: &str) -> Vec<MountEntry> {
-    let mut entries = Vec::new();
+fn parse_mountlist(content: &str) -> Mountlist {
+    let mut mountlist = Mountlist::default();
     for line in content.lines() {
-        let line = line.trim();
-        if line.is_empty() || line.starts_with('#') {
+        let line = line.trim_end_matches('\r');
+        if line.trim().is_empty() || line.trim_start().starts_with('#') {
             continue;
         }
-        let parts: Vec<&str> = line.split('\t').collect();
-        if parts.len() < 3 {
+        let parts: Vec<String> = line
+            .split('\t')
+            .map(|part| unescape_mount_field(part.trim()))
+            .collect();
+        if parts.len() < 3 || parts[..3].iter().any(|part| part.is_empty()) {
+            mountlist.skipped.push(line.to_string());
             continue;
         }
-        let name = parts[0].trim();
-        let local = parts[1].trim();
-        let remote = parts[2].trim();
-        if name.is_empty() || local.is_empty() || remote.is_empty() {
+        let identifier = parts.get(3).filter(|id| !id.is_empty()).cloned();
+        mountlist.entries.push(MountEntry {
+            name: parts[0].clone(),
+            local: parts[1].clone(),
+            remote: parts[2].clone(),
+            identifier,
+        });
+    }
+    mountlist
+}
+
+/// Escapes separators so a field can never split a mountlist line.
+fn escape_mount_field(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+fn unescape_mount_field(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            out.push(ch);
             continue;
         }
-        entries.push(MountEntry {
-            name: name.to_string(),
-            local: local.to_string(),
-            remote: remote.to_string(),
-        });
+        match chars.next() {
+            Some('t') => out.push('\t'),
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('\\') => out.push('\\'),
+            Some(other) => {
+                out.push('\\');
+                out.push(other);
+            }
+            None => out.push('\\'),
+        }
     }
-    entries
+    out
 }
 
 fn expand_local_path(path: &str) -> String {
@@ -659,3 +1330,275 @@ fn remote_path_command(remote: &str) -> String {
     }
     shell_escape(trimmed)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sync_groups_collects_paths_per_host() {
+        let sync = |host: &str, local: &str| WorkspaceSync {
+            droplet_name: format!("droplet-{host}"),
+            ssh_user: "root".to_string(),
+            host: host.to_string(),
+            ssh_port: 22,
+            ssh_key_path: "/tmp/id_rsa".to_string(),
+            use_agent: false,
+            local: local.to_string(),
+            remote: format!("~/{local}"),
+        };
+        let groups = sync_groups(&[sync("a", "web"), sync("b", "api"), sync("a", "docs")]);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].0, "droplet-a");
+        let locals: Vec<&str> = groups[0].2.iter().map(|path| path.local.as_str()).collect();
+        assert_eq!(locals, ["web", "docs"]);
+        assert_eq!(groups[1].1.host, "b");
+    }
+
+    #[test]
+    fn parse_host_from_url_handles_bracketed_ipv6() {
+        assert_eq!(
+            parse_host_from_url("root@[2001:db8::1]:/srv/app").as_deref(),
+            Some("2001:db8::1")
+        );
+        assert_eq!(
+            parse_host_from_url("root@203.0.113.5:/srv/app").as_deref(),
+            Some("203.0.113.5")
+        );
+    }
+
+    #[test]
+    fn parse_mountlist_keeps_paths_with_spaces() {
+        let content = "sync-web-docs\t/Users/me/My Docs\t~/my docs\n";
+        let mountlist = parse_mountlist(content);
+        assert!(mountlist.skipped.is_empty());
+        assert_eq!(mountlist.entries.len(), 1);
+        assert_eq!(mountlist.entries[0].local, "/Users/me/My Docs");
+        assert_eq!(mountlist.entries[0].remote, "~/my docs");
+        assert!(mountlist.entries[0].identifier.is_none());
+    }
+
+    #[test]
+    fn parse_mountlist_reads_extra_columns_and_reports_malformed() {
+        let content =
+            "a\t/local/a\t/remote/a\tsync_abc\textra\nlegacy-only-two\t/local\n# comment\n";
+        let mountlist = parse_mountlist(content);
+        assert_eq!(mountlist.entries.len(), 1);
+        assert_eq!(mountlist.entries[0].identifier.as_deref(), Some("sync_abc"));
+        assert_eq!(
+            mountlist.skipped,
+            vec!["legacy-only-two\t/local".to_string()]
+        );
+    }
+
+    #[test]
+    fn entry_matching_prefers_identifier_over_name() {
+        let entry = MountEntry {
+            name: "sync-web-app".to_string(),
+            local: "/tmp/app".to_string(),
+            remote: "~/app".to_string(),
+            identifier: Some("sync_one".to_string()),
+        };
+        let mut session = SyncSession {
+            name: "sync-web-app".to_string(),
+            identifier: Some("sync_two".to_string()),
+            status: None,
+            beta_url: None,
+            beta_host: None,
+            conflicts: 0,
+        };
+        assert!(!entry_matches_session(&entry, &session));
+        session.identifier = None;
+        assert!(entry_matches_session(&entry, &session));
+    }
+
+    #[test]
+    fn mount_fields_with_tabs_round_trip() {
+        let local = "/tmp/odd\tname\\dir";
+        let line = format!(
+            "name\t{}\t{}",
+            escape_mount_field(local),
+            escape_mount_field("/remote")
+        );
+        let mountlist = parse_mountlist(&line);
+        assert_eq!(mountlist.entries.len(), 1);
+        assert_eq!(mountlist.entries[0].local, local);
+    }
+
+    #[test]
+    fn sync_plan_marks_create_resume_and_duplicates() {
+        let entries = vec![MountEntry {
+            name: "sync-web-app".to_string(),
+            local: "/tmp/app".to_string(),
+            remote: "~/app".to_string(),
+            identifier: None,
+        }];
+        let existing: HashSet<String> = ["sync-web-app".to_string()].into_iter().collect();
+        let path = |local: &str, remote: &str| SyncPath {
+            local: local.to_string(),
+            remote: remote.to_string(),
+        };
+        let plan = build_sync_plan(
+            &entries,
+            &existing,
+            "web",
+            vec![
+                path("/tmp/app", "~/app"),
+                path("/tmp/docs", "~/docs"),
+                path("/tmp/app", " ~/app "),
+            ],
+        )
+        .expect("plan");
+
+        let actions: Vec<SyncPlanAction> = plan.items.iter().map(|item| item.action).collect();
+        assert_eq!(
+            actions,
+            vec![
+                SyncPlanAction::Resume,
+                SyncPlanAction::Create,
+                SyncPlanAction::Skip
+            ]
+        );
+        assert!(!plan.items[0].new_entry);
+        assert!(plan.items[1].new_entry);
+        assert!(plan.items[1].name.starts_with("sync-web-docs-"));
+        assert_eq!(plan.items[2].name, "sync-web-app");
+        assert_eq!(plan.count(SyncPlanAction::Create), 1);
+
+        let err =
+            build_sync_plan(&entries, &existing, "web", vec![path("/tmp/a", " ")]).unwrap_err();
+        assert_eq!(err.to_string(), "Remote path cannot be empty");
+    }
+
+    #[test]
+    fn sync_batch_stops_at_failure_and_reports_progress() {
+        let item = |name: &str, action: SyncPlanAction| PlannedSync {
+            name: name.to_string(),
+            local: format!("/tmp/{name}"),
+            remote: format!("~/{name}"),
+            action,
+            new_entry: action == SyncPlanAction::Create,
+        };
+        let plan = SyncPlan {
+            items: vec![
+                item("one", SyncPlanAction::Create),
+                item("two", SyncPlanAction::Resume),
+                item("three", SyncPlanAction::Create),
+                item("four", SyncPlanAction::Create),
+                item("five", SyncPlanAction::Create),
+            ],
+        };
+
+        let mut attempted = Vec::new();
+        let result = run_sync_batch(&plan, |item| {
+            attempted.push(item.name.clone());
+            if item.name == "four" {
+                return Err(anyhow!("mutagen create failed"));
+            }
+            Ok(Some(format!("sync_{}", item.name)))
+        });
+
+        assert_eq!(attempted, vec!["one", "two", "three", "four"]);
+        let created: Vec<&str> = result.created.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(created, vec!["one", "three"]);
+        assert_eq!(result.created[0].identifier.as_deref(), Some("sync_one"));
+        assert_eq!(result.resumed, 1);
+        assert_eq!(result.new_entries.len(), 2);
+        let (failed, error) = result.failed.expect("batch should fail");
+        assert_eq!(failed.name, "four");
+        assert_eq!(error, "mutagen create failed");
+
+        let failure = SyncBatchFailure {
+            created: result.created,
+            resumed: result.resumed,
+            failed_local: failed.local,
+            failed_remote: failed.remote,
+            error,
+            mountlist_error: None,
+        };
+        assert_eq!(
+            failure.to_string(),
+            "Sync /tmp/four -> ~/four failed: mutagen create failed. Before that, 2 session(s) were created and 1 resumed."
+        );
+
+        let progress = run_sync_batch(&plan, |_| Ok(None));
+        assert!(progress.failed.is_none());
+        assert_eq!(progress.created.len(), 4);
+        assert_eq!(progress.resumed, 1);
+    }
+
+    #[test]
+    fn sessions_from_json_reads_precise_state_and_conflicts() {
+        let raw = r#"[
+            {"name": "web", "status": "staging-beta", "paused": false, "conflicts": []},
+            {"name": "api", "status": "watching", "conflicts": [{"root": "a"}, {"root": "b"}]},
+            {"name": "docs", "status": "watching", "paused": true},
+            {"name": "db", "status": "halted-on-root-emptied"}
+        ]"#;
+        let sessions = sessions_from_json(raw).expect("sessions");
+        let states: Vec<SyncState> = sessions.iter().map(SyncSession::state).collect();
+        assert_eq!(
+            states,
+            vec![
+                SyncState::Staging,
+                SyncState::Watching,
+                SyncState::Paused,
+                SyncState::Halted
+            ]
+        );
+        assert_eq!(sessions[0].conflicts, 0);
+        assert_eq!(sessions[1].conflicts, 2);
+        assert_eq!(
+            SyncState::from_status("Connecting to beta"),
+            SyncState::Connecting
+        );
+        assert_eq!(
+            SyncState::from_status("waiting-for-rescan"),
+            SyncState::WaitingForRescan
+        );
+    }
+
+    #[test]
+    fn skip_running_only_skips_live_resumes() {
+        let item = |name: &str, action| PlannedSync {
+            name: name.to_string(),
+            local: format!("/local/{name}"),
+            remote: format!("/remote/{name}"),
+            action,
+            new_entry: false,
+        };
+        let mut plan = SyncPlan {
+            items: vec![
+                item("live", SyncPlanAction::Resume),
+                item("paused", SyncPlanAction::Resume),
+                item("new", SyncPlanAction::Create),
+            ],
+        };
+        let running: HashSet<String> = ["live".to_string(), "new".to_string()].into();
+        assert_eq!(skip_running(&mut plan, &running), 1);
+        assert_eq!(plan.count(SyncPlanAction::Skip), 1);
+        assert_eq!(plan.items[1].action, SyncPlanAction::Resume);
+        assert_eq!(plan.items[2].action, SyncPlanAction::Create);
+    }
+
+    #[test]
+    fn syncing_status_covers_transfer_phases() {
+        for status in [
+            "Syncing",
+            "scanning",
+            "staging-beta",
+            "transitioning",
+            "saving",
+        ] {
+            assert!(is_syncing_status(status), "{status}");
+        }
+        for status in [
+            "watching",
+            "disconnected",
+            "connecting-beta",
+            "halted-on-root-emptied",
+        ] {
+            assert!(!is_syncing_status(status), "{status}");
+        }
+    }
+}
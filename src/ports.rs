@@ -1,14 +1,16 @@
-use std::net::TcpListener;
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
 use std::process::{Child, Command, Stdio};
-use std::time::Duration;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result, anyhow};
 use chrono::Utc;
 
-use crate::model::{AppStateFile, PortBinding};
+use crate::logging::LoggedCommand;
+use crate::model::{AppStateFile, BindInterface, PortBinding, ssh_identity_args};
 
-pub fn is_port_available(port: u16) -> bool {
-    TcpListener::bind(("127.0.0.1", port)).is_ok()
+pub fn is_port_available(address: &str, port: u16) -> bool {
+    TcpListener::bind((address, port)).is_ok()
 }
 
 pub fn port_in_registry(state: &AppStateFile, port: u16) -> Option<&PortBinding> {
@@ -18,39 +20,197 @@ pub fn port_in_registry(state: &AppStateFile, port: u16) -> Option<&PortBinding>
         .find(|binding| binding.local_port == port)
 }
 
+/// Default for how long `start_tunnel` waits for the local port to start listening.
+pub const DEFAULT_TUNNEL_START_TIMEOUT_MS: u64 = 1000;
+
+/// Interval between liveness checks while a new tunnel comes up.
+const TUNNEL_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+static TUNNEL_START_TIMEOUT_MS: AtomicU64 = AtomicU64::new(DEFAULT_TUNNEL_START_TIMEOUT_MS);
+
+/// Sets how long `start_tunnel` waits for the forward to listen; `None` uses the default.
+pub fn set_tunnel_start_timeout(timeout_ms: Option<u64>) {
+    TUNNEL_START_TIMEOUT_MS.store(
+        timeout_ms.unwrap_or(DEFAULT_TUNNEL_START_TIMEOUT_MS),
+        Ordering::Relaxed,
+    );
+}
+
+/// How long to wait for the droplet's SSH port to accept a connection before giving up.
+const SSH_REACHABLE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Whether a TCP connection to `host:port` succeeds, so a dead custom SSH port fails fast
+/// with a clear message instead of an opaque ssh exit.
+pub fn is_ssh_reachable(host: &str, port: u16) -> bool {
+    let Ok(addrs) = (host, port).to_socket_addrs() else {
+        return false;
+    };
+    addrs
+        .into_iter()
+        .any(|addr| TcpStream::connect_timeout(&addr, SSH_REACHABLE_TIMEOUT).is_ok())
+}
+
+/// Whether something accepts connections on a tunnel's local port. Wildcard bind
+/// addresses are probed on loopback.
+pub fn is_port_serving(bind_address: &str, port: u16) -> bool {
+    let address = match bind_address {
+        "0.0.0.0" => "127.0.0.1",
+        "::" => "::1",
+        other => other,
+    };
+    let Ok(addrs) = (address, port).to_socket_addrs() else {
+        return false;
+    };
+    addrs
+        .into_iter()
+        .any(|addr| TcpStream::connect_timeout(&addr, PORT_SERVING_TIMEOUT).is_ok())
+}
+
+const PORT_SERVING_TIMEOUT: Duration = Duration::from_millis(500);
+
 pub fn start_tunnel(binding: &mut PortBinding) -> Result<u32> {
+    if !is_ssh_reachable(&binding.public_ip, binding.ssh_port) {
+        return Err(anyhow!(
+            "SSH port {} on {} is not reachable; check the SSH port and firewall",
+            binding.ssh_port,
+            binding.public_ip
+        ));
+    }
     let mut child = spawn_ssh_tunnel(binding)?;
-    std::thread::sleep(Duration::from_millis(250));
-    match child.try_wait() {
-        Ok(Some(status)) => {
-            let stderr = read_child_stderr(&mut child);
-            return Err(anyhow!("SSH tunnel exited early ({status}). {stderr}"));
+    let timeout = Duration::from_millis(TUNNEL_START_TIMEOUT_MS.load(Ordering::Relaxed));
+    let pid = wait_for_tunnel(
+        &mut child,
+        || is_port_serving(&binding.bind_address, binding.local_port),
+        timeout,
+    )?;
+    binding.tunnel_pid = Some(pid);
+    Ok(pid)
+}
+
+/// Polls a freshly spawned tunnel until `is_listening` reports the forward is up, failing
+/// as soon as the process exits. A process still running at `timeout` counts as started,
+/// since a slow login can take longer than that to open the forward.
+fn wait_for_tunnel(
+    child: &mut Child,
+    is_listening: impl Fn() -> bool,
+    timeout: Duration,
+) -> Result<u32> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) => {
+                let stderr = read_child_stderr(child);
+                return Err(anyhow!("SSH tunnel exited early ({status}). {stderr}"));
+            }
+            Ok(None) if is_listening() || Instant::now() >= deadline => return Ok(child.id()),
+            Ok(None) => std::thread::sleep(TUNNEL_POLL_INTERVAL),
+            Err(err) => return Err(anyhow!("Failed to poll SSH tunnel: {err}")),
+        }
+    }
+}
+
+/// Whether two bindings forward the same local address to the same droplet port.
+pub fn same_forward(a: &PortBinding, b: &PortBinding) -> bool {
+    a.local_port == b.local_port
+        && a.bind_address == b.bind_address
+        && a.droplet_id == b.droplet_id
+        && a.public_ip == b.public_ip
+        && a.remote_port == b.remote_port
+        && a.ssh_port == b.ssh_port
+        && a.ssh_user == b.ssh_user
+}
+
+/// Puts a workspace's recorded binding into `bindings` and returns its index. A binding
+/// with the same forward is reused and a stopped one on the same local port is replaced;
+/// a running one there is left alone, and its index comes back as the error.
+pub fn place_recorded_binding(
+    bindings: &mut Vec<PortBinding>,
+    recorded: &PortBinding,
+) -> std::result::Result<usize, usize> {
+    match bindings
+        .iter()
+        .position(|binding| binding.local_port == recorded.local_port)
+    {
+        Some(index) if same_forward(&bindings[index], recorded) => Ok(index),
+        Some(index) if bindings[index].tunnel_pid.is_some_and(is_pid_running) => Err(index),
+        Some(index) => {
+            bindings[index] = recorded.clone();
+            Ok(index)
+        }
+        None => {
+            bindings.push(recorded.clone());
+            Ok(bindings.len() - 1)
+        }
+    }
+}
+
+/// Result of restarting every dead tunnel in one go.
+#[derive(Debug, Default)]
+pub struct ReconnectOutcome {
+    pub reconnected: Vec<PortBinding>,
+    /// `(local_port, reason)` for tunnels that could not be restarted.
+    pub failed: Vec<(u16, String)>,
+}
+
+/// Restarts each binding's tunnel with its stored parameters, one after another.
+pub fn reconnect_tunnels(bindings: Vec<PortBinding>) -> ReconnectOutcome {
+    let mut outcome = ReconnectOutcome::default();
+    for mut binding in bindings {
+        if !is_port_available(&binding.bind_address, binding.local_port) {
+            outcome
+                .failed
+                .push((binding.local_port, "in use by another process".to_string()));
+            continue;
         }
-        Ok(None) => {
-            let pid = child.id();
-            binding.tunnel_pid = Some(pid);
-            Ok(pid)
+        binding.tunnel_pid = None;
+        match start_tunnel(&mut binding) {
+            Ok(_) => outcome.reconnected.push(binding),
+            Err(err) => outcome.failed.push((binding.local_port, err.to_string())),
         }
-        Err(err) => Err(anyhow!("Failed to poll SSH tunnel: {err}")),
     }
+    outcome
+}
+
+/// Logs in over SSH with the binding's user, key, and port and runs `true`, so bad
+/// credentials fail before anything is registered.
+pub fn check_ssh_login(binding: &PortBinding) -> Result<()> {
+    let output = Command::new("ssh")
+        .args(ssh_identity_args(&binding.ssh_key_path, binding.use_agent))
+        .arg("-p")
+        .arg(binding.ssh_port.to_string())
+        .arg("-o")
+        .arg("BatchMode=yes")
+        .arg("-o")
+        .arg("ConnectTimeout=5")
+        .arg(format!("{}@{}", binding.ssh_user, binding.public_ip))
+        .arg("true")
+        .stdin(Stdio::null())
+        .logged_output()
+        .context("Failed to execute ssh")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow!(
+            "SSH login to {}@{} failed: {}",
+            binding.ssh_user,
+            binding.public_ip,
+            stderr.trim()
+        ));
+    }
+    Ok(())
 }
 
 pub fn spawn_ssh_tunnel(binding: &PortBinding) -> Result<Child> {
     let mut cmd = Command::new("ssh");
     cmd.arg("-N")
         .arg("-L")
-        .arg(format!(
-            "127.0.0.1:{}:127.0.0.1:{}",
-            binding.local_port, binding.remote_port
-        ))
+        .arg(local_forward_spec(binding))
         .arg("-o")
         .arg("ExitOnForwardFailure=yes")
         .arg("-o")
         .arg("ServerAliveInterval=30")
         .arg("-o")
         .arg("ServerAliveCountMax=3")
-        .arg("-i")
-        .arg(&binding.ssh_key_path)
+        .args(ssh_identity_args(&binding.ssh_key_path, binding.use_agent))
         .arg("-p")
         .arg(binding.ssh_port.to_string())
         .arg(format!("{}@{}", binding.ssh_user, binding.public_ip))
@@ -58,7 +218,26 @@ pub fn spawn_ssh_tunnel(binding: &PortBinding) -> Result<Child> {
         .stdout(Stdio::null())
         .stderr(Stdio::piped());
 
-    cmd.spawn().context("Failed to start SSH tunnel")
+    cmd.logged_spawn().context("Failed to start SSH tunnel")
+}
+
+/// The local end as `address:port`, with IPv6 bind addresses bracketed to tell them
+/// from the port separator.
+pub fn local_address(binding: &PortBinding) -> String {
+    if binding.bind_address.contains(':') {
+        format!("[{}]:{}", binding.bind_address, binding.local_port)
+    } else {
+        format!("{}:{}", binding.bind_address, binding.local_port)
+    }
+}
+
+/// The `-L` forward spec; ssh takes the same bracketed local address as `local_address`.
+fn local_forward_spec(binding: &PortBinding) -> String {
+    format!(
+        "{}:127.0.0.1:{}",
+        local_address(binding),
+        binding.remote_port
+    )
 }
 
 pub fn read_child_stderr(child: &mut Child) -> String {
@@ -92,6 +271,8 @@ pub fn new_binding(
     ssh_user: String,
     ssh_key_path: String,
     ssh_port: u16,
+    interface: BindInterface,
+    bind_address: String,
 ) -> PortBinding {
     PortBinding {
         droplet_id,
@@ -101,9 +282,13 @@ pub fn new_binding(
         remote_port,
         ssh_user,
         ssh_key_path,
+        use_agent: false,
         ssh_port,
         created_at: Utc::now(),
         tunnel_pid: None,
+        interface,
+        bind_address,
+        label: None,
     }
 }
 
@@ -113,6 +298,90 @@ mod tests {
     use crate::model::AppStateFile;
     use std::net::TcpListener;
 
+    #[test]
+    fn same_forward_compares_the_whole_forward() {
+        let recorded = new_binding(
+            1,
+            "web".to_string(),
+            "203.0.113.10".to_string(),
+            8080,
+            80,
+            "root".to_string(),
+            "/tmp/id_rsa".to_string(),
+            22,
+            Default::default(),
+            "127.0.0.1".to_string(),
+        );
+        let mut existing = PortBinding {
+            tunnel_pid: Some(4242),
+            label: Some("api".to_string()),
+            ..recorded.clone()
+        };
+        assert!(same_forward(&existing, &recorded));
+        existing.remote_port = 8000;
+        assert!(!same_forward(&existing, &recorded));
+        existing.remote_port = 80;
+        existing.bind_address = "0.0.0.0".to_string();
+        assert!(!same_forward(&existing, &recorded));
+    }
+
+    #[test]
+    fn place_recorded_binding_reuses_replaces_or_refuses() {
+        let recorded = new_binding(
+            1,
+            "web".to_string(),
+            "203.0.113.10".to_string(),
+            8080,
+            80,
+            "root".to_string(),
+            String::new(),
+            22,
+            BindInterface::Public,
+            "127.0.0.1".to_string(),
+        );
+        let mut bindings = Vec::new();
+        assert_eq!(place_recorded_binding(&mut bindings, &recorded), Ok(0));
+        assert_eq!(place_recorded_binding(&mut bindings, &recorded), Ok(0));
+        assert_eq!(bindings.len(), 1);
+
+        let other = PortBinding {
+            remote_port: 3000,
+            ..recorded.clone()
+        };
+        bindings[0] = other.clone();
+        assert_eq!(place_recorded_binding(&mut bindings, &recorded), Ok(0));
+        assert_eq!(bindings[0].remote_port, 80);
+
+        bindings[0] = PortBinding {
+            tunnel_pid: Some(std::process::id()),
+            ..other
+        };
+        assert_eq!(place_recorded_binding(&mut bindings, &recorded), Err(0));
+        assert_eq!(bindings[0].remote_port, 3000);
+    }
+
+    #[test]
+    fn local_forward_spec_brackets_ipv6_bind_addresses() {
+        let mut binding = new_binding(
+            1,
+            "droplet".to_string(),
+            "203.0.113.7".to_string(),
+            8080,
+            80,
+            "root".to_string(),
+            String::new(),
+            22,
+            BindInterface::Public,
+            "127.0.0.1".to_string(),
+        );
+        assert_eq!(local_forward_spec(&binding), "127.0.0.1:8080:127.0.0.1:80");
+        binding.bind_address = "::1".to_string();
+        assert_eq!(local_forward_spec(&binding), "[::1]:8080:127.0.0.1:80");
+        assert_eq!(local_address(&binding), "[::1]:8080");
+        binding.bind_address = "::".to_string();
+        assert_eq!(local_forward_spec(&binding), "[::]:8080:127.0.0.1:80");
+    }
+
     #[test]
     fn registry_lookup_matches_local_port() {
         let binding = new_binding(
@@ -124,11 +393,18 @@ mod tests {
             "root".to_string(),
             "/tmp/id_rsa".to_string(),
             22,
+            BindInterface::Public,
+            "127.0.0.1".to_string(),
         );
         let state = AppStateFile {
+            version: crate::config::STATE_VERSION,
             bindings: vec![binding],
             rsync_binds: Vec::new(),
             settings: Default::default(),
+            droplet_ssh_keys: Default::default(),
+            port_history: Default::default(),
+            theme: Default::default(),
+            workspaces: Vec::new(),
         };
         assert!(port_in_registry(&state, 8080).is_some());
         assert!(port_in_registry(&state, 9090).is_none());
@@ -144,7 +420,85 @@ mod tests {
             Err(err) => panic!("bind failed: {err}"),
         };
         let port = listener.local_addr().unwrap().port();
-        assert!(!is_port_available(port));
+        assert!(!is_port_available("127.0.0.1", port));
+        drop(listener);
+    }
+
+    #[test]
+    fn ssh_reachability_follows_listener() {
+        let listener = match TcpListener::bind("127.0.0.1:0") {
+            Ok(listener) => listener,
+            Err(err) if err.kind() == std::io::ErrorKind::PermissionDenied => {
+                return;
+            }
+            Err(err) => panic!("bind failed: {err}"),
+        };
+        let port = listener.local_addr().unwrap().port();
+        assert!(is_ssh_reachable("127.0.0.1", port));
+        assert!(is_port_serving("0.0.0.0", port));
+        drop(listener);
+        assert!(!is_ssh_reachable("127.0.0.1", port));
+        assert!(!is_port_serving("127.0.0.1", port));
+    }
+
+    fn spawn_sh(script: &str) -> Child {
+        Command::new("sh")
+            .arg("-c")
+            .arg(script)
+            .stderr(Stdio::piped())
+            .spawn()
+            .expect("spawn sh")
+    }
+
+    #[test]
+    fn wait_for_tunnel_reports_early_exit_with_stderr() {
+        let mut child = spawn_sh("echo 'bind: Address already in use' >&2; exit 255");
+        let err = wait_for_tunnel(&mut child, || false, Duration::from_secs(5))
+            .expect_err("exited child should fail");
+        assert!(err.to_string().contains("Address already in use"));
+    }
+
+    #[test]
+    fn wait_for_tunnel_returns_once_listening_or_at_timeout() {
+        let mut child = spawn_sh("sleep 5");
+        let started = Instant::now();
+        let pid = wait_for_tunnel(&mut child, || true, Duration::from_secs(5)).expect("running");
+        assert_eq!(pid, child.id());
+        assert!(started.elapsed() < Duration::from_secs(1));
+
+        let started = Instant::now();
+        wait_for_tunnel(&mut child, || false, Duration::from_millis(200)).expect("running");
+        assert!(started.elapsed() >= Duration::from_millis(200));
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+
+    #[test]
+    fn reconnect_reports_ports_taken_by_other_processes() {
+        let listener = match TcpListener::bind("127.0.0.1:0") {
+            Ok(listener) => listener,
+            Err(err) if err.kind() == std::io::ErrorKind::PermissionDenied => {
+                return;
+            }
+            Err(err) => panic!("bind failed: {err}"),
+        };
+        let port = listener.local_addr().unwrap().port();
+        let binding = new_binding(
+            1,
+            "droplet".to_string(),
+            "127.0.0.1".to_string(),
+            port,
+            80,
+            "root".to_string(),
+            "/tmp/id_rsa".to_string(),
+            22,
+            BindInterface::Public,
+            "127.0.0.1".to_string(),
+        );
+        let outcome = reconnect_tunnels(vec![binding]);
+        assert!(outcome.reconnected.is_empty());
+        assert_eq!(outcome.failed.len(), 1);
+        assert_eq!(outcome.failed[0].0, port);
         drop(listener);
     }
 }
@@ -7,12 +7,19 @@ use std::time::Duration;
 use anyhow::{Context, Result, anyhow};
 use crossbeam_channel::Sender;
 
-use crate::doctl::{self, CreateDropletArgs};
-use crate::model::{Droplet, Image, PortBinding, Region, RsyncBind, Size, Snapshot, SshKey};
+use crate::cancel::{self, CancelToken};
+use crate::doctl::{self, CreateDropletArgs, DropletAction};
+use crate::logging::LoggedCommand;
+use crate::model::{
+    AccountInfo, DoctlContext, DomainRecord, Droplet, Image, PortBinding, Region, RsyncBind, Size,
+    Snapshot, SshKey, WorkspaceSync, host_for_remote_spec, ssh_identity_args,
+};
 use crate::mutagen::{
-    self, DeleteDropletSyncsOutcome, DeleteSyncOutcome, SshConfig, SyncPath, SyncSession,
+    self, CreatedSync, DeleteDropletSyncsOutcome, DeleteSyncOutcome, ReconcileReport,
+    RestoreSyncsOutcome, SshConfig, SyncPath, SyncPlan, SyncRollbackOutcome, SyncSession,
 };
-use crate::ports;
+use crate::ports::{self, ReconnectOutcome};
+use crate::tools::{self, ToolAvailability};
 
 #[derive(Debug, Clone)]
 pub struct RemoteDirectoryListing {
@@ -26,10 +33,72 @@ pub enum RsyncDirection {
     Down,
 }
 
-#[derive(Debug, Clone)]
-pub struct RsyncRunOutcome {
-    pub bind: RsyncBind,
-    pub direction: RsyncDirection,
+/// Totals from the `--stats` block rsync prints after a run.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RsyncStats {
+    /// Files (and dirs, links, ...) rsync considered, e.g. "120 (reg: 100, dir: 20)".
+    pub files: Option<String>,
+    pub files_transferred: Option<u64>,
+    /// Human-readable size of the transferred file data, e.g. "1.23M".
+    pub transferred_size: Option<String>,
+    pub speedup: Option<String>,
+    /// rsync's full stdout, for the notice scroll.
+    pub output: String,
+}
+
+impl RsyncStats {
+    pub fn parse(output: &str) -> Self {
+        let mut stats = RsyncStats {
+            output: output.to_string(),
+            ..Default::default()
+        };
+        for line in output.lines().map(str::trim) {
+            let Some((key, value)) = line.split_once(": ") else {
+                if let Some((_, speedup)) = line.rsplit_once("speedup is ") {
+                    stats.speedup = Some(speedup.trim().to_string());
+                }
+                continue;
+            };
+            match key {
+                "Number of files" => stats.files = Some(value.to_string()),
+                // rsync 3.1+ says "regular files"; older releases just "files".
+                "Number of regular files transferred" | "Number of files transferred" => {
+                    stats.files_transferred = value.replace(',', "").parse().ok();
+                }
+                "Total transferred file size" => {
+                    stats.transferred_size =
+                        Some(value.trim_end_matches(" bytes").trim().to_string());
+                }
+                _ => {}
+            }
+        }
+        stats
+    }
+
+    /// One-line summary such as "3 files transferred (1.23K), speedup 12.34".
+    pub fn summary(&self) -> String {
+        let mut summary = match self.files_transferred {
+            Some(1) => "1 file transferred".to_string(),
+            Some(count) => format!("{count} files transferred"),
+            None => "transfer complete".to_string(),
+        };
+        if let Some(size) = &self.transferred_size {
+            summary.push_str(&format!(" ({size})"));
+        }
+        if let Some(speedup) = &self.speedup {
+            summary.push_str(&format!(", speedup {speedup}"));
+        }
+        summary
+    }
+}
+
+/// Whether an rsync bind's remote folder is still there.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RemotePathStatus {
+    Exists,
+    Missing,
+    /// SSH itself failed; holds the first line of its error.
+    Unreachable(String),
 }
 
 #[derive(Debug, Clone)]
@@ -41,7 +110,11 @@ pub struct DeleteRsyncBindOutcome {
 #[derive(Debug, Clone)]
 pub enum Task {
     CheckDoctl,
+    CheckTools,
     RefreshDroplets,
+    RefreshDroplet {
+        id: u64,
+    },
     LoadSnapshots,
     LoadSnapshotsDelayed {
         delay_ms: u64,
@@ -50,32 +123,78 @@ pub enum Task {
     LoadSizes,
     LoadImages,
     LoadSshKeys,
+    LoadDomainRecords,
+    LoadContexts,
     CreateDroplet(CreateDropletArgs),
     RestoreDroplet(CreateDropletArgs),
+    /// Polls a just-created droplet until it is active with a public IP.
+    WaitForNetwork {
+        droplet_id: u64,
+        droplet_name: String,
+        /// Created in the background; the poll stays out of the busy overlay.
+        background: bool,
+    },
     SnapshotDelete {
         droplet_id: u64,
         snapshot_name: String,
+        /// Power the droplet off before snapshotting for a consistent filesystem.
+        power_off: bool,
     },
     DeleteDroplet {
         droplet_id: u64,
+        droplet_name: String,
     },
+    /// Pre-flight SSH login for a new binding; success goes on to `StartTunnel`.
+    TestSshConnection(PortBinding),
     StartTunnel(PortBinding),
+    /// Restarts dead tunnels; `alive` is carried through for the summary.
+    ReconnectTunnels {
+        bindings: Vec<PortBinding>,
+        alive: usize,
+    },
+    /// Starts a workspace's stopped tunnels and its syncs. `conflicts` are recorded tunnels
+    /// whose local port a different running binding holds; they are reported as failed.
+    ActivateWorkspace {
+        name: String,
+        bindings: Vec<PortBinding>,
+        conflicts: Vec<(u16, String)>,
+        alive: usize,
+        syncs: Vec<WorkspaceSync>,
+    },
+    /// Stops a workspace's running tunnels, given as `(local_port, pid)`, and pauses its syncs.
+    DeactivateWorkspace {
+        name: String,
+        tunnels: Vec<(u16, u32)>,
+        syncs: Vec<WorkspaceSync>,
+    },
+    /// Probes `(bind_address, local_port)` pairs for a listener.
+    CheckBindingHealth(Vec<(String, u16)>),
     StopTunnel {
         port: u16,
         pid: u32,
     },
-    CreateSyncs {
+    PlanSyncs {
         ssh: SshConfig,
         droplet_name: String,
         paths: Vec<SyncPath>,
     },
+    CreateSyncs {
+        ssh: SshConfig,
+        plan: SyncPlan,
+    },
+    RollbackSyncs {
+        ssh: SshConfig,
+        created: Vec<CreatedSync>,
+    },
     RestoreSyncs {
         ssh: SshConfig,
     },
     LoadSyncs,
     DeleteSync {
         name: String,
+        identifier: Option<String>,
         ssh: Option<SshConfig>,
+        flush: bool,
     },
     CreateRsyncBind {
         bind: RsyncBind,
@@ -84,6 +203,18 @@ pub enum Task {
         bind: RsyncBind,
         direction: RsyncDirection,
     },
+    CheckRsyncRemote {
+        bind: RsyncBind,
+    },
+    /// Sizes a bind's local folder for the delete-local-copy confirmation.
+    MeasureLocalCopy {
+        bind: RsyncBind,
+    },
+    RunRsyncGroup {
+        group: String,
+        binds: Vec<RsyncBind>,
+        direction: RsyncDirection,
+    },
     DeleteRsyncBind {
         bind: RsyncBind,
         delete_local_copy: bool,
@@ -97,29 +228,116 @@ pub enum Task {
         droplet_name: String,
     },
     TerminateAllSyncs,
+    ReconcileSyncs {
+        ssh: SshConfig,
+    },
+    PruneMountEntries {
+        ssh: SshConfig,
+        names: Vec<String>,
+    },
 }
 
 #[derive(Debug)]
 pub enum TaskResult {
-    DoctlCheck(Result<()>),
+    DoctlCheck(Result<AccountInfo>),
+    Tools(ToolAvailability),
     Droplets(Result<Vec<Droplet>>),
-    Snapshots(Result<Vec<Snapshot>>),
+    Droplet(Result<Droplet>),
+    /// `background` marks the delayed reload, which doesn't raise the busy overlay.
+    Snapshots {
+        background: bool,
+        result: Result<Vec<Snapshot>>,
+    },
     Regions(Result<Vec<Region>>),
     Sizes(Result<Vec<Size>>),
     Images(Result<Vec<Image>>),
     SshKeys(Result<Vec<SshKey>>),
-    CreateDroplet(Result<Droplet>),
-    RestoreDroplet(Result<Droplet>),
-    SnapshotDelete(Result<()>),
-    DeleteDroplet(Result<()>),
+    DomainRecords(Result<Vec<DomainRecord>>),
+    Contexts(Result<Vec<DoctlContext>>),
+    CreateDroplet {
+        args: CreateDropletArgs,
+        result: Result<Droplet>,
+    },
+    RestoreDroplet {
+        args: CreateDropletArgs,
+        result: Result<Droplet>,
+    },
+    DropletNetwork {
+        droplet_name: String,
+        background: bool,
+        result: Result<Droplet>,
+    },
+    /// The finished snapshot action; the droplet was deleted after it completed.
+    SnapshotDelete(Result<DropletAction>),
+    /// Which step a multi-step droplet task is on. Not the end of a task; it only updates
+    /// the overlay.
+    ActionProgress {
+        droplet_id: u64,
+        step: String,
+    },
+    /// The machine woke from sleep (see `wake::watch`); not tied to any task.
+    SystemResumed,
+    DeleteDroplet {
+        droplet_id: u64,
+        droplet_name: String,
+        result: Result<()>,
+    },
+    SshConnection {
+        binding: PortBinding,
+        result: Result<()>,
+    },
     StartTunnel(Result<PortBinding>),
+    ReconnectTunnels {
+        outcome: ReconnectOutcome,
+        alive: usize,
+    },
+    BindingHealth(Vec<(u16, bool)>),
+    ActivateWorkspace {
+        name: String,
+        outcome: ReconnectOutcome,
+        alive: usize,
+        /// One report line per sync host, and whether that host succeeded.
+        syncs: Vec<(String, bool)>,
+    },
+    DeactivateWorkspace {
+        name: String,
+        stopped: Vec<u16>,
+        failed: Vec<(u16, String)>,
+        syncs: Vec<(String, bool)>,
+    },
     StopTunnel(Result<u16>),
-    CreateSyncs(Result<usize>),
-    RestoreSyncs(Result<usize>),
+    PlanSyncs {
+        ssh: SshConfig,
+        result: Result<SyncPlan>,
+    },
+    CreateSyncs {
+        ssh: SshConfig,
+        result: Result<usize>,
+    },
+    RollbackSyncs(SyncRollbackOutcome),
+    RestoreSyncs(Result<RestoreSyncsOutcome>),
     Syncs(Result<Vec<SyncSession>>),
     DeleteSync(Result<DeleteSyncOutcome>),
     CreateRsyncBind(Result<RsyncBind>),
-    RunRsync(Result<RsyncRunOutcome>),
+    RunRsync {
+        bind: RsyncBind,
+        direction: RsyncDirection,
+        result: Result<RsyncStats>,
+    },
+    RsyncRemote {
+        bind: RsyncBind,
+        status: RemotePathStatus,
+    },
+    /// The expanded local path and its size in bytes, or `None` if the folder is gone.
+    LocalCopySize {
+        bind: RsyncBind,
+        size: Option<(String, u64)>,
+    },
+    RsyncGroup {
+        group: String,
+        direction: RsyncDirection,
+        results: Vec<(RsyncBind, Result<RsyncStats>)>,
+    },
     DeleteRsyncBind(Result<DeleteRsyncBindOutcome>),
     RemoteDirectories {
         requested_path: String,
@@ -127,63 +345,199 @@ pub enum TaskResult {
     },
     DeleteDropletSyncs(Result<DeleteDropletSyncsOutcome>),
     TerminateAllSyncs(Result<usize>),
+    ReconcileSyncs {
+        ssh: SshConfig,
+        result: Result<ReconcileReport>,
+    },
+    PruneMountEntries(Result<usize>),
 }
 
-pub fn spawn(task: Task, tx: Sender<TaskResult>) {
+/// Runs `task` on its own thread. Long-running tasks stop early once `cancel` fires and
+/// report `cancel::Cancelled` as their error.
+pub fn spawn(task: Task, tx: Sender<TaskResult>, cancel: CancelToken) {
     thread::spawn(move || {
         let result = match task {
             Task::CheckDoctl => TaskResult::DoctlCheck(doctl::check_doctl()),
+            Task::CheckTools => TaskResult::Tools(tools::detect()),
             Task::RefreshDroplets => TaskResult::Droplets(doctl::list_droplets()),
-            Task::LoadSnapshots => TaskResult::Snapshots(doctl::list_snapshots()),
+            Task::RefreshDroplet { id } => TaskResult::Droplet(doctl::get_droplet(id)),
+            Task::LoadSnapshots => TaskResult::Snapshots {
+                background: false,
+                result: doctl::list_snapshots(),
+            },
             Task::LoadSnapshotsDelayed { delay_ms } => {
                 thread::sleep(Duration::from_millis(delay_ms));
-                TaskResult::Snapshots(doctl::list_snapshots())
+                TaskResult::Snapshots {
+                    background: true,
+                    result: doctl::list_snapshots(),
+                }
             }
             Task::LoadRegions => TaskResult::Regions(doctl::list_regions()),
             Task::LoadSizes => TaskResult::Sizes(doctl::list_sizes()),
             Task::LoadImages => TaskResult::Images(doctl::list_images()),
             Task::LoadSshKeys => TaskResult::SshKeys(doctl::list_ssh_keys()),
-            Task::CreateDroplet(args) => TaskResult::CreateDroplet(doctl::create_droplet(&args)),
+            Task::LoadDomainRecords => TaskResult::DomainRecords(doctl::list_all_domain_records()),
+            Task::LoadContexts => TaskResult::Contexts(doctl::list_contexts()),
+            Task::CreateDroplet(args) => {
+                let result = doctl::create_droplet(&args, &cancel);
+                TaskResult::CreateDroplet { args, result }
+            }
             Task::RestoreDroplet(args) => {
-                TaskResult::RestoreDroplet(doctl::create_droplet_from_snapshot(&args))
+                let result = doctl::create_droplet_from_snapshot(&args, &cancel);
+                TaskResult::RestoreDroplet { args, result }
             }
+            Task::WaitForNetwork {
+                droplet_id,
+                droplet_name,
+                background,
+            } => TaskResult::DropletNetwork {
+                result: wait_for_network(droplet_id, &droplet_name, background, &cancel),
+                droplet_name,
+                background,
+            },
             Task::SnapshotDelete {
                 droplet_id,
                 snapshot_name,
-            } => TaskResult::SnapshotDelete(
-                doctl::snapshot_droplet(droplet_id, &snapshot_name)
-                    .and_then(|_| doctl::delete_droplet(droplet_id)),
+                power_off,
+            } => TaskResult::SnapshotDelete(snapshot_then_delete(
+                droplet_id,
+                &snapshot_name,
+                power_off,
+                &cancel,
+                &tx,
+            )),
+            Task::DeleteDroplet {
+                droplet_id,
+                droplet_name,
+            } => TaskResult::DeleteDroplet {
+                droplet_id,
+                droplet_name,
+                result: doctl::delete_droplet(droplet_id),
+            },
+            Task::CheckBindingHealth(targets) => TaskResult::BindingHealth(
+                targets
+                    .into_iter()
+                    .map(|(address, port)| (port, ports::is_port_serving(&address, port)))
+                    .collect(),
             ),
-            Task::DeleteDroplet { droplet_id } => {
-                TaskResult::DeleteDroplet(doctl::delete_droplet(droplet_id))
-            }
+            Task::TestSshConnection(binding) => TaskResult::SshConnection {
+                result: ports::check_ssh_login(&binding),
+                binding,
+            },
             Task::StartTunnel(mut binding) => {
                 let res = ports::start_tunnel(&mut binding).map(|_| binding);
                 TaskResult::StartTunnel(res)
             }
+            Task::ReconnectTunnels { bindings, alive } => TaskResult::ReconnectTunnels {
+                outcome: ports::reconnect_tunnels(bindings),
+                alive,
+            },
+            Task::ActivateWorkspace {
+                name,
+                bindings,
+                conflicts,
+                alive,
+                syncs,
+            } => {
+                let mut outcome = ports::reconnect_tunnels(bindings);
+                outcome.failed.extend(conflicts);
+                TaskResult::ActivateWorkspace {
+                    name,
+                    outcome,
+                    alive,
+                    syncs: mutagen::activate_workspace_syncs(&syncs),
+                }
+            }
+            Task::DeactivateWorkspace {
+                name,
+                tunnels,
+                syncs,
+            } => {
+                let (mut stopped, mut failed) = (Vec::new(), Vec::new());
+                for (port, pid) in tunnels {
+                    match ports::stop_tunnel(pid) {
+                        Ok(()) => stopped.push(port),
+                        Err(err) => failed.push((port, err.to_string())),
+                    }
+                }
+                TaskResult::DeactivateWorkspace {
+                    name,
+                    stopped,
+                    failed,
+                    syncs: mutagen::pause_workspace_syncs(&syncs),
+                }
+            }
             Task::StopTunnel { port, pid } => {
                 let res = ports::stop_tunnel(pid).map(|_| port);
                 TaskResult::StopTunnel(res)
             }
-            Task::CreateSyncs {
+            Task::PlanSyncs {
                 ssh,
                 droplet_name,
                 paths,
-            } => TaskResult::CreateSyncs(mutagen::create_syncs(&ssh, &droplet_name, paths)),
+            } => {
+                let result = mutagen::plan_syncs(&ssh, &droplet_name, paths);
+                TaskResult::PlanSyncs { ssh, result }
+            }
+            Task::CreateSyncs { ssh, plan } => {
+                let result = mutagen::create_syncs(&ssh, &plan);
+                TaskResult::CreateSyncs { ssh, result }
+            }
+            Task::RollbackSyncs { ssh, created } => {
+                TaskResult::RollbackSyncs(mutagen::rollback_syncs(&ssh, &created))
+            }
             Task::RestoreSyncs { ssh } => TaskResult::RestoreSyncs(mutagen::restore_syncs(&ssh)),
             Task::LoadSyncs => TaskResult::Syncs(mutagen::list_syncs()),
-            Task::DeleteSync { name, ssh } => {
-                TaskResult::DeleteSync(mutagen::delete_sync(&name, ssh.as_ref()))
-            }
+            Task::DeleteSync {
+                name,
+                identifier,
+                ssh,
+                flush,
+            } => TaskResult::DeleteSync(mutagen::delete_sync(
+                &name,
+                identifier.as_deref(),
+                ssh.as_ref(),
+                flush,
+            )),
+            Task::RunRsyncGroup {
+                group,
+                binds,
+                direction,
+            } => TaskResult::RsyncGroup {
+                group,
+                direction,
+                results: binds
+                    .into_iter()
+                    .map(|bind| {
+                        let result = run_rsync(&bind, direction, &cancel);
+                        (bind, result)
+                    })
+                    .collect(),
+            },
+            Task::CheckRsyncRemote { bind } => TaskResult::RsyncRemote {
+                status: check_remote_path(&bind),
+                bind,
+            },
+            Task::MeasureLocalCopy { bind } => TaskResult::LocalCopySize {
+                size: local_copy_size(&bind.local_path),
+                bind,
+            },
             Task::CreateRsyncBind { bind } => TaskResult::CreateRsyncBind(create_rsync_bind(&bind)),
-            Task::RunRsync { bind, direction } => TaskResult::RunRsync(run_rsync(&bind, direction)),
+            Task::RunRsync { bind, direction } => {
+                let result = run_rsync(&bind, direction, &cancel);
+                TaskResult::RunRsync {
+                    bind,
+                    direction,
+                    result,
+                }
+            }
             Task::DeleteRsyncBind {
                 bind,
                 delete_local_copy,
             } => TaskResult::DeleteRsyncBind(delete_rsync_bind(bind, delete_local_copy)),
             Task::ListRemoteDirectories { ssh, path } => TaskResult::RemoteDirectories {
                 requested_path: path.clone(),
-                result: list_remote_directories(&ssh, &path),
+                result: list_remote_directories(&ssh, &path, &cancel),
             },
             Task::DeleteDropletSyncs { ssh, droplet_name } => TaskResult::DeleteDropletSyncs(
                 mutagen::delete_syncs_for_droplet(&ssh, &droplet_name),
@@ -191,11 +545,120 @@ pub fn spawn(task: Task, tx: Sender<TaskResult>) {
             Task::TerminateAllSyncs => {
                 TaskResult::TerminateAllSyncs(mutagen::terminate_all_syncs())
             }
+            Task::ReconcileSyncs { ssh } => {
+                let result = mutagen::reconcile(&ssh);
+                TaskResult::ReconcileSyncs { ssh, result }
+            }
+            Task::PruneMountEntries { ssh, names } => {
+                TaskResult::PruneMountEntries(mutagen::delete_mount_entries(&ssh, &names))
+            }
         };
         let _ = tx.send(result);
     });
 }
 
+/// Pause between `droplet-action get` polls.
+const ACTION_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Failed polls in a row tolerated before giving up on an action that may still be running.
+const MAX_ACTION_POLL_ERRORS: u32 = 5;
+
+fn snapshot_then_delete(
+    droplet_id: u64,
+    snapshot_name: &str,
+    power_off: bool,
+    cancel: &CancelToken,
+    tx: &Sender<TaskResult>,
+) -> Result<DropletAction> {
+    let steps = if power_off { 3 } else { 2 };
+    let mut step = 0;
+    let report = |label: String| {
+        let _ = tx.send(TaskResult::ActionProgress {
+            droplet_id,
+            step: label,
+        });
+    };
+    if power_off {
+        step += 1;
+        report(format!("Step {step}/{steps}: powering off"));
+        doctl::power_off_droplet(droplet_id, cancel)?;
+    }
+    step += 1;
+    report(format!("Step {step}/{steps}: starting snapshot"));
+    let mut action = doctl::start_snapshot(droplet_id, snapshot_name, cancel)?;
+    let mut poll_errors = 0;
+    while !action.is_done() {
+        report(format!(
+            "Step {step}/{steps}: snapshot action #{} {}",
+            action.id, action.status
+        ));
+        cancel.sleep(ACTION_POLL_INTERVAL)?;
+        // The snapshot keeps going server-side, so a busy or rate-limited API only delays
+        // the next look; give up once polling keeps failing.
+        match doctl::get_droplet_action(droplet_id, action.id) {
+            Ok(polled) => {
+                action = polled;
+                poll_errors = 0;
+            }
+            Err(err) => {
+                poll_errors += 1;
+                if poll_errors >= MAX_ACTION_POLL_ERRORS {
+                    return Err(err.context(format!(
+                        "Lost track of snapshot action #{} after {poll_errors} failed polls; the droplet was not deleted",
+                        action.id
+                    )));
+                }
+            }
+        }
+    }
+    if action.status != "completed" {
+        return Err(anyhow!(
+            "Snapshot action #{} ended as '{}'; the droplet was not deleted",
+            action.id,
+            action.status
+        ));
+    }
+    report(format!("Step {steps}/{steps}: deleting droplet"));
+    doctl::delete_droplet(droplet_id)?;
+    Ok(action)
+}
+
+/// Pauses between polls while a new droplet waits for its network, about a minute in total.
+const NETWORK_WAIT_BACKOFF_SECS: &[u64] = &[2, 4, 8, 15, 30];
+
+/// The same poll for a background create, about five minutes in total; nobody is waiting
+/// on it, and slow regions regularly need more than a minute to hand out an address.
+const BACKGROUND_NETWORK_WAIT_BACKOFF_SECS: &[u64] = &[2, 4, 8, 15, 30, 30, 30, 60, 60, 60];
+
+fn wait_for_network(
+    droplet_id: u64,
+    droplet_name: &str,
+    background: bool,
+    cancel: &CancelToken,
+) -> Result<Droplet> {
+    let backoff = if background {
+        BACKGROUND_NETWORK_WAIT_BACKOFF_SECS
+    } else {
+        NETWORK_WAIT_BACKOFF_SECS
+    };
+    let mut last_status = None;
+    for delay in backoff {
+        cancel.sleep(Duration::from_secs(*delay))?;
+        // A failed poll is as likely to be the API being busy as anything else; keep trying.
+        let Ok(droplet) = doctl::get_droplet(droplet_id) else {
+            continue;
+        };
+        if droplet.has_network() {
+            return Ok(droplet);
+        }
+        last_status = Some(droplet.status);
+    }
+    Err(anyhow!(
+        "Droplet '{droplet_name}' is still {} without a public IP; refresh later with g",
+        last_status.as_deref().unwrap_or("unreachable")
+    ))
+}
+
 fn create_rsync_bind(bind: &RsyncBind) -> Result<RsyncBind> {
     let local_path = expand_local_path(&bind.local_path);
     let local = Path::new(&local_path);
@@ -224,16 +687,29 @@ fn create_rsync_bind(bind: &RsyncBind) -> Result<RsyncBind> {
     Ok(created)
 }
 
-fn run_rsync(bind: &RsyncBind, direction: RsyncDirection) -> Result<RsyncRunOutcome> {
+fn run_rsync(
+    bind: &RsyncBind,
+    direction: RsyncDirection,
+    cancel: &CancelToken,
+) -> Result<RsyncStats> {
     let local_path = expand_local_path(&bind.local_path);
     fs::create_dir_all(&local_path)
         .with_context(|| format!("Failed to ensure local folder '{local_path}'"))?;
 
     let key_path = expand_local_path(&bind.ssh_key_path);
-    let remote = format!("{}@{}:{}", bind.ssh_user, bind.host, bind.remote_path);
+    let remote = format!(
+        "{}@{}:{}",
+        bind.ssh_user,
+        host_for_remote_spec(&bind.host),
+        bind.remote_path
+    );
+    let identity = if bind.use_agent {
+        String::new()
+    } else {
+        format!("-i {} ", shell_escape_arg(&key_path))
+    };
     let ssh_cmd = format!(
-        "ssh -i {} -p {} -o BatchMode=yes -o ServerAliveInterval=15 -o ServerAliveCountMax=3",
-        shell_escape_arg(&key_path),
+        "ssh {identity}-p {} -o BatchMode=yes -o ServerAliveInterval=15 -o ServerAliveCountMax=3",
         bind.ssh_port
     );
 
@@ -242,18 +718,18 @@ fn run_rsync(bind: &RsyncBind, direction: RsyncDirection) -> Result<RsyncRunOutc
         RsyncDirection::Down => (format!("{remote}/"), format!("{}/", local_path)),
     };
 
-    let output = Command::new("rsync")
-        .arg("-az")
+    let mut cmd = Command::new("rsync");
+    cmd.args(bind.rsync_flags())
         .arg("--human-readable")
+        .arg("--stats")
         .arg("--exclude=node_modules")
         .arg("--exclude=target")
         .arg("--exclude=/.cargo*")
         .arg("-e")
         .arg(ssh_cmd)
         .arg(source)
-        .arg(dest)
-        .output()
-        .context("Failed to execute rsync")?;
+        .arg(dest);
+    let output = cancel::output(&mut cmd, cancel).context("Failed to execute rsync")?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
@@ -274,12 +750,7 @@ fn run_rsync(bind: &RsyncBind, direction: RsyncDirection) -> Result<RsyncRunOutc
         ));
     }
 
-    let mut result_bind = bind.clone();
-    result_bind.local_path = local_path;
-    Ok(RsyncRunOutcome {
-        bind: result_bind,
-        direction,
-    })
+    Ok(RsyncStats::parse(&String::from_utf8_lossy(&output.stdout)))
 }
 
 fn delete_rsync_bind(bind: RsyncBind, delete_local_copy: bool) -> Result<DeleteRsyncBindOutcome> {
@@ -288,6 +759,9 @@ fn delete_rsync_bind(bind: RsyncBind, delete_local_copy: bool) -> Result<DeleteR
     if delete_local_copy {
         let path = Path::new(&local_path);
         if path.exists() {
+            if let Some(problem) = local_delete_problem(&bind.local_path) {
+                return Err(anyhow!("Refusing to delete local copy: {problem}"));
+            }
             if path.is_dir() {
                 fs::remove_dir_all(path)
                     .with_context(|| format!("Failed to remove local folder '{local_path}'"))?;
@@ -304,7 +778,51 @@ fn delete_rsync_bind(bind: RsyncBind, delete_local_copy: bool) -> Result<DeleteR
     })
 }
 
-fn list_remote_directories(ssh: &SshConfig, path: &str) -> Result<RemoteDirectoryListing> {
+/// Runs `test -d` on the bind's remote folder over SSH.
+fn check_remote_path(bind: &RsyncBind) -> RemotePathStatus {
+    let output = Command::new("ssh")
+        .args(ssh_identity_args(
+            &expand_local_path(&bind.ssh_key_path),
+            bind.use_agent,
+        ))
+        .arg("-p")
+        .arg(bind.ssh_port.to_string())
+        .arg("-o")
+        .arg("BatchMode=yes")
+        .arg("-o")
+        .arg("ConnectTimeout=5")
+        .arg(format!("{}@{}", bind.ssh_user, bind.host))
+        .arg(format!("test -d {}", remote_path_arg(&bind.remote_path)))
+        .logged_output();
+    match output {
+        Ok(output) if output.status.success() => RemotePathStatus::Exists,
+        Ok(output) if output.status.code() == Some(1) => RemotePathStatus::Missing,
+        Ok(output) => RemotePathStatus::Unreachable(
+            String::from_utf8_lossy(&output.stderr)
+                .lines()
+                .next()
+                .unwrap_or("ssh failed")
+                .trim()
+                .to_string(),
+        ),
+        Err(err) => RemotePathStatus::Unreachable(err.to_string()),
+    }
+}
+
+/// Quotes a remote path for the remote shell, leaving a leading `~` to expand.
+fn remote_path_arg(path: &str) -> String {
+    match path.strip_prefix("~/") {
+        Some(rest) => format!("~/{}", shell_escape(rest)),
+        None if path == "~" => "~".to_string(),
+        None => shell_escape(path),
+    }
+}
+
+fn list_remote_directories(
+    ssh: &SshConfig,
+    path: &str,
+    cancel: &CancelToken,
+) -> Result<RemoteDirectoryListing> {
     let key_path = expand_local_path(&ssh.key_path);
     let remote_cmd = format!(
         "TARGET={}; \
@@ -315,17 +833,15 @@ fn list_remote_directories(ssh: &SshConfig, path: &str) -> Result<RemoteDirector
         shell_escape(path)
     );
 
-    let output = Command::new("ssh")
-        .arg("-i")
-        .arg(&key_path)
+    let mut cmd = Command::new("ssh");
+    cmd.args(ssh_identity_args(&key_path, ssh.use_agent))
         .arg("-p")
         .arg(ssh.port.to_string())
         .arg("-o")
         .arg("BatchMode=yes")
         .arg(format!("{}@{}", ssh.user, ssh.host))
-        .arg(remote_cmd)
-        .output()
-        .context("Failed to execute ssh")?;
+        .arg(remote_cmd);
+    let output = cancel::output(&mut cmd, cancel)?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -354,6 +870,106 @@ fn list_remote_directories(ssh: &SshConfig, path: &str) -> Result<RemoteDirector
     })
 }
 
+/// True when the bind's local folder exists and contains anything a pull could overwrite.
+pub(crate) fn local_folder_has_files(path: &str) -> bool {
+    let local_path = expand_local_path(path);
+    let local = Path::new(&local_path);
+    local.is_dir() && !is_dir_empty(local).unwrap_or(true)
+}
+
+/// The bind's local folder with `~` expanded and its size in bytes, or `None` if it's gone.
+pub(crate) fn local_copy_size(local_path: &str) -> Option<(String, u64)> {
+    let expanded = expand_local_path(local_path);
+    let path = Path::new(&expanded);
+    path.exists().then(|| (expanded.clone(), path_size(path)))
+}
+
+/// Bytes under `path`, not following symlinks; unreadable entries count as zero.
+fn path_size(path: &Path) -> u64 {
+    let Ok(meta) = fs::symlink_metadata(path) else {
+        return 0;
+    };
+    if !meta.is_dir() {
+        return meta.len();
+    }
+    fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| path_size(&entry.path()))
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
+/// `unsafe_delete_target` for a bind's local folder, with `~`, symlinks, and `..` resolved.
+pub(crate) fn local_delete_problem(local_path: &str) -> Option<String> {
+    let resolve = |path: &Path| fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let path = resolve(Path::new(&expand_local_path(local_path)));
+    let home = std::env::var_os("HOME").map(|home| resolve(Path::new(&home)));
+    let config_dir = crate::config::state_file_path().ok().and_then(|state| {
+        state
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .map(resolve)
+    });
+    unsafe_delete_target(&path, home.as_deref(), config_dir.as_deref())
+}
+
+/// Why removing `path` would take more than a bind's copy with it: the filesystem root, the
+/// home directory, or a folder holding the config directory. `None` when it's safe.
+fn unsafe_delete_target(
+    path: &Path,
+    home: Option<&Path>,
+    config_dir: Option<&Path>,
+) -> Option<String> {
+    if path.parent().is_none() {
+        Some(format!("'{}' is the filesystem root", path.display()))
+    } else if home.is_some_and(|home| path == home) {
+        Some(format!("'{}' is your home directory", path.display()))
+    } else if config_dir.is_some_and(|dir| dir.starts_with(path)) {
+        Some(format!(
+            "'{}' holds the config directory and state file",
+            path.display()
+        ))
+    } else {
+        None
+    }
+}
+
+/// Why `path` won't work as an SSH key once `~` is expanded, or `None` when it names a file.
+/// Callers only warn: an agent or ssh's default identities may still log in.
+pub(crate) fn ssh_key_path_problem(path: &str) -> Option<String> {
+    if path.trim().is_empty() {
+        return Some("No SSH key path set".to_string());
+    }
+    let expanded = expand_local_path(path);
+    match fs::metadata(&expanded) {
+        Ok(meta) if meta.is_file() => None,
+        Ok(_) => Some(format!("SSH key {expanded} is not a file")),
+        Err(_) => Some(format!(
+            "SSH key {expanded} not found; ssh will rely on the agent"
+        )),
+    }
+}
+
+/// `ssh_key_path_problem` for key-file logins; with the agent on, the key path is unused
+/// and the agent's socket has to be reachable instead.
+pub(crate) fn ssh_identity_problem(key_path: &str, use_agent: bool) -> Option<String> {
+    if use_agent {
+        agent_socket_problem(std::env::var("SSH_AUTH_SOCK").ok().as_deref())
+    } else {
+        ssh_key_path_problem(key_path)
+    }
+}
+
+fn agent_socket_problem(socket: Option<&str>) -> Option<String> {
+    match socket.map(str::trim) {
+        Some(socket) if !socket.is_empty() => None,
+        _ => Some("SSH agent selected but SSH_AUTH_SOCK is not set; start ssh-agent".to_string()),
+    }
+}
+
 fn is_dir_empty(path: &Path) -> Result<bool> {
     let mut entries = fs::read_dir(path)
         .with_context(|| format!("Failed to read directory '{}'", path.display()))?;
@@ -377,7 +993,7 @@ fn expand_local_path(path: &str) -> String {
     cwd.join(p).to_string_lossy().to_string()
 }
 
-fn shell_escape(value: &str) -> String {
+pub(crate) fn shell_escape(value: &str) -> String {
     if value.is_empty() {
         "''".to_string()
     } else {
@@ -392,3 +1008,133 @@ fn shell_escape_arg(value: &str) -> String {
         format!("'{}'", value.replace('\'', "'\"'\"'"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        RsyncStats, agent_socket_problem, list_remote_directories, path_size, remote_path_arg,
+        ssh_identity_problem, ssh_key_path_problem, unsafe_delete_target,
+    };
+    use crate::cancel::{CancelToken, is_cancelled_error};
+    use crate::mutagen::SshConfig;
+    use std::path::Path;
+
+    #[test]
+    fn unsafe_delete_target_refuses_root_home_and_config_parents() {
+        let home = Some(Path::new("/home/me"));
+        let config = Some(Path::new("/home/me/.config/doctl-tui"));
+        let check = |path: &str| unsafe_delete_target(Path::new(path), home, config);
+
+        assert!(check("/").unwrap().contains("filesystem root"));
+        assert!(check("/home/me").unwrap().contains("home directory"));
+        assert!(check("/home/me/").unwrap().contains("home directory"));
+        assert!(check("/home").unwrap().contains("config directory"));
+        assert!(
+            check("/home/me/.config")
+                .unwrap()
+                .contains("config directory")
+        );
+        assert!(check("/home/me/.config/doctl-tui").is_some());
+
+        assert_eq!(check("/home/me/code/app"), None);
+        assert_eq!(check("/home/me/.config/other"), None);
+        assert_eq!(
+            unsafe_delete_target(Path::new("/srv/app"), None, None),
+            None
+        );
+    }
+
+    #[test]
+    fn path_size_sums_files_below_a_folder() {
+        let dir = std::env::temp_dir().join(format!("doctl-tui-size-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("nested")).unwrap();
+        std::fs::write(dir.join("a.txt"), b"hello").unwrap();
+        std::fs::write(dir.join("nested/b.txt"), b"world!!").unwrap();
+        assert_eq!(path_size(&dir), 12);
+        assert_eq!(path_size(&dir.join("a.txt")), 5);
+        assert_eq!(path_size(&dir.join("missing")), 0);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn ssh_key_path_problem_expands_and_requires_a_file() {
+        let dir = std::env::temp_dir().join(format!("doctl-tui-key-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let key = dir.join("id_ed25519");
+        std::fs::write(&key, "key").unwrap();
+
+        assert_eq!(ssh_key_path_problem(&format!(" {} ", key.display())), None);
+        let missing = ssh_key_path_problem(&dir.join("missing").to_string_lossy()).unwrap();
+        assert!(missing.contains("not found"), "{missing}");
+        let folder = ssh_key_path_problem(&dir.to_string_lossy()).unwrap();
+        assert!(folder.contains("is not a file"), "{folder}");
+        assert!(ssh_key_path_problem("  ").is_some());
+        if let Ok(home) = std::env::var("HOME") {
+            let tilde = ssh_key_path_problem("~").unwrap();
+            assert_eq!(tilde, format!("SSH key {home} is not a file"));
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn agent_logins_check_the_socket_instead_of_the_key() {
+        assert!(agent_socket_problem(None).is_some());
+        assert!(agent_socket_problem(Some(" ")).is_some());
+        assert_eq!(agent_socket_problem(Some("/tmp/ssh-XXXX/agent.1")), None);
+        let missing_key = "/nonexistent/doctl-tui/id_rsa";
+        assert!(ssh_identity_problem(missing_key, false).is_some());
+        assert_eq!(
+            ssh_identity_problem(missing_key, true),
+            agent_socket_problem(std::env::var("SSH_AUTH_SOCK").ok().as_deref())
+        );
+    }
+
+    #[test]
+    fn superseded_remote_listing_is_cancelled() {
+        let root = CancelToken::default();
+        let listing = root.child();
+        root.cancel_all();
+        let ssh = SshConfig {
+            user: "root".to_string(),
+            host: "203.0.113.10".to_string(),
+            port: 22,
+            key_path: "/tmp/id_rsa".to_string(),
+            use_agent: false,
+        };
+        let err = list_remote_directories(&ssh, "/srv", &listing).expect_err("cancelled");
+        assert!(is_cancelled_error(&err));
+    }
+
+    #[test]
+    fn remote_path_arg_quotes_but_keeps_home_expansion() {
+        assert_eq!(remote_path_arg("/srv/my app"), "'/srv/my app'");
+        assert_eq!(remote_path_arg("~/site"), "~/'site'");
+        assert_eq!(remote_path_arg("~"), "~");
+    }
+
+    #[test]
+    fn rsync_stats_parse_summary_block() {
+        let output = "\
+Number of files: 120 (reg: 100, dir: 20)
+Number of created files: 0
+Number of deleted files: 0
+Number of regular files transferred: 1,203
+Total file size: 4.56M bytes
+Total transferred file size: 1.23K bytes
+Literal data: 1.23K bytes
+
+sent 2.01K bytes  received 64 bytes  4.15K bytes/sec
+total size is 4.56M  speedup is 2,197.11
+";
+        let stats = RsyncStats::parse(output);
+        assert_eq!(stats.files.as_deref(), Some("120 (reg: 100, dir: 20)"));
+        assert_eq!(stats.files_transferred, Some(1203));
+        assert_eq!(stats.transferred_size.as_deref(), Some("1.23K"));
+        assert_eq!(stats.speedup.as_deref(), Some("2,197.11"));
+        assert_eq!(
+            stats.summary(),
+            "1203 files transferred (1.23K), speedup 2,197.11"
+        );
+        assert_eq!(RsyncStats::parse("").summary(), "transfer complete");
+    }
+}
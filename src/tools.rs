@@ -0,0 +1,95 @@
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ToolAvailability {
+    pub doctl: bool,
+    pub mutagen: bool,
+    pub rsync: bool,
+    pub ssh: bool,
+}
+
+impl ToolAvailability {
+    pub fn missing(&self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if !self.doctl {
+            missing.push("doctl");
+        }
+        if !self.mutagen {
+            missing.push("mutagen");
+        }
+        if !self.rsync {
+            missing.push("rsync");
+        }
+        if !self.ssh {
+            missing.push("ssh");
+        }
+        missing
+    }
+}
+
+impl Default for ToolAvailability {
+    fn default() -> Self {
+        Self {
+            doctl: true,
+            mutagen: true,
+            rsync: true,
+            ssh: true,
+        }
+    }
+}
+
+pub fn detect() -> ToolAvailability {
+    ToolAvailability {
+        doctl: find_on_path("doctl").is_some(),
+        mutagen: find_on_path("mutagen").is_some(),
+        rsync: find_on_path("rsync").is_some(),
+        ssh: find_on_path("ssh").is_some(),
+    }
+}
+
+/// The desktop's open-a-folder command, or `None` over SSH or without a graphical session.
+pub fn file_manager() -> Option<&'static str> {
+    let env_set = |name: &str| std::env::var_os(name).is_some_and(|value| !value.is_empty());
+    if env_set("SSH_CONNECTION") {
+        return None;
+    }
+    if cfg!(target_os = "macos") {
+        return Some("open");
+    }
+    let graphical = env_set("DISPLAY") || env_set("WAYLAND_DISPLAY");
+    (graphical && find_on_path("xdg-open").is_some()).then_some("xdg-open")
+}
+
+pub fn find_on_path(program: &str) -> Option<PathBuf> {
+    let paths = std::env::var_os("PATH")?;
+    std::env::split_paths(&paths)
+        .map(|dir| dir.join(program))
+        .find(|candidate| is_executable(candidate))
+}
+
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata()
+        .map(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_on_path_misses_unknown_program() {
+        assert!(find_on_path("definitely-not-a-real-tool-4f2a").is_none());
+    }
+
+    #[test]
+    fn missing_lists_unavailable_tools() {
+        let tools = ToolAvailability {
+            mutagen: false,
+            rsync: false,
+            ..ToolAvailability::default()
+        };
+        assert_eq!(tools.missing(), vec!["mutagen", "rsync"]);
+    }
+}
@@ -10,15 +10,25 @@ use ratatui::Terminal;
 use ratatui::layout::{Alignment, Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap};
+use ratatui::widgets::{
+    Block, Borders, Clear, List, ListItem, Paragraph, Scrollbar, ScrollbarOrientation,
+    ScrollbarState, Wrap,
+};
 use std::io;
 
 use crate::app::{
-    App, BindForm, CreateForm, DeleteRsyncBindForm, Modal, Notice, Picker, RemoteBrowserForm,
-    RestoreForm, RsyncBindActionsForm, RsyncBindForm, Screen, SnapshotForm, SyncForm, ToastLevel,
+    App, BindForm, BindingLabelForm, ConnectCommandForm, CreateForm, DeleteRsyncBindForm,
+    DropletDetail, ListHitArea, ManualRsyncBindFields, Modal, Notice, Picker, ReconcileForm,
+    RemoteBrowserForm, RestoreForm, RsyncBindActionsForm, RsyncBindForm, RsyncBindGroupForm,
+    Screen, Selection, SnapshotForm, SyncForm, ToastLevel, format_age, ssh_key_summary,
 };
+use crate::config;
 use crate::input::TextInput;
+use crate::logging::{self, LoggedCommand};
+use crate::model::{BindInterface, RsyncBind, ThemeConfig, is_loopback_address};
+use crate::mutagen::SyncState;
 use crate::ports;
+use crate::tasks::{RemotePathStatus, shell_escape};
 
 pub struct Theme {
     pub bg: Color,
@@ -42,6 +52,27 @@ impl Theme {
             border: Color::Rgb(60, 60, 70),
         }
     }
+
+    /// Applies configured colors, keeping the default for any missing or invalid entry.
+    pub fn from_config(config: &ThemeConfig) -> Self {
+        let defaults = Self::default();
+        let pick = |value: &Option<String>, fallback: Color| {
+            value
+                .as_deref()
+                .and_then(config::parse_hex_color)
+                .map(|(r, g, b)| Color::Rgb(r, g, b))
+                .unwrap_or(fallback)
+        };
+        Self {
+            bg: pick(&config.bg, defaults.bg),
+            muted: pick(&config.muted, defaults.muted),
+            accent: pick(&config.accent, defaults.accent),
+            success: pick(&config.success, defaults.success),
+            warning: pick(&config.warning, defaults.warning),
+            error: pick(&config.error, defaults.error),
+            border: pick(&config.border, defaults.border),
+        }
+    }
 }
 
 pub fn setup_terminal() -> anyhow::Result<Terminal<ratatui::backend::CrosstermBackend<io::Stdout>>>
@@ -74,7 +105,9 @@ pub fn run_interactive(args: &[&str]) -> anyhow::Result<()> {
     stdout.execute(DisableMouseCapture)?;
     stdout.execute(crossterm::cursor::Show)?;
 
-    let status = std::process::Command::new("doctl").args(args).status()?;
+    let status = std::process::Command::new("doctl")
+        .args(args)
+        .logged_status()?;
 
     stdout.execute(EnterAlternateScreen)?;
     stdout.execute(EnableMouseCapture)?;
@@ -88,6 +121,15 @@ pub fn run_interactive(args: &[&str]) -> anyhow::Result<()> {
 }
 
 pub fn run_external(program: &str, args: &[String]) -> anyhow::Result<()> {
+    run_released(program, args, false)
+}
+
+/// Like `run_external`, but keeps the output on screen until Enter is pressed.
+pub fn run_external_and_wait(program: &str, args: &[String]) -> anyhow::Result<()> {
+    run_released(program, args, true)
+}
+
+fn run_released(program: &str, args: &[String], wait: bool) -> anyhow::Result<()> {
     disable_raw_mode()?;
     let mut stdout = io::stdout();
     stdout.execute(LeaveAlternateScreen)?;
@@ -96,8 +138,16 @@ pub fn run_external(program: &str, args: &[String]) -> anyhow::Result<()> {
 
     let status = std::process::Command::new(program)
         .args(args)
-        .status()
+        .logged_status()
         .with_context(|| format!("Failed to execute {program}"))?;
+    if wait {
+        println!("\nPress Enter to return");
+        let mut line = String::new();
+        // A failed read just skips the pause; the terminal still has to be restored.
+        if let Err(err) = io::stdin().read_line(&mut line) {
+            logging::log(format!("waiting for Enter failed: {err}"));
+        }
+    }
 
     stdout.execute(EnterAlternateScreen)?;
     stdout.execute(EnableMouseCapture)?;
@@ -110,24 +160,89 @@ pub fn run_external(program: &str, args: &[String]) -> anyhow::Result<()> {
     Ok(())
 }
 
-pub fn draw(frame: &mut Frame, app: &App) {
-    let theme = Theme::default();
+pub fn inside_tmux() -> bool {
+    std::env::var("TMUX")
+        .map(|value| !value.is_empty())
+        .unwrap_or(false)
+}
+
+pub fn run_in_tmux_window(program: &str, args: &[String]) -> anyhow::Result<()> {
+    // Trace the command the window runs rather than the tmux call, whose single shell
+    // string would slip key paths past the log's argument redaction.
+    let mut inner = std::process::Command::new(program);
+    inner.args(args);
+    logging::command(&inner);
+    let mut command = shell_escape(program);
+    for arg in args {
+        command.push(' ');
+        command.push_str(&shell_escape(arg));
+    }
+    let output = std::process::Command::new("tmux")
+        .arg("new-window")
+        .arg(command)
+        .output()
+        .context("Failed to execute tmux")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow!("tmux new-window failed: {}", stderr.trim()));
+    }
+    Ok(())
+}
+
+/// Copies text with the first clipboard tool found on PATH.
+pub fn copy_to_clipboard(text: &str) -> anyhow::Result<()> {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    let candidates: [(&str, &[&str]); 4] = [
+        ("pbcopy", &[]),
+        ("wl-copy", &[]),
+        ("xclip", &["-selection", "clipboard"]),
+        ("xsel", &["--clipboard", "--input"]),
+    ];
+    for (program, args) in candidates {
+        if crate::tools::find_on_path(program).is_none() {
+            continue;
+        }
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .logged_spawn()
+            .with_context(|| format!("Failed to execute {program}"))?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(text.as_bytes())?;
+        }
+        let status = child.wait()?;
+        if !status.success() {
+            return Err(anyhow!("{program} exited with {status}"));
+        }
+        return Ok(());
+    }
+    Err(anyhow!(
+        "No clipboard tool found (pbcopy, wl-copy, xclip, xsel)"
+    ))
+}
+
+pub fn draw(frame: &mut Frame, app: &App, theme: &Theme) {
+    app.list_area.set(None);
     let area = frame.size();
     frame.render_widget(Block::default().style(Style::default().bg(theme.bg)), area);
 
     match app.screen {
-        Screen::Home => draw_home(frame, app, &theme),
-        Screen::Bindings => draw_bindings(frame, app, &theme),
-        Screen::Syncs => draw_syncs(frame, app, &theme),
-        Screen::RsyncBinds => draw_rsync_binds(frame, app, &theme),
+        Screen::Home => draw_home(frame, app, theme),
+        Screen::Bindings => draw_bindings(frame, app, theme),
+        Screen::Syncs => draw_syncs(frame, app, theme),
+        Screen::RsyncBinds => draw_rsync_binds(frame, app, theme),
     }
 
     if let Some(modal) = &app.modal {
-        draw_modal(frame, app, modal, &theme);
+        draw_modal(frame, app, modal, theme);
     }
 
-    draw_toast(frame, app, &theme);
-    draw_loading_overlay(frame, app, &theme);
+    draw_toast(frame, app, theme);
+    draw_loading_overlay(frame, app, theme);
 }
 
 fn draw_home(frame: &mut Frame, app: &App, theme: &Theme) {
@@ -168,38 +283,121 @@ fn draw_bindings(frame: &mut Frame, app: &App, theme: &Theme) {
         .border_style(Style::default().fg(theme.border))
         .title("Port Bindings")
         .title_alignment(Alignment::Left);
-    let title = Paragraph::new(Line::from(vec![
+    let active_count = app
+        .state
+        .bindings
+        .iter()
+        .filter(|binding| {
+            binding
+                .tunnel_pid
+                .map(ports::is_pid_running)
+                .unwrap_or(false)
+        })
+        .count();
+    let stale_count = app.state.bindings.len() - active_count;
+    let mut title_spans = vec![
         Span::styled("Active Port Bindings", Style::default().fg(theme.accent)),
-        Span::raw("  (press q to return)"),
-    ]))
-    .block(header);
+        Span::styled(
+            format!("  {active_count} active"),
+            Style::default().fg(theme.success),
+        ),
+        Span::styled(
+            format!(" / {stale_count} stale"),
+            Style::default().fg(theme.muted),
+        ),
+    ];
+    if app.binding_filter_editing || !app.binding_filter.value.is_empty() {
+        title_spans.push(Span::styled("  filter: ", Style::default().fg(theme.muted)));
+        title_spans.push(Span::styled(
+            app.binding_filter.value.as_str(),
+            Style::default().fg(Color::White),
+        ));
+    } else {
+        title_spans.push(Span::raw("  (press q to return)"));
+    }
+    let title = Paragraph::new(Line::from(title_spans)).block(header);
     frame.render_widget(title, chunks[0]);
+    if app.binding_filter_editing && app.modal.is_none() {
+        let prefix = "Active Port Bindings".len()
+            + format!("  {active_count} active / {stale_count} stale").len()
+            + "  filter: ".len();
+        let cursor_x =
+            chunks[0].x + 1 + prefix as u16 + app.binding_filter.cursor_display_offset() as u16;
+        frame.set_cursor(cursor_x, chunks[0].y + 1);
+    }
 
-    let items: Vec<ListItem> = app
-        .state
-        .bindings
+    let visible = app.visible_binding_indices();
+    let items: Vec<ListItem> = visible
         .iter()
+        .filter_map(|idx| app.state.bindings.get(*idx))
         .map(|binding| {
             let active = binding
                 .tunnel_pid
                 .map(ports::is_pid_running)
                 .unwrap_or(false);
-            let status = if active { "*" } else { "o" };
-            let status_style = if active {
-                Style::default().fg(theme.success)
+            let health = app
+                .binding_health
+                .get(&binding.local_port)
+                .copied()
+                .filter(|_| active);
+            let status = match (active, health) {
+                (true, Some(false)) => "!",
+                (true, _) => "*",
+                (false, _) => "o",
+            };
+            let status_style = match (active, health) {
+                (true, Some(false)) => Style::default().fg(theme.warning),
+                (true, _) => Style::default().fg(theme.success),
+                (false, _) => Style::default().fg(theme.muted),
+            };
+            let local_style = if is_loopback_address(&binding.bind_address) {
+                Style::default()
             } else {
-                Style::default().fg(theme.muted)
+                Style::default().fg(theme.warning)
             };
             let line = Line::from(vec![
                 Span::styled(status, status_style),
-                Span::raw(format!(
-                    "  {}:{} -> {}:{}  ",
-                    binding.droplet_name, binding.remote_port, "localhost", binding.local_port
-                )),
+                Span::raw("  "),
+                Span::styled(ports::local_address(binding), local_style),
+                Span::raw(" -> "),
+                Span::styled(
+                    binding.droplet_name.clone(),
+                    Style::default()
+                        .fg(theme.accent)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!(":{}  ", binding.remote_port)),
+                match &binding.label {
+                    Some(label) => Span::styled(
+                        format!("\"{label}\"  "),
+                        Style::default().add_modifier(Modifier::ITALIC),
+                    ),
+                    None => Span::raw(""),
+                },
                 Span::styled(
                     format!("{}", binding.public_ip),
                     Style::default().fg(theme.muted),
                 ),
+                Span::styled(
+                    format!("  ssh:{}", binding.ssh_port),
+                    if binding.ssh_port == 22 {
+                        Style::default().fg(theme.muted)
+                    } else {
+                        Style::default().fg(theme.accent)
+                    },
+                ),
+                Span::styled(
+                    format!("  [{}]", binding.interface.label()),
+                    Style::default().fg(theme.muted),
+                ),
+                match health {
+                    Some(true) => Span::styled("  serving", Style::default().fg(theme.success)),
+                    Some(false) => Span::styled(
+                        "  alive, port not responding",
+                        Style::default().fg(theme.warning),
+                    ),
+                    None => Span::raw(""),
+                },
             ]);
             ListItem::new(line)
         })
@@ -210,7 +408,16 @@ fn draw_bindings(frame: &mut Frame, app: &App, theme: &Theme) {
             Block::default()
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(theme.border))
-                .title("Port Bindings"),
+                .title(format!(
+                    "Port Bindings ({}/{}, sorted by {})",
+                    visible.len(),
+                    app.state.bindings.len(),
+                    if app.binding_sort_by_port {
+                        "local port"
+                    } else {
+                        "creation"
+                    }
+                )),
         )
         .highlight_style(
             Style::default()
@@ -221,12 +428,27 @@ fn draw_bindings(frame: &mut Frame, app: &App, theme: &Theme) {
 
     let mut state = binding_state_list(app);
     frame.render_stateful_widget(list, chunks[1], &mut state);
+    remember_list_area(app, chunks[1], &state);
 
     let help = Paragraph::new(Line::from(vec![
         Span::styled("d", Style::default().fg(theme.accent)),
         Span::raw(" unbind  "),
         Span::styled("x", Style::default().fg(theme.accent)),
         Span::raw(" cleanup stale  "),
+        Span::styled("r", Style::default().fg(theme.accent)),
+        Span::raw(" restart  "),
+        Span::styled("R", Style::default().fg(theme.accent)),
+        Span::raw(" reconnect all  "),
+        Span::styled("h", Style::default().fg(theme.accent)),
+        Span::raw(" health  "),
+        Span::styled("l", Style::default().fg(theme.accent)),
+        Span::raw(" label  "),
+        Span::styled("w/W", Style::default().fg(theme.accent)),
+        Span::raw(" workspace on/off  "),
+        Span::styled("/", Style::default().fg(theme.accent)),
+        Span::raw(" filter  "),
+        Span::styled("s", Style::default().fg(theme.accent)),
+        Span::raw(" sort  "),
         Span::styled("q", Style::default().fg(theme.accent)),
         Span::raw(" back"),
     ]))
@@ -265,25 +487,52 @@ fn draw_syncs(frame: &mut Frame, app: &App, theme: &Theme) {
         .iter()
         .map(|sync| {
             let status = sync.status.as_deref().unwrap_or("unknown");
-            let status_style = if status.eq_ignore_ascii_case("watching")
-                || status.eq_ignore_ascii_case("syncing")
-                || status.eq_ignore_ascii_case("monitoring")
-            {
-                Style::default().fg(theme.success)
-            } else if status.eq_ignore_ascii_case("paused")
-                || status.eq_ignore_ascii_case("stopped")
-            {
-                Style::default().fg(theme.warning)
+            let state = sync.state();
+            let (icon, status_style) = if sync.conflicts > 0 {
+                (
+                    "⚠",
+                    Style::default()
+                        .fg(theme.error)
+                        .add_modifier(Modifier::BOLD),
+                )
             } else {
-                Style::default().fg(theme.muted)
+                (state.icon(), sync_state_style(state, theme))
+            };
+            let conflicts = if sync.conflicts > 0 {
+                format!(
+                    " ({} conflict{})",
+                    sync.conflicts,
+                    if sync.conflicts == 1 { "" } else { "s" }
+                )
+            } else {
+                String::new()
+            };
+            let droplet = sync
+                .beta_host
+                .as_deref()
+                .and_then(|host| app.droplet_name_for_host(host));
+            let target = match (droplet, sync.beta_host.as_deref()) {
+                (Some(name), _) => {
+                    Span::styled(format!("  -> {name}"), Style::default().fg(theme.accent))
+                }
+                (None, Some(host)) => Span::styled(
+                    format!("  -> {host} (unknown host)"),
+                    Style::default().fg(theme.muted),
+                ),
+                (None, None) => Span::styled("  -> ?", Style::default().fg(theme.muted)),
             };
             let line = Line::from(vec![
                 Span::styled("• ", Style::default().fg(theme.muted)),
                 Span::raw(&sync.name),
                 Span::raw("  "),
-                Span::styled(format!("{status}"), status_style),
+                Span::styled(format!("{icon} {status}{conflicts}"), status_style),
+                target,
             ]);
-            ListItem::new(line)
+            if droplet.is_some() {
+                ListItem::new(line)
+            } else {
+                ListItem::new(line).style(Style::default().fg(theme.muted))
+            }
         })
         .collect();
 
@@ -306,12 +555,15 @@ fn draw_syncs(frame: &mut Frame, app: &App, theme: &Theme) {
         state.select(Some(app.selected.min(app.syncs.len() - 1)));
     }
     frame.render_stateful_widget(list, chunks[1], &mut state);
+    remember_list_area(app, chunks[1], &state);
 
     let help = Paragraph::new(Line::from(vec![
         Span::styled("d", Style::default().fg(theme.accent)),
         Span::raw(" delete  "),
         Span::styled("g", Style::default().fg(theme.accent)),
         Span::raw(" refresh  "),
+        Span::styled("X", Style::default().fg(theme.accent)),
+        Span::raw(" terminate all  "),
         Span::styled("q", Style::default().fg(theme.accent)),
         Span::raw(" back"),
     ]))
@@ -360,6 +612,13 @@ fn draw_rsync_binds(frame: &mut Frame, app: &App, theme: &Theme) {
             .map(|bind| {
                 let line = Line::from(vec![
                     Span::styled("• ", Style::default().fg(theme.muted)),
+                    match &bind.group {
+                        Some(group) => Span::styled(
+                            format!("[{group}] "),
+                            Style::default().add_modifier(Modifier::ITALIC),
+                        ),
+                        None => Span::raw(""),
+                    },
                     Span::raw(format!("{}  ", bind.droplet_name)),
                     Span::styled(
                         format!("{}@{}:{} ", bind.ssh_user, bind.host, bind.remote_path),
@@ -367,6 +626,7 @@ fn draw_rsync_binds(frame: &mut Frame, app: &App, theme: &Theme) {
                     ),
                     Span::raw(" -> "),
                     Span::styled(&bind.local_path, Style::default().fg(theme.muted)),
+                    rsync_last_run_span(bind, theme),
                 ]);
                 ListItem::new(line)
             })
@@ -389,10 +649,13 @@ fn draw_rsync_binds(frame: &mut Frame, app: &App, theme: &Theme) {
 
     let mut state = rsync_bind_state_list(app);
     frame.render_stateful_widget(list, chunks[1], &mut state);
+    remember_list_area(app, chunks[1], &state);
 
     let help = Paragraph::new(Line::from(vec![
         Span::styled("Enter", Style::default().fg(theme.accent)),
         Span::raw(" open bind actions  "),
+        Span::styled("n", Style::default().fg(theme.accent)),
+        Span::raw(" new bind  "),
         Span::styled("?", Style::default().fg(theme.accent)),
         Span::raw(" shortcuts  "),
         Span::styled("q", Style::default().fg(theme.accent)),
@@ -424,9 +687,54 @@ fn draw_header(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
             Style::default().fg(theme.muted),
         ));
     }
-    if app.pending > 0 {
+    if app.pending > app.background_pending {
         right.push(Span::styled("  *", Style::default().fg(theme.accent)));
     }
+    if app.background_pending > 0 {
+        right.push(Span::styled(
+            format!("  {}", spinner_frame()),
+            Style::default().fg(theme.muted),
+        ));
+    }
+    if let Some(until) = app.rate_limited_until {
+        let secs = (until - Utc::now()).num_seconds().max(0);
+        right.push(Span::styled(
+            format!("  rate limited {secs}s"),
+            Style::default().fg(theme.warning),
+        ));
+    }
+    if let Some(account) = &app.account {
+        let style = if account.warning().is_some() {
+            Style::default().fg(theme.warning)
+        } else {
+            Style::default().fg(theme.muted)
+        };
+        right.push(Span::styled(
+            format!("  {} ctx:{}", account.email, account.context),
+            style,
+        ));
+        if let Some(warning) = account.warning() {
+            right.push(Span::styled(format!(" [{warning}]"), style));
+        }
+    } else if app.account_error.is_some() {
+        let context = app
+            .state
+            .settings
+            .doctl_context
+            .as_deref()
+            .unwrap_or("current");
+        right.push(Span::styled(
+            format!("  NOT AUTHENTICATED ctx:{context}"),
+            Style::default()
+                .fg(theme.error)
+                .add_modifier(Modifier::BOLD),
+        ));
+    } else if let Some(context) = &app.state.settings.doctl_context {
+        right.push(Span::styled(
+            format!("  ctx:{context}"),
+            Style::default().fg(theme.muted),
+        ));
+    }
     if app.filter_running {
         right.push(Span::styled(
             "  [running]",
@@ -464,9 +772,15 @@ fn draw_droplet_list(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
             } else {
                 Style::default().fg(theme.muted)
             };
+            let favorite = if app.is_favorite(droplet.id) {
+                Span::styled(" ★", Style::default().fg(theme.warning))
+            } else {
+                Span::raw("  ")
+            };
             let line = Line::from(vec![
                 Span::styled(status, status_style),
-                Span::raw(format!("  {}", droplet.name)),
+                favorite,
+                Span::raw(format!(" {}", droplet.name)),
                 Span::styled(
                     format!("  #{}", droplet.id),
                     Style::default().fg(theme.muted),
@@ -495,6 +809,23 @@ fn draw_droplet_list(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
         );
     let mut state = app_state_list(app);
     frame.render_stateful_widget(list, area, &mut state);
+    remember_list_area(app, area, &state);
+
+    if indices.is_empty() {
+        let message = if app.last_refresh.is_none() {
+            "Loading droplets..."
+        } else if app.droplets.is_empty() {
+            "No droplets yet. Press c to create one."
+        } else {
+            "No running droplets. Press f to show all."
+        };
+        frame.render_widget(
+            Paragraph::new(message)
+                .style(Style::default().fg(theme.muted))
+                .wrap(Wrap { trim: true }),
+            inner_rect(area, 1),
+        );
+    }
 }
 
 fn draw_droplet_details(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
@@ -521,27 +852,71 @@ fn draw_droplet_details(frame: &mut Frame, app: &App, theme: &Theme, area: Rect)
                 },
             ),
         ]));
+        let detail = DropletDetail::new(droplet, &app.regions, &app.sizes, Utc::now());
         lines.push(Line::from(vec![
             Span::styled("Region: ", Style::default().fg(theme.muted)),
-            Span::raw(&droplet.region),
+            Span::raw(detail.region),
         ]));
-        if let Some(size) = &droplet.size {
+        if let Some(size) = detail.size {
             lines.push(Line::from(vec![
                 Span::styled("Size: ", Style::default().fg(theme.muted)),
                 Span::raw(size),
             ]));
         }
+        // Both addresses stay visible; the preferred one leads and is highlighted.
+        let preferred_ip = droplet.preferred_ip(app.state.settings.preferred_ip);
+        let ip_span = |ip: &str| {
+            if preferred_ip == Some(ip) {
+                Span::styled(
+                    ip.to_string(),
+                    Style::default()
+                        .fg(theme.accent)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Span::raw(ip.to_string())
+            }
+        };
+        let mut ip_lines = Vec::new();
         if let Some(ip) = &droplet.public_ipv4 {
-            lines.push(Line::from(vec![
+            ip_lines.push(Line::from(vec![
                 Span::styled("Public IP: ", Style::default().fg(theme.muted)),
-                Span::raw(ip),
+                ip_span(ip),
+            ]));
+        }
+        if let Some(ip) = &droplet.public_ipv6 {
+            ip_lines.push(Line::from(vec![
+                Span::styled("Public IPv6: ", Style::default().fg(theme.muted)),
+                ip_span(ip),
+                Span::styled("  (", Style::default().fg(theme.muted)),
+                Span::styled("6", Style::default().fg(theme.accent)),
+                Span::styled(" copy)", Style::default().fg(theme.muted)),
             ]));
         }
         if let Some(ip) = &droplet.private_ipv4 {
-            lines.push(Line::from(vec![
+            let line = Line::from(vec![
                 Span::styled("Private IP: ", Style::default().fg(theme.muted)),
-                Span::raw(ip),
-            ]));
+                ip_span(ip),
+            ]);
+            if app.state.settings.preferred_ip == BindInterface::Private {
+                ip_lines.insert(0, line);
+            } else {
+                ip_lines.push(line);
+            }
+        }
+        lines.extend(ip_lines);
+        if app.state.settings.show_dns_records && droplet.public_ip().is_some() {
+            let dns = match app.dns_hostnames(droplet) {
+                None => Some(Span::styled("loading…", Style::default().fg(theme.muted))),
+                Some(names) if names.is_empty() => None,
+                Some(names) => Some(Span::raw(names.join(", "))),
+            };
+            if let Some(dns) = dns {
+                lines.push(Line::from(vec![
+                    Span::styled("DNS: ", Style::default().fg(theme.muted)),
+                    dns,
+                ]));
+            }
         }
         if !droplet.tags.is_empty() {
             lines.push(Line::from(vec![
@@ -549,21 +924,84 @@ fn draw_droplet_details(frame: &mut Frame, app: &App, theme: &Theme, area: Rect)
                 Span::raw(droplet.tags.join(", ")),
             ]));
         }
-        if let Some(created_at) = &droplet.created_at {
-            lines.push(Line::from(vec![
+        if let Some(created) = detail.created {
+            let mut spans = vec![
                 Span::styled("Created: ", Style::default().fg(theme.muted)),
-                Span::raw(created_at),
+                Span::raw(created),
+            ];
+            if let Some(age) = detail.age {
+                spans.push(Span::styled(
+                    format!(" ({age})"),
+                    Style::default().fg(theme.muted),
+                ));
+            }
+            lines.push(Line::from(spans));
+        }
+        if let Some(enabled) = detail.backups_enabled {
+            lines.push(Line::from(vec![
+                Span::styled("Backups: ", Style::default().fg(theme.muted)),
+                if enabled {
+                    Span::styled("enabled", Style::default().fg(theme.success))
+                } else {
+                    Span::styled("off", Style::default().fg(theme.muted))
+                },
+            ]));
+        }
+        if let Some(features) = detail.features {
+            lines.push(Line::from(vec![
+                Span::styled("Features: ", Style::default().fg(theme.muted)),
+                Span::raw(features),
             ]));
         }
+        lines.push(match app.droplet_ssh_key_names(droplet.id) {
+            Some(names) => Line::from(vec![
+                Span::styled("SSH Keys: ", Style::default().fg(theme.muted)),
+                Span::raw(names.join(", ")),
+            ]),
+            None => Line::from(vec![
+                Span::styled("SSH Keys: ", Style::default().fg(theme.muted)),
+                Span::styled(
+                    "unknown (not created here; DO doesn't expose keys after create)",
+                    Style::default().fg(theme.muted),
+                ),
+            ]),
+        });
+    } else if app.droplets.is_empty() && app.last_refresh.is_some() {
+        lines.push(Line::from(Span::styled(
+            "No droplets in this account yet",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )));
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::raw("Press "),
+            Span::styled("c", Style::default().fg(theme.accent)),
+            Span::raw(" to create your first droplet, or "),
+            Span::styled("r", Style::default().fg(theme.accent)),
+            Span::raw(" to restore one from a snapshot."),
+        ]));
+        lines.push(Line::from(
+            "Droplet actions (connect, bind, sync, snapshot) unlock once one exists.",
+        ));
     } else {
         lines.push(Line::from("No droplet selected"));
     }
 
+    let connect_method = app.state.settings.connect_method;
     let actions = vec![
         Line::from(""),
         Line::from(vec![
             Span::styled("Enter", Style::default().fg(theme.accent)),
-            Span::raw(" connect"),
+            Span::raw(format!(" connect ({})", connect_method.label())),
+        ]),
+        Line::from(vec![
+            Span::styled("x", Style::default().fg(theme.accent)),
+            Span::raw(format!(" connect ({})", connect_method.other().label())),
+        ]),
+        Line::from(vec![
+            Span::styled("e", Style::default().fg(theme.accent)),
+            Span::raw(" connect and run a command"),
         ]),
         Line::from(vec![
             Span::styled("c", Style::default().fg(theme.accent)),
@@ -593,6 +1031,10 @@ fn draw_droplet_details(frame: &mut Frame, app: &App, theme: &Theme, area: Rect)
             Span::styled("m", Style::default().fg(theme.accent)),
             Span::raw(" mutagen config"),
         ]),
+        Line::from(vec![
+            Span::styled("M", Style::default().fg(theme.accent)),
+            Span::raw(" delete all syncs for droplet"),
+        ]),
         Line::from(vec![
             Span::styled("o", Style::default().fg(theme.accent)),
             Span::raw(" open remote folder"),
@@ -620,8 +1062,8 @@ fn draw_droplet_details(frame: &mut Frame, app: &App, theme: &Theme, area: Rect)
     );
 }
 
-fn draw_footer(frame: &mut Frame, _app: &App, theme: &Theme, area: Rect) {
-    let help = Line::from(vec![
+fn draw_footer(frame: &mut Frame, app: &App, theme: &Theme, area: Rect) {
+    let mut help = vec![
         Span::styled("g", Style::default().fg(theme.accent)),
         Span::raw(" refresh  "),
         Span::styled("m", Style::default().fg(theme.accent)),
@@ -636,9 +1078,19 @@ fn draw_footer(frame: &mut Frame, _app: &App, theme: &Theme, area: Rect) {
         Span::raw(" filter running  "),
         Span::styled("p", Style::default().fg(theme.accent)),
         Span::raw(" port bindings  "),
-        Span::styled("q", Style::default().fg(theme.accent)),
-        Span::raw(" quit"),
-    ]);
+        Span::styled(":", Style::default().fg(theme.accent)),
+        Span::raw(" commands  "),
+    ];
+    if !app.error_log.is_empty() {
+        help.push(Span::styled("E", Style::default().fg(theme.accent)));
+        help.push(Span::styled(
+            format!(" errors ({})  ", app.error_log.len()),
+            Style::default().fg(theme.error),
+        ));
+    }
+    help.push(Span::styled("q", Style::default().fg(theme.accent)));
+    help.push(Span::raw(" quit"));
+    let help = Line::from(help);
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(theme.border));
@@ -646,31 +1098,65 @@ fn draw_footer(frame: &mut Frame, _app: &App, theme: &Theme, area: Rect) {
 }
 
 fn draw_modal(frame: &mut Frame, app: &App, modal: &Modal, theme: &Theme) {
+    let fits = modal_fits(frame.size());
+    app.modal_hidden.set(!fits);
+    if !fits {
+        draw_too_small(frame, theme);
+        return;
+    }
     let area = centered_rect(70, 70, frame.size());
     frame.render_widget(Clear, area);
 
     match modal {
-        Modal::Create(form) => draw_create_modal(frame, form, theme, area),
-        Modal::Restore(form) => draw_restore_modal(frame, form, theme, area),
-        Modal::Bind(form) => draw_bind_modal(frame, form, theme, area),
+        Modal::Create(form) => draw_create_modal(frame, app, form, theme, area),
+        Modal::Restore(form) => draw_restore_modal(frame, app, form, theme, area),
+        Modal::Bind(form) => draw_bind_modal(frame, app, form, theme, area),
         Modal::Sync(form) => draw_sync_modal(frame, form, theme, area),
         Modal::Mutagen(form) => draw_mutagen_modal(frame, app, form, theme, area),
         Modal::RemoteBrowser(form) => draw_remote_browser_modal(frame, form, theme, area),
         Modal::RsyncBind(form) => draw_rsync_bind_modal(frame, form, theme, area),
-        Modal::RsyncBindActions(form) => draw_rsync_bind_actions_modal(frame, form, theme, area),
+        Modal::RsyncBindActions(form) => {
+            draw_rsync_bind_actions_modal(frame, app, form, theme, area)
+        }
         Modal::DeleteRsyncBind(form) => draw_delete_rsync_bind_modal(frame, form, theme, area),
-        Modal::Notice(notice) => draw_notice_modal(frame, notice, theme, area),
+        Modal::Reconcile(form) => draw_reconcile_modal(frame, form, theme, area),
+        Modal::Notice(notice) => draw_notice_modal(frame, app, notice, theme, area),
         Modal::Snapshot(form) => draw_snapshot_modal(frame, form, theme, area),
+        Modal::BindingLabel(form) => draw_binding_label_modal(frame, form, theme, area),
+        Modal::ConnectCommand(form) => draw_connect_command_modal(frame, form, theme, area),
+        Modal::RsyncBindGroup(form) => draw_rsync_bind_group_modal(frame, form, theme, area),
         Modal::Confirm(confirm) => draw_confirm_modal(frame, confirm, theme, area),
         Modal::Picker { picker, .. } => draw_picker_modal(frame, picker, theme, area),
     }
 }
 
-fn draw_create_modal(frame: &mut Frame, form: &CreateForm, theme: &Theme, area: Rect) {
+fn draw_too_small(frame: &mut Frame, theme: &Theme) {
+    let area = frame.size();
+    frame.render_widget(Clear, area);
+    let message = Paragraph::new(vec![
+        Line::from(Span::styled(
+            "Terminal too small",
+            Style::default()
+                .fg(theme.warning)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(format!(
+            "Enlarge to at least {MIN_MODAL_WIDTH}x{MIN_MODAL_HEIGHT} to see this dialog (Esc closes it)"
+        )),
+    ])
+    .alignment(Alignment::Center)
+    .wrap(Wrap { trim: true });
+    frame.render_widget(message, area);
+}
+
+fn draw_create_modal(frame: &mut Frame, app: &App, form: &CreateForm, theme: &Theme, area: Rect) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(theme.border))
-        .title("Create Droplet")
+        .title(match app.active_project() {
+            Some(project) => format!("Create Droplet (project {project})"),
+            None => "Create Droplet".to_string(),
+        })
         .title_alignment(Alignment::Left);
     frame.render_widget(block, area);
 
@@ -717,28 +1203,48 @@ fn draw_create_modal(frame: &mut Frame, form: &CreateForm, theme: &Theme, area:
         rows[3],
         theme,
     );
-    let ssh_label = format!("{} selected", form.ssh_keys.len());
-    render_select_row(
-        frame,
-        "SSH Keys",
-        Some(ssh_label.as_str()),
-        form.focus == 4,
-        rows[4],
-        theme,
-    );
+    render_ssh_keys_row(frame, &form.ssh_keys, form.focus == 4, rows[4], theme);
     cursor =
         render_input_row(frame, "Tags", &form.tags, form.focus == 5, rows[5], theme).or(cursor);
     render_action_row(frame, "Create", "Cancel", form.focus, 6, rows[6], theme);
 
-    let help = Paragraph::new(Line::from(vec![
-        Span::styled("Tab", Style::default().fg(theme.accent)),
-        Span::raw(" move  "),
-        Span::styled("Enter", Style::default().fg(theme.accent)),
-        Span::raw(" select  "),
-        Span::styled("Esc", Style::default().fg(theme.accent)),
-        Span::raw(" close"),
-    ]))
-    .style(Style::default().fg(theme.muted));
+    let background = if form.background {
+        Span::styled(
+            "[x] Create in background (no --wait; keep working while it provisions)",
+            Style::default().fg(theme.success),
+        )
+    } else {
+        Span::styled(
+            "[ ] Create in background (waits until the droplet is active)",
+            Style::default().fg(theme.muted),
+        )
+    };
+    let mut help = vec![cost_preview_line(
+        app.size_cost_preview(form.size.as_ref()),
+        theme,
+    )];
+    if let Some(name) = form.normalized_name() {
+        help.push(Line::from(Span::styled(
+            format!("Will be created as '{name}' (lowercase letters, digits, hyphens)"),
+            Style::default().fg(theme.warning),
+        )));
+    }
+    help.extend([
+        Line::from(background),
+        Line::from(vec![
+            Span::styled("Tab", Style::default().fg(theme.accent)),
+            Span::raw(" move  "),
+            Span::styled("Enter", Style::default().fg(theme.accent)),
+            Span::raw(" select  "),
+            Span::styled("Ctrl+B", Style::default().fg(theme.accent)),
+            Span::raw(" background  "),
+            Span::styled("Ctrl+Y", Style::default().fg(theme.accent)),
+            Span::raw(" copy doctl cmd  "),
+            Span::styled("Esc", Style::default().fg(theme.accent)),
+            Span::raw(" close"),
+        ]),
+    ]);
+    let help = Paragraph::new(help).style(Style::default().fg(theme.muted));
     frame.render_widget(help, rows[7]);
 
     if let Some((x, y)) = cursor {
@@ -746,11 +1252,14 @@ fn draw_create_modal(frame: &mut Frame, form: &CreateForm, theme: &Theme, area:
     }
 }
 
-fn draw_restore_modal(frame: &mut Frame, form: &RestoreForm, theme: &Theme, area: Rect) {
+fn draw_restore_modal(frame: &mut Frame, app: &App, form: &RestoreForm, theme: &Theme, area: Rect) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(theme.border))
-        .title("Restore Droplet")
+        .title(match app.active_project() {
+            Some(project) => format!("Restore Droplet (project {project})"),
+            None => "Restore Droplet".to_string(),
+        })
         .title_alignment(Alignment::Left);
     frame.render_widget(block, area);
 
@@ -796,27 +1305,24 @@ fn draw_restore_modal(frame: &mut Frame, form: &RestoreForm, theme: &Theme, area
         rows[3],
         theme,
     );
-    let ssh_label = format!("{} selected", form.ssh_keys.len());
-    render_select_row(
-        frame,
-        "SSH Keys",
-        Some(ssh_label.as_str()),
-        form.focus == 4,
-        rows[4],
-        theme,
-    );
+    render_ssh_keys_row(frame, &form.ssh_keys, form.focus == 4, rows[4], theme);
     cursor =
         render_input_row(frame, "Tags", &form.tags, form.focus == 5, rows[5], theme).or(cursor);
     render_action_row(frame, "Restore", "Cancel", form.focus, 6, rows[6], theme);
 
-    let help = Paragraph::new(Line::from(vec![
-        Span::styled("Tab", Style::default().fg(theme.accent)),
-        Span::raw(" move  "),
-        Span::styled("Enter", Style::default().fg(theme.accent)),
-        Span::raw(" select  "),
-        Span::styled("Esc", Style::default().fg(theme.accent)),
-        Span::raw(" close"),
-    ]))
+    let help = Paragraph::new(vec![
+        cost_preview_line(app.size_cost_preview(form.size.as_ref()), theme),
+        Line::from(vec![
+            Span::styled("Tab", Style::default().fg(theme.accent)),
+            Span::raw(" move  "),
+            Span::styled("Enter", Style::default().fg(theme.accent)),
+            Span::raw(" select  "),
+            Span::styled("Ctrl+S", Style::default().fg(theme.accent)),
+            Span::raw(" source specs  "),
+            Span::styled("Esc", Style::default().fg(theme.accent)),
+            Span::raw(" close"),
+        ]),
+    ])
     .style(Style::default().fg(theme.muted));
     frame.render_widget(help, rows[7]);
 
@@ -825,7 +1331,17 @@ fn draw_restore_modal(frame: &mut Frame, form: &RestoreForm, theme: &Theme, area
     }
 }
 
-fn draw_bind_modal(frame: &mut Frame, form: &BindForm, theme: &Theme, area: Rect) {
+fn cost_preview_line(cost: Option<String>, theme: &Theme) -> Line<'static> {
+    match cost {
+        Some(cost) => Line::from(vec![
+            Span::raw("Cost: "),
+            Span::styled(cost, Style::default().fg(theme.warning)),
+        ]),
+        None => Line::from("Cost: select a size to see its price"),
+    }
+}
+
+fn draw_bind_modal(frame: &mut Frame, app: &App, form: &BindForm, theme: &Theme, area: Rect) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(theme.border))
@@ -833,6 +1349,30 @@ fn draw_bind_modal(frame: &mut Frame, form: &BindForm, theme: &Theme, area: Rect
         .title_alignment(Alignment::Left);
     frame.render_widget(block, area);
 
+    let mut hints = Vec::new();
+    if let Some(warning) = &form.key_warning {
+        hints.push(Line::from(Span::styled(
+            warning.as_str(),
+            Style::default().fg(theme.warning),
+        )));
+    }
+    let bind_address = form.bind_address.value.trim();
+    if !bind_address.is_empty() && !is_loopback_address(bind_address) {
+        hints.push(Line::from(Span::styled(
+            format!("{bind_address} exposes this port to other devices on your network"),
+            Style::default().fg(theme.warning),
+        )));
+    }
+    if let Some(previous) = app.previous_port_droplet(form) {
+        hints.push(Line::from(Span::styled(
+            format!(
+                "Local port {} last pointed at droplet '{previous}'",
+                form.local_port.value.trim()
+            ),
+            Style::default().fg(theme.warning),
+        )));
+    }
+
     let inner = inner_rect(area, 1);
     let rows = Layout::default()
         .direction(Direction::Vertical)
@@ -843,14 +1383,23 @@ fn draw_bind_modal(frame: &mut Frame, form: &BindForm, theme: &Theme, area: Rect
             Constraint::Length(2),
             Constraint::Length(2),
             Constraint::Length(2),
+            Constraint::Length(2),
+            Constraint::Length(2),
+            Constraint::Length(2),
+            // One line per hint so a third warning is not clipped.
+            Constraint::Length(hints.len().max(2) as u16),
             Constraint::Min(1),
         ])
         .split(inner);
 
     let mut cursor = None;
+    let host = match form.interface {
+        BindInterface::Public => form.public_ip.as_deref().unwrap_or("-"),
+        BindInterface::Private => form.private_ip.as_deref().unwrap_or("-"),
+    };
     let header = Paragraph::new(Line::from(vec![
         Span::styled(&form.droplet_name, Style::default().fg(theme.accent)),
-        Span::raw(format!("  {}", form.public_ip)),
+        Span::raw(format!("  {host}")),
     ]))
     .style(Style::default());
     frame.render_widget(header, rows[0]);
@@ -884,7 +1433,7 @@ fn draw_bind_modal(frame: &mut Frame, form: &BindForm, theme: &Theme, area: Rect
     .or(cursor);
     cursor = render_input_row(
         frame,
-        "SSH Key",
+        ssh_key_label(form.use_agent),
         &form.ssh_key_path,
         form.focus == 3,
         rows[4],
@@ -900,14 +1449,49 @@ fn draw_bind_modal(frame: &mut Frame, form: &BindForm, theme: &Theme, area: Rect
         theme,
     )
     .or(cursor);
+    cursor = render_input_row(
+        frame,
+        "Bind Address",
+        &form.bind_address,
+        form.focus == 5,
+        rows[6],
+        theme,
+    )
+    .or(cursor);
+    cursor =
+        render_input_row(frame, "Label", &form.label, form.focus == 6, rows[7], theme).or(cursor);
+
+    let interface_style = if form.focus == 7 {
+        Style::default()
+            .fg(theme.accent)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(theme.muted)
+    };
+    let private_hint = match &form.private_ip {
+        Some(ip) => format!("  (private {ip})"),
+        None => "  (no private IP)".to_string(),
+    };
+    let interface = Paragraph::new(Line::from(vec![
+        Span::styled("Interface: ", interface_style),
+        Span::styled(format!("< {} >", form.interface.label()), interface_style),
+        Span::styled(private_hint, Style::default().fg(theme.muted)),
+    ]));
+    frame.render_widget(interface, rows[8]);
+
+    frame.render_widget(Paragraph::new(hints), rows[9]);
 
     let action = Paragraph::new(Line::from(vec![
+        Span::styled("Space", Style::default().fg(theme.accent)),
+        Span::raw(" toggle interface  "),
+        Span::styled("Ctrl+A", Style::default().fg(theme.accent)),
+        Span::raw(" SSH agent  "),
         Span::styled("Enter", Style::default().fg(theme.accent)),
         Span::raw(" bind  "),
         Span::styled("Esc", Style::default().fg(theme.accent)),
         Span::raw(" cancel"),
     ]));
-    frame.render_widget(action, rows[6]);
+    frame.render_widget(action, rows[10]);
 
     if let Some((x, y)) = cursor {
         frame.set_cursor(x, y);
@@ -938,7 +1522,7 @@ fn draw_sync_modal(frame: &mut Frame, form: &SyncForm, theme: &Theme, area: Rect
 
     let header = Paragraph::new(Line::from(vec![
         Span::styled(&form.droplet_name, Style::default().fg(theme.accent)),
-        Span::raw(format!("  {}", form.public_ip)),
+        Span::raw(format!("  {}", form.host)),
     ]))
     .style(Style::default());
     frame.render_widget(header, rows[0]);
@@ -964,7 +1548,7 @@ fn draw_sync_modal(frame: &mut Frame, form: &SyncForm, theme: &Theme, area: Rect
     .or(cursor);
     cursor = render_input_row(
         frame,
-        "SSH Key",
+        ssh_key_label(form.use_agent),
         &form.ssh_key_path,
         form.focus == 2,
         rows[3],
@@ -983,13 +1567,23 @@ fn draw_sync_modal(frame: &mut Frame, form: &SyncForm, theme: &Theme, area: Rect
 
     render_action_row(frame, "Sync", "Cancel", form.focus, 4, rows[5], theme);
 
-    let help = Paragraph::new(Line::from(vec![
+    let mut help = vec![Line::from(vec![
         Span::styled("Comma-separated", Style::default().fg(theme.muted)),
         Span::raw("  use "),
         Span::styled("local->remote", Style::default().fg(theme.accent)),
-        Span::raw(" to override remote path"),
-    ]))
-    .style(Style::default().fg(theme.muted));
+        Span::raw(" to override remote path  "),
+        Span::styled("Ctrl+R", Style::default().fg(theme.accent)),
+        Span::raw(" browse remote  "),
+        Span::styled("Ctrl+A", Style::default().fg(theme.accent)),
+        Span::raw(" SSH agent"),
+    ])];
+    if let Some(warning) = &form.key_warning {
+        help.push(Line::from(Span::styled(
+            warning.as_str(),
+            Style::default().fg(theme.warning),
+        )));
+    }
+    let help = Paragraph::new(help).style(Style::default().fg(theme.muted));
     frame.render_widget(help, rows[6]);
 
     if let Some((x, y)) = cursor {
@@ -1086,11 +1680,11 @@ fn draw_remote_browser_modal(
         .constraints([
             Constraint::Length(2),
             Constraint::Min(1),
-            Constraint::Length(5),
+            Constraint::Length(6),
         ])
         .split(inner);
 
-    let header = Paragraph::new(Line::from(vec![
+    let header = Line::from(vec![
         Span::styled(&form.droplet_name, Style::default().fg(theme.accent)),
         Span::raw("  "),
         Span::styled(&form.current_path, Style::default().fg(theme.muted)),
@@ -1099,8 +1693,31 @@ fn draw_remote_browser_modal(
         } else {
             Span::raw("")
         },
-    ]));
+    ]);
+    let mut view = Vec::new();
+    if form.filter_editing || !form.filter.value.is_empty() {
+        view.push(Span::styled("Filter: ", Style::default().fg(theme.accent)));
+        view.push(Span::raw(form.filter.value.as_str()));
+        view.push(Span::raw("  "));
+    }
+    view.push(Span::styled(
+        format!(
+            "dotfiles {}  sort {}",
+            if form.show_hidden { "shown" } else { "hidden" },
+            if form.case_insensitive {
+                "a-Z"
+            } else {
+                "A-Z, a-z"
+            }
+        ),
+        Style::default().fg(theme.muted),
+    ));
+    let header = Paragraph::new(vec![header, Line::from(view)]);
     frame.render_widget(header, rows[0]);
+    if form.filter_editing {
+        let x = rows[0].x + "Filter: ".len() as u16 + form.filter.cursor_display_offset() as u16;
+        frame.set_cursor(x.min(rows[0].right().saturating_sub(1)), rows[0].y + 1);
+    }
 
     let items: Vec<ListItem> = if form.entries.is_empty() && !form.loading {
         vec![ListItem::new(Line::from(vec![Span::styled(
@@ -1139,15 +1756,39 @@ fn draw_remote_browser_modal(
             Span::raw(" refresh"),
         ]),
         Line::from(vec![
-            Span::styled("o", Style::default().fg(theme.accent)),
-            Span::raw(" open highlighted in Cursor"),
-        ]),
-        Line::from(vec![
-            Span::styled("m", Style::default().fg(theme.accent)),
-            Span::raw(" bind rsync to local folder  "),
-            Span::styled("Esc", Style::default().fg(theme.accent)),
-            Span::raw(" close"),
+            Span::styled("/", Style::default().fg(theme.accent)),
+            Span::raw(" filter  "),
+            Span::styled(".", Style::default().fg(theme.accent)),
+            Span::raw(" dotfiles  "),
+            Span::styled("i", Style::default().fg(theme.accent)),
+            Span::raw(" ignore case  "),
+            Span::styled("PgUp/PgDn", Style::default().fg(theme.accent)),
+            Span::raw(" page"),
         ]),
+        if form.sync_form.is_some() {
+            Line::from(vec![
+                Span::styled("s", Style::default().fg(theme.accent)),
+                Span::raw(" use highlighted as the sync's remote path"),
+            ])
+        } else {
+            Line::from(vec![
+                Span::styled("o", Style::default().fg(theme.accent)),
+                Span::raw(" open highlighted in Cursor"),
+            ])
+        },
+        if form.sync_form.is_some() {
+            Line::from(vec![
+                Span::styled("Esc", Style::default().fg(theme.accent)),
+                Span::raw(" back to sync form"),
+            ])
+        } else {
+            Line::from(vec![
+                Span::styled("m", Style::default().fg(theme.accent)),
+                Span::raw(" bind rsync to local folder  "),
+                Span::styled("Esc", Style::default().fg(theme.accent)),
+                Span::raw(" close"),
+            ])
+        },
     ])
     .style(Style::default().fg(theme.muted))
     .wrap(Wrap { trim: true });
@@ -1155,6 +1796,10 @@ fn draw_remote_browser_modal(
 }
 
 fn draw_rsync_bind_modal(frame: &mut Frame, form: &RsyncBindForm, theme: &Theme, area: Rect) {
+    if let Some(fields) = &form.manual {
+        draw_manual_rsync_bind_modal(frame, form, fields, theme, area);
+        return;
+    }
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(theme.border))
@@ -1217,8 +1862,178 @@ fn draw_rsync_bind_modal(frame: &mut Frame, form: &RsyncBindForm, theme: &Theme,
     }
 }
 
+/// Idle is green, busy phases are accent, connection trouble is a warning, and anything
+/// that needs a person (halted) is an error.
+fn sync_state_style(state: SyncState, theme: &Theme) -> Style {
+    let style = Style::default();
+    match state {
+        SyncState::Watching => style.fg(theme.success),
+        SyncState::Syncing | SyncState::Scanning | SyncState::Staging | SyncState::Saving => {
+            style.fg(theme.accent)
+        }
+        SyncState::Reconciling | SyncState::Transitioning => {
+            style.fg(theme.accent).add_modifier(Modifier::BOLD)
+        }
+        SyncState::WaitingForRescan => style.fg(theme.accent).add_modifier(Modifier::DIM),
+        SyncState::Connecting => style.fg(theme.warning).add_modifier(Modifier::ITALIC),
+        SyncState::Paused => style.fg(theme.warning),
+        SyncState::Disconnected => style.fg(theme.warning).add_modifier(Modifier::BOLD),
+        SyncState::Halted => style.fg(theme.error).add_modifier(Modifier::BOLD),
+        SyncState::Unknown => style.fg(theme.muted),
+    }
+}
+
+fn rsync_remote_status_line<'a>(app: &App, bind: &RsyncBind, theme: &Theme) -> Line<'a> {
+    let (text, style) = match app.rsync_remote_status(bind) {
+        None if !app.tools.ssh => (
+            "not checked, ssh not found on PATH".to_string(),
+            Style::default().fg(theme.muted),
+        ),
+        None => ("checking...".to_string(), Style::default().fg(theme.muted)),
+        Some(RemotePathStatus::Exists) => (
+            "remote folder exists".to_string(),
+            Style::default().fg(theme.success),
+        ),
+        Some(RemotePathStatus::Missing) => (
+            "remote folder is gone (droplet rebuilt or path moved?); this bind is stale"
+                .to_string(),
+            Style::default()
+                .fg(theme.error)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Some(RemotePathStatus::Unreachable(reason)) => (
+            format!("could not check over SSH: {reason}"),
+            Style::default().fg(theme.warning),
+        ),
+    };
+    Line::from(vec![
+        Span::styled("Status:  ", Style::default().fg(theme.muted)),
+        Span::styled(text, style),
+    ])
+}
+
+fn draw_manual_rsync_bind_modal(
+    frame: &mut Frame,
+    form: &RsyncBindForm,
+    fields: &ManualRsyncBindFields,
+    theme: &Theme,
+    area: Rect,
+) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .title("New RSYNC Bind")
+        .title_alignment(Alignment::Left);
+    frame.render_widget(block, area);
+
+    let inner = inner_rect(area, 1);
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Length(2),
+            Constraint::Length(2),
+            Constraint::Length(2),
+            Constraint::Length(2),
+            Constraint::Length(2),
+            Constraint::Length(2),
+            Constraint::Min(1),
+        ])
+        .split(inner);
+
+    let droplet = (!form.droplet_name.is_empty())
+        .then(|| format!("{} ({})", form.droplet_name, form.ssh.host));
+    render_select_row(
+        frame,
+        "Droplet",
+        droplet.as_deref(),
+        form.focus == 0,
+        rows[0],
+        theme,
+    );
+    let mut cursor = None;
+    cursor = render_input_row(
+        frame,
+        "Remote Folder",
+        &fields.remote_path,
+        form.focus == 1,
+        rows[1],
+        theme,
+    )
+    .or(cursor);
+    cursor = render_input_row(
+        frame,
+        "Local Folder",
+        &form.local_path,
+        form.focus == 2,
+        rows[2],
+        theme,
+    )
+    .or(cursor);
+    cursor = render_input_row(
+        frame,
+        "SSH User",
+        &fields.ssh_user,
+        form.focus == 3,
+        rows[3],
+        theme,
+    )
+    .or(cursor);
+    cursor = render_input_row(
+        frame,
+        ssh_key_label(fields.use_agent),
+        &fields.ssh_key_path,
+        form.focus == 4,
+        rows[4],
+        theme,
+    )
+    .or(cursor);
+    cursor = render_input_row(
+        frame,
+        "SSH Port",
+        &fields.ssh_port,
+        form.focus == 5,
+        rows[5],
+        theme,
+    )
+    .or(cursor);
+    render_action_row(
+        frame,
+        "Bind + Open Finder",
+        "Cancel",
+        form.focus,
+        6,
+        rows[6],
+        theme,
+    );
+
+    let mut help = vec![Line::from(vec![
+        Span::styled("Enter", Style::default().fg(theme.accent)),
+        Span::raw(" pick droplet / next / confirm  "),
+        Span::styled("Tab", Style::default().fg(theme.accent)),
+        Span::raw(" move  "),
+        Span::styled("Ctrl+A", Style::default().fg(theme.accent)),
+        Span::raw(" SSH agent  "),
+        Span::styled("Esc", Style::default().fg(theme.accent)),
+        Span::raw(" close"),
+    ])];
+    if let Some(warning) = &fields.key_warning {
+        help.push(Line::from(Span::styled(
+            warning.as_str(),
+            Style::default().fg(theme.warning),
+        )));
+    }
+    let help = Paragraph::new(help).style(Style::default().fg(theme.muted));
+    frame.render_widget(help, rows[7]);
+
+    if let Some((x, y)) = cursor {
+        frame.set_cursor(x, y);
+    }
+}
+
 fn draw_rsync_bind_actions_modal(
     frame: &mut Frame,
+    app: &App,
     form: &RsyncBindActionsForm,
     theme: &Theme,
     area: Rect,
@@ -1234,7 +2049,7 @@ fn draw_rsync_bind_actions_modal(
     let rows = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(6),
+            Constraint::Length(9),
             Constraint::Length(2),
             Constraint::Length(2),
             Constraint::Min(1),
@@ -1253,16 +2068,35 @@ fn draw_rsync_bind_actions_modal(
                 form.bind.ssh_user, form.bind.host, form.bind.remote_path
             )),
         ]),
+        rsync_remote_status_line(app, &form.bind, theme),
+        Line::from(vec![
+            Span::styled("Group:   ", Style::default().fg(theme.muted)),
+            match &form.bind.group {
+                Some(group) => Span::raw(format!(
+                    "{group} ({} binds, U/D push/pull all)",
+                    app.state
+                        .rsync_binds
+                        .iter()
+                        .filter(|bind| bind.group.as_ref() == Some(group))
+                        .count()
+                )),
+                None => Span::styled("none (g to set)", Style::default().fg(theme.muted)),
+            },
+        ]),
         Line::from(vec![
             Span::styled("Local:   ", Style::default().fg(theme.muted)),
             Span::raw(&form.bind.local_path),
         ]),
         Line::from(vec![
             Span::styled("SSH:     ", Style::default().fg(theme.muted)),
-            Span::raw(format!(
-                "key={}  port={}",
-                form.bind.ssh_key_path, form.bind.ssh_port
-            )),
+            Span::raw(if form.bind.use_agent {
+                format!("agent  port={}", form.bind.ssh_port)
+            } else {
+                format!(
+                    "key={}  port={}",
+                    form.bind.ssh_key_path, form.bind.ssh_port
+                )
+            }),
         ]),
         Line::from(vec![
             Span::styled("Created: ", Style::default().fg(theme.muted)),
@@ -1273,6 +2107,31 @@ fn draw_rsync_bind_actions_modal(
                     .to_string(),
             ),
         ]),
+        Line::from(vec![
+            Span::styled("Profile: ", Style::default().fg(theme.muted)),
+            Span::styled(
+                form.bind.rsync_profile.label(),
+                Style::default().fg(theme.accent),
+            ),
+            Span::styled(
+                format!(
+                    "  compress {}",
+                    if form.bind.compress { "on" } else { "off" }
+                ),
+                Style::default().fg(theme.muted),
+            ),
+            Span::styled(
+                format!(
+                    "  checksum {}",
+                    if form.bind.checksum { "on" } else { "off" }
+                ),
+                Style::default().fg(theme.muted),
+            ),
+            Span::styled(
+                format!("  ({})", form.bind.rsync_flags().join(" ")),
+                Style::default().fg(theme.muted),
+            ),
+        ]),
     ])
     .wrap(Wrap { trim: true });
     frame.render_widget(info, rows[0]);
@@ -1291,12 +2150,19 @@ fn draw_rsync_bind_actions_modal(
         }
     };
 
-    let sync_actions = Paragraph::new(Line::from(vec![
+    let mut sync_spans = vec![
         Span::styled("Sync: ", Style::default().fg(theme.muted)),
         action_button("Push Up", form.selected_action == 0),
         Span::raw("  "),
         action_button("Pull Down", form.selected_action == 1),
-    ]));
+    ];
+    if !app.tools.rsync {
+        sync_spans.push(Span::styled(
+            "  rsync not found on PATH",
+            Style::default().fg(theme.warning),
+        ));
+    }
+    let sync_actions = Paragraph::new(Line::from(sync_spans));
     frame.render_widget(sync_actions, rows[1]);
 
     let other_actions = Paragraph::new(Line::from(vec![
@@ -1317,6 +2183,14 @@ fn draw_rsync_bind_actions_modal(
         Span::raw(" select  "),
         Span::styled("Enter", Style::default().fg(theme.accent)),
         Span::raw(" run action  "),
+        Span::styled("p", Style::default().fg(theme.accent)),
+        Span::raw(" cycle profile  "),
+        Span::styled("z", Style::default().fg(theme.accent)),
+        Span::raw(" compress  "),
+        Span::styled("c", Style::default().fg(theme.accent)),
+        Span::raw(" checksum  "),
+        Span::styled("g", Style::default().fg(theme.accent)),
+        Span::raw(" group  "),
         Span::styled("Esc", Style::default().fg(theme.accent)),
         Span::raw(" close"),
     ]))
@@ -1369,6 +2243,10 @@ fn draw_delete_rsync_bind_modal(
         Paragraph::new(Line::from(vec![
             Span::styled(checkbox, Style::default().fg(theme.accent)),
             Span::raw(" Also delete local copy"),
+            Span::styled(
+                " (asks again with its path and size)",
+                Style::default().fg(theme.muted),
+            ),
         ])),
         rows[1],
     );
@@ -1385,7 +2263,7 @@ fn draw_delete_rsync_bind_modal(
     frame.render_widget(help, rows[2]);
 }
 
-fn draw_notice_modal(frame: &mut Frame, notice: &Notice, theme: &Theme, area: Rect) {
+fn draw_notice_modal(frame: &mut Frame, app: &App, notice: &Notice, theme: &Theme, area: Rect) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(theme.border))
@@ -1399,22 +2277,239 @@ fn draw_notice_modal(frame: &mut Frame, notice: &Notice, theme: &Theme, area: Re
         .constraints([Constraint::Min(1), Constraint::Length(2)])
         .split(inner);
 
+    let paragraph = Paragraph::new(notice.message.clone()).wrap(Wrap { trim: true });
+    // Counted by the paragraph's own word wrapping, so the end of long notices stays reachable.
+    let total_lines = paragraph.line_count(rows[0].width.saturating_sub(1));
+    let visible_lines = rows[0].height as usize;
+    let scrollable = total_lines > visible_lines;
+    let max_scroll = total_lines.saturating_sub(visible_lines) as u16;
+    app.notice_max_scroll.set(max_scroll);
+    let scroll = notice.scroll.min(max_scroll);
+    let text_area = if scrollable {
+        Rect {
+            width: rows[0].width.saturating_sub(1),
+            ..rows[0]
+        }
+    } else {
+        rows[0]
+    };
+    frame.render_widget(paragraph.scroll((scroll, 0)), text_area);
+    if scrollable {
+        let mut scrollbar_state =
+            ScrollbarState::new(max_scroll as usize + 1).position(scroll as usize);
+        frame.render_stateful_widget(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .style(Style::default().fg(theme.border)),
+            rows[0],
+            &mut scrollbar_state,
+        );
+    }
+    let mut help = Vec::new();
+    if scrollable {
+        help.push(Span::styled(
+            "Up/Down/PgUp/PgDn",
+            Style::default().fg(theme.accent),
+        ));
+        help.push(Span::raw(" scroll  "));
+    }
+    help.extend([
+        Span::styled("Enter", Style::default().fg(theme.accent)),
+        Span::raw(" close  "),
+        Span::styled("Esc", Style::default().fg(theme.accent)),
+        Span::raw(" close"),
+    ]);
     frame.render_widget(
-        Paragraph::new(notice.message.clone()).wrap(Wrap { trim: true }),
-        rows[0],
-    );
-    frame.render_widget(
-        Paragraph::new(Line::from(vec![
-            Span::styled("Enter", Style::default().fg(theme.accent)),
-            Span::raw(" close  "),
-            Span::styled("Esc", Style::default().fg(theme.accent)),
-            Span::raw(" close"),
-        ]))
-        .style(Style::default().fg(theme.muted)),
+        Paragraph::new(Line::from(help)).style(Style::default().fg(theme.muted)),
         rows[1],
     );
 }
 
+fn draw_reconcile_modal(frame: &mut Frame, form: &ReconcileForm, theme: &Theme, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .title("Reconcile Mountlist")
+        .title_alignment(Alignment::Left);
+    frame.render_widget(block, area);
+
+    let inner = inner_rect(area, 1);
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(2)])
+        .split(inner);
+
+    let report = &form.report;
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(&form.droplet_name, Style::default().fg(theme.accent)),
+            Span::raw(format!("  {} matched", report.matched)),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!(
+                "Mountlist entries without a live session ({})",
+                report.stale_entries.len()
+            ),
+            Style::default().fg(theme.warning),
+        )),
+    ];
+    for entry in &report.stale_entries {
+        lines.push(Line::from(format!(
+            "  {}  {} -> {}",
+            entry.name, entry.local, entry.remote
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        format!(
+            "Sessions missing from the mountlist ({})",
+            report.untracked_sessions.len()
+        ),
+        Style::default().fg(theme.warning),
+    )));
+    for session in &report.untracked_sessions {
+        lines.push(Line::from(format!(
+            "  {}  {}",
+            session.name,
+            session.beta_url.as_deref().unwrap_or("")
+        )));
+    }
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), rows[0]);
+
+    let help = Paragraph::new(Line::from(vec![
+        Span::styled("p", Style::default().fg(theme.accent)),
+        Span::raw(" prune stale lines  "),
+        Span::styled("r", Style::default().fg(theme.accent)),
+        Span::raw(" recreate missing sessions  "),
+        Span::styled("Esc", Style::default().fg(theme.accent)),
+        Span::raw(" close"),
+    ]))
+    .style(Style::default().fg(theme.muted));
+    frame.render_widget(help, rows[1]);
+}
+
+fn draw_binding_label_modal(frame: &mut Frame, form: &BindingLabelForm, theme: &Theme, area: Rect) {
+    let header = Line::from(vec![
+        Span::styled(
+            form.local_address.as_str(),
+            Style::default().fg(theme.accent),
+        ),
+        Span::raw(format!(" -> {}:{}", form.droplet_name, form.remote_port)),
+    ]);
+    let field = ("Label", &form.label);
+    let help = " save (empty clears)  ";
+    draw_text_input_modal(frame, "Binding Label", header, field, help, theme, area);
+}
+
+/// A modal with a header line, one text input, and an Enter/Esc help row; `help` describes
+/// what Enter does.
+fn draw_text_input_modal(
+    frame: &mut Frame,
+    title: &str,
+    header: Line,
+    (label, input): (&str, &TextInput),
+    help: &str,
+    theme: &Theme,
+    area: Rect,
+) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .title(title)
+        .title_alignment(Alignment::Left);
+    frame.render_widget(block, area);
+
+    let inner = inner_rect(area, 1);
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Length(2),
+            Constraint::Min(1),
+        ])
+        .split(inner);
+
+    frame.render_widget(Paragraph::new(header), rows[0]);
+
+    let cursor = render_input_row(frame, label, input, true, rows[1], theme);
+
+    let help = Paragraph::new(Line::from(vec![
+        Span::styled("Enter", Style::default().fg(theme.accent)),
+        Span::raw(help),
+        Span::styled("Esc", Style::default().fg(theme.accent)),
+        Span::raw(" cancel"),
+    ]));
+    frame.render_widget(help, rows[2]);
+
+    if let Some((x, y)) = cursor {
+        frame.set_cursor(x, y);
+    }
+}
+
+fn draw_connect_command_modal(
+    frame: &mut Frame,
+    form: &ConnectCommandForm,
+    theme: &Theme,
+    area: Rect,
+) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .title("Connect and Run")
+        .title_alignment(Alignment::Left);
+    frame.render_widget(block, area);
+
+    let inner = inner_rect(area, 1);
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Length(2),
+            Constraint::Min(1),
+        ])
+        .split(inner);
+
+    let header = Paragraph::new(Line::from(vec![
+        Span::styled(&form.droplet_name, Style::default().fg(theme.accent)),
+        Span::raw(format!(" via {}", form.method.label())),
+    ]));
+    frame.render_widget(header, rows[0]);
+
+    let cursor = render_input_row(frame, "Command", &form.command, true, rows[1], theme);
+
+    let help = Paragraph::new(Line::from(vec![
+        Span::styled("Enter", Style::default().fg(theme.accent)),
+        Span::raw(" connect (empty opens a shell)  "),
+        Span::styled("Tab", Style::default().fg(theme.accent)),
+        Span::raw(" switch method  "),
+        Span::styled("Esc", Style::default().fg(theme.accent)),
+        Span::raw(" cancel"),
+    ]));
+    frame.render_widget(help, rows[2]);
+
+    if let Some((x, y)) = cursor {
+        frame.set_cursor(x, y);
+    }
+}
+
+fn draw_rsync_bind_group_modal(
+    frame: &mut Frame,
+    form: &RsyncBindGroupForm,
+    theme: &Theme,
+    area: Rect,
+) {
+    let header = Line::from(vec![
+        Span::styled(&form.bind.droplet_name, Style::default().fg(theme.accent)),
+        Span::raw(format!(
+            "  {} <-> {}",
+            form.bind.remote_path, form.bind.local_path
+        )),
+    ]);
+    let field = ("Group", &form.group);
+    let help = " save (empty removes from group)  ";
+    draw_text_input_modal(frame, "RSYNC Bind Group", header, field, help, theme, area);
+}
+
 fn draw_snapshot_modal(frame: &mut Frame, form: &SnapshotForm, theme: &Theme, area: Rect) {
     let block = Block::default()
         .borders(Borders::ALL)
@@ -1427,6 +2522,7 @@ fn draw_snapshot_modal(frame: &mut Frame, form: &SnapshotForm, theme: &Theme, ar
     let rows = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
+            Constraint::Length(2),
             Constraint::Length(2),
             Constraint::Length(2),
             Constraint::Min(1),
@@ -1448,13 +2544,28 @@ fn draw_snapshot_modal(frame: &mut Frame, form: &SnapshotForm, theme: &Theme, ar
         theme,
     );
 
+    let power_off = if form.power_off {
+        Span::styled(
+            "[x] Power off first (consistent snapshot)",
+            Style::default().fg(theme.success),
+        )
+    } else {
+        Span::styled(
+            "[ ] Power off first (snapshot runs live)",
+            Style::default().fg(theme.muted),
+        )
+    };
+    frame.render_widget(Paragraph::new(Line::from(power_off)), rows[2]);
+
     let help = Paragraph::new(Line::from(vec![
         Span::styled("Enter", Style::default().fg(theme.accent)),
         Span::raw(" continue  "),
+        Span::styled("Ctrl+O", Style::default().fg(theme.accent)),
+        Span::raw(" power off first  "),
         Span::styled("Esc", Style::default().fg(theme.accent)),
         Span::raw(" cancel"),
     ]));
-    frame.render_widget(help, rows[2]);
+    frame.render_widget(help, rows[3]);
 
     if let Some((x, y)) = cursor {
         frame.set_cursor(x, y);
@@ -1491,7 +2602,11 @@ fn draw_picker_modal(frame: &mut Frame, picker: &Picker, theme: &Theme, area: Re
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(theme.border))
-        .title(picker.title.as_str())
+        .title(if picker.multi {
+            format!("{} ({} selected)", picker.title, picker.chosen.len())
+        } else {
+            picker.title.clone()
+        })
         .title_alignment(Alignment::Left);
     frame.render_widget(block, area);
 
@@ -1521,23 +2636,38 @@ fn draw_picker_modal(frame: &mut Frame, picker: &Picker, theme: &Theme, area: Re
         .iter()
         .filter_map(|idx| picker.items.get(*idx))
         .map(|item| {
-            let marker = if picker.multi {
-                if picker.chosen.iter().any(|chosen| {
+            if item.header {
+                return ListItem::new(Line::from(Span::styled(
+                    item.label.as_str(),
+                    Style::default()
+                        .fg(theme.muted)
+                        .add_modifier(Modifier::BOLD),
+                )));
+            }
+            let chosen = picker.multi
+                && picker.chosen.iter().any(|chosen| {
                     picker
                         .items
                         .get(*chosen)
                         .map(|i| i.value == item.value)
                         .unwrap_or(false)
-                }) {
-                    "[x]"
-                } else {
-                    "[ ]"
-                }
-            } else {
+                });
+            let marker = if !picker.multi {
                 "   "
+            } else if chosen {
+                "[x]"
+            } else {
+                "[ ]"
+            };
+            let marker_style = if chosen {
+                Style::default()
+                    .fg(theme.success)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.muted)
             };
             ListItem::new(Line::from(vec![
-                Span::styled(marker, Style::default().fg(theme.muted)),
+                Span::styled(marker, marker_style),
                 Span::raw(" "),
                 Span::raw(&item.label),
             ]))
@@ -1578,6 +2708,15 @@ fn draw_picker_modal(frame: &mut Frame, picker: &Picker, theme: &Theme, area: Re
     frame.render_widget(help, rows[2]);
 }
 
+/// The key row's label; with the agent on the path is kept but not passed to ssh.
+fn ssh_key_label(use_agent: bool) -> &'static str {
+    if use_agent {
+        "SSH Key (unused, agent)"
+    } else {
+        "SSH Key"
+    }
+}
+
 fn render_input_row(
     frame: &mut Frame,
     label: &str,
@@ -1628,6 +2767,32 @@ fn render_select_row(
     frame.render_widget(Paragraph::new(line), area);
 }
 
+fn render_ssh_keys_row(
+    frame: &mut Frame,
+    keys: &[Selection],
+    focused: bool,
+    area: Rect,
+    theme: &Theme,
+) {
+    let style = if focused {
+        Style::default().fg(theme.accent)
+    } else {
+        Style::default().fg(theme.muted)
+    };
+    let label = "SSH Keys: ";
+    let value = if keys.is_empty() {
+        Span::styled(
+            "none, droplet may only be reachable by password",
+            Style::default().fg(theme.warning),
+        )
+    } else {
+        let width = (area.width as usize).saturating_sub(label.len());
+        Span::raw(ssh_key_summary(keys, width))
+    };
+    let line = Line::from(vec![Span::styled(label, style), value]);
+    frame.render_widget(Paragraph::new(line), area);
+}
+
 fn render_action_row(
     frame: &mut Frame,
     primary: &str,
@@ -1664,7 +2829,7 @@ fn draw_toast(frame: &mut Frame, app: &App, theme: &Theme) {
         Some(toast) => toast,
         None => return,
     };
-    if (Utc::now() - toast.created_at).num_seconds() > 6 {
+    if (Utc::now() - toast.created_at).num_seconds() > app.toast_ttl_secs(toast.level) {
         return;
     }
     let style = match toast.level {
@@ -1683,14 +2848,17 @@ fn draw_toast(frame: &mut Frame, app: &App, theme: &Theme) {
     frame.render_widget(Paragraph::new(toast.message.clone()).style(style), rect);
 }
 
+fn spinner_frame() -> &'static str {
+    let frames = ["|", "/", "-", "\\"];
+    frames[((Utc::now().timestamp_subsec_millis() / 120) % frames.len() as u32) as usize]
+}
+
 fn draw_loading_overlay(frame: &mut Frame, app: &App, theme: &Theme) {
-    if app.pending == 0 {
+    if !app.show_loading_overlay() {
         return;
     }
 
-    let frames = ["|", "/", "-", "\\"];
-    let spinner_idx = ((Utc::now().timestamp_subsec_millis() / 120) % frames.len() as u32) as usize;
-    let spinner = frames[spinner_idx];
+    let spinner = spinner_frame();
 
     let area = centered_rect(64, 34, frame.size());
     frame.render_widget(Clear, area);
@@ -1719,34 +2887,42 @@ fn draw_loading_overlay(frame: &mut Frame, app: &App, theme: &Theme) {
     frame.render_widget(content, inner);
 }
 
+/// Smallest modal that still lays out its rows; below this `draw_modal` shows a notice instead.
+const MIN_MODAL_WIDTH: u16 = 40;
+const MIN_MODAL_HEIGHT: u16 = 18;
+
+/// A `percent_x` by `percent_y` rect centered in `r`, grown to the minimum modal size when
+/// `r` has room and never larger than `r`.
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage((100 - percent_y) / 2),
-            Constraint::Percentage(percent_y),
-            Constraint::Percentage((100 - percent_y) / 2),
-        ])
-        .split(r);
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - percent_x) / 2),
-            Constraint::Percentage(percent_x),
-            Constraint::Percentage((100 - percent_x) / 2),
-        ])
-        .split(popup_layout[1])[1]
+    let scale = |len: u16, percent: u16, min: u16| {
+        let scaled = (u32::from(len) * u32::from(percent.min(100)) / 100) as u16;
+        scaled.max(min).min(len)
+    };
+    let width = scale(r.width, percent_x, MIN_MODAL_WIDTH);
+    let height = scale(r.height, percent_y, MIN_MODAL_HEIGHT);
+    Rect {
+        x: r.x + (r.width - width) / 2,
+        y: r.y + (r.height - height) / 2,
+        width,
+        height,
+    }
 }
 
 fn inner_rect(area: Rect, margin: u16) -> Rect {
+    let margin_x = margin.min(area.width / 2);
+    let margin_y = margin.min(area.height / 2);
     Rect {
-        x: area.x + margin,
-        y: area.y + margin,
-        width: area.width.saturating_sub(margin * 2),
-        height: area.height.saturating_sub(margin * 2),
+        x: area.x + margin_x,
+        y: area.y + margin_y,
+        width: area.width - margin_x * 2,
+        height: area.height - margin_y * 2,
     }
 }
 
+fn modal_fits(area: Rect) -> bool {
+    area.width >= MIN_MODAL_WIDTH && area.height >= MIN_MODAL_HEIGHT
+}
+
 fn app_state_list(app: &App) -> ratatui::widgets::ListState {
     let mut state = ratatui::widgets::ListState::default();
     let max = app.visible_indices().len();
@@ -1757,9 +2933,36 @@ fn app_state_list(app: &App) -> ratatui::widgets::ListState {
     state
 }
 
+fn rsync_last_run_span<'a>(bind: &RsyncBind, theme: &Theme) -> Span<'a> {
+    let (Some(synced_at), Some(result)) = (bind.last_synced_at, bind.last_result.as_deref()) else {
+        return Span::styled("  never synced", Style::default().fg(theme.muted));
+    };
+    let age = format_age(Utc::now() - synced_at);
+    if bind.last_run_failed() {
+        let verb = result.split(' ').next().unwrap_or("sync");
+        Span::styled(
+            format!("  x {verb} failed {age}"),
+            Style::default().fg(theme.error),
+        )
+    } else {
+        Span::styled(
+            format!("  ok {result} · {age}"),
+            Style::default().fg(theme.success),
+        )
+    }
+}
+
+/// Records where the screen's bordered list rendered so mouse clicks can map to rows.
+fn remember_list_area(app: &App, area: Rect, state: &ratatui::widgets::ListState) {
+    app.list_area.set(Some(ListHitArea {
+        rows: inner_rect(area, 1),
+        offset: state.offset(),
+    }));
+}
+
 fn binding_state_list(app: &App) -> ratatui::widgets::ListState {
     let mut state = ratatui::widgets::ListState::default();
-    let max = app.state.bindings.len();
+    let max = app.visible_binding_indices().len();
     if max > 0 {
         let selected = app.selected.min(max - 1);
         state.select(Some(selected));
@@ -1776,3 +2979,34 @@ fn rsync_bind_state_list(app: &App) -> ratatui::widgets::ListState {
     }
     state
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn centered_rect_stays_inside_tiny_areas() {
+        for size in [0, 1, 2] {
+            let area = Rect::new(3, 4, size, size);
+            let rect = centered_rect(70, 70, area);
+            assert_eq!(rect, area);
+            let inner = inner_rect(rect, 1);
+            assert!(inner.x >= area.x && inner.right() <= area.right());
+            assert!(inner.y >= area.y && inner.bottom() <= area.bottom());
+            Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(2), Constraint::Min(1)])
+                .split(inner);
+        }
+        assert!(!modal_fits(Rect::new(0, 0, 2, 2)));
+    }
+
+    #[test]
+    fn centered_rect_grows_to_minimum_modal_size() {
+        let rect = centered_rect(70, 70, Rect::new(0, 0, 50, 20));
+        assert_eq!(rect, Rect::new(5, 1, 40, 18));
+        let rect = centered_rect(70, 70, Rect::new(0, 0, 200, 60));
+        assert_eq!(rect, Rect::new(30, 9, 140, 42));
+        assert!(modal_fits(Rect::new(0, 0, 80, 24)));
+    }
+}
@@ -0,0 +1,146 @@
+//! Detects the machine waking from sleep so dead tunnels can be reconnected right away.
+//! Each platform tails a system event stream in a child process; anywhere the stream
+//! isn't available the watcher is a no-op.
+
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+use crossbeam_channel::Sender;
+
+use crate::logging;
+use crate::tasks::TaskResult;
+use crate::tools;
+
+/// Give Wi-Fi/VPN a moment to come back before reconnecting over it.
+const RESUME_SETTLE: Duration = Duration::from_secs(10);
+
+/// Starts watching for resume events in the background, sending
+/// `TaskResult::SystemResumed` after each one.
+pub fn watch(tx: Sender<TaskResult>) {
+    let Some(mut cmd) = monitor_command() else {
+        logging::log("wake: no resume monitor on this platform");
+        return;
+    };
+    let program = cmd.get_program().to_string_lossy().into_owned();
+    if tools::find_on_path(&program).is_none() {
+        logging::log(format!(
+            "wake: {program} not found; resume detection disabled"
+        ));
+        return;
+    }
+    thread::spawn(move || {
+        logging::command(&cmd);
+        let mut child = match cmd
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+        {
+            Ok(child) => child,
+            Err(err) => {
+                logging::log(format!("wake: failed to start {program}: {err}"));
+                return;
+            }
+        };
+        let Some(stdout) = child.stdout.take() else {
+            return;
+        };
+        let mut parser = ResumeParser::default();
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if parser.feed(&line) {
+                logging::log("wake: system resumed");
+                thread::sleep(RESUME_SETTLE);
+                if tx.send(TaskResult::SystemResumed).is_err() {
+                    break;
+                }
+            }
+        }
+        let _ = child.kill();
+        let _ = child.wait();
+    });
+}
+
+/// logind announces `PrepareForSleep(true)` before suspending and `PrepareForSleep(false)`
+/// on resume.
+#[cfg(target_os = "linux")]
+fn monitor_command() -> Option<Command> {
+    let mut cmd = Command::new("dbus-monitor");
+    cmd.arg("--system")
+        .arg("type='signal',interface='org.freedesktop.login1.Manager',member='PrepareForSleep'");
+    Some(cmd)
+}
+
+/// The kernel logs a "Wake reason" line through IOKit's power management on every wake.
+#[cfg(target_os = "macos")]
+fn monitor_command() -> Option<Command> {
+    let mut cmd = Command::new("log");
+    cmd.args([
+        "stream",
+        "--style",
+        "compact",
+        "--predicate",
+        "process == \"kernel\" AND eventMessage CONTAINS \"Wake reason\"",
+    ]);
+    Some(cmd)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn monitor_command() -> Option<Command> {
+    None
+}
+
+/// Turns monitor output into resume events. dbus-monitor prints the signal header and its
+/// boolean argument on separate lines, so the header is remembered until the argument.
+#[derive(Debug, Default)]
+struct ResumeParser {
+    in_sleep_signal: bool,
+}
+
+impl ResumeParser {
+    fn feed(&mut self, line: &str) -> bool {
+        let line = line.trim();
+        if line.contains("member=PrepareForSleep") {
+            self.in_sleep_signal = true;
+            return false;
+        }
+        if self.in_sleep_signal && line.starts_with("boolean") {
+            self.in_sleep_signal = false;
+            return line == "boolean false";
+        }
+        // `log stream` first echoes its predicate, which also mentions "Wake reason"; only
+        // the kernel's own message counts.
+        line.contains("kernel[") && line.contains("Wake reason:")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parser_reports_logind_resume_only() {
+        let mut parser = ResumeParser::default();
+        let header = "signal time=1700000000.1 sender=:1.3 -> destination=(null destination) \
+                      serial=812 path=/org/freedesktop/login1; \
+                      interface=org.freedesktop.login1.Manager; member=PrepareForSleep";
+        assert!(!parser.feed(header));
+        assert!(!parser.feed("   boolean true"));
+        assert!(!parser.feed(header));
+        assert!(parser.feed("   boolean false"));
+        assert!(!parser.feed("   boolean false"));
+    }
+
+    #[test]
+    fn parser_reports_macos_wake_lines() {
+        let mut parser = ResumeParser::default();
+        assert!(!parser.feed(
+            r#"Filtering the log data using "process == "kernel" AND eventMessage CONTAINS "Wake reason"""#
+        ));
+        assert!(!parser.feed("Timestamp               Ty Process[PID:TID]"));
+        assert!(
+            parser.feed("2024-05-01 09:12:03.118 Df kernel[0:1a2] Wake reason: EC.LidOpen (User)")
+        );
+    }
+}